@@ -7,14 +7,14 @@ use ckb_std::{
         packed::{Byte32, ScriptBuilder},
         prelude::*,
     },
-    high_level::{load_cell_lock_hash, load_cell_type_hash},
     syscalls::SysError,
 };
-use utils::{is_deposit_data, DAO_HASH, XUDT_CODE_HASH, XUDT_HASH_TYPE};
+use utils::{is_deposit_data, CellSource, DAO_HASH, XUDT_CODE_HASH, XUDT_HASH_TYPE};
 
 use crate::{constants::XUDT_ARGS_FLAGS, error::Error};
 
-pub struct CellTypeIter {
+pub struct CellTypeIter<'a, C: CellSource> {
+    cells: &'a C,
     index: usize,
     source: Source,
     ickb_logic_hash: [u8; 32],
@@ -28,7 +28,7 @@ pub enum CellType {
     Udt,
 }
 
-impl Iterator for CellTypeIter {
+impl<C: CellSource> Iterator for CellTypeIter<'_, C> {
     type Item = Result<(usize, Source, CellType), Error>;
 
     // Iterates over the specified sources, returning the index and type of found iCKB cells
@@ -37,8 +37,8 @@ impl Iterator for CellTypeIter {
         let err = |e| Some(Err(e));
 
         let (lock_script_type, type_script_type) = match (
-            load_cell_lock_hash(self.index, self.source),
-            load_cell_type_hash(self.index, self.source),
+            self.cells.load_cell_lock_hash(self.index, self.source),
+            self.cells.load_cell_type_hash(self.index, self.source),
         ) {
             // No more cells
             (Err(SysError::IndexOutOfBound), ..) => return None,
@@ -91,11 +91,11 @@ enum ScriptType {
     IckbUdt,
 }
 
-impl CellTypeIter {
+impl<C: CellSource> CellTypeIter<'_, C> {
     fn script_type(&self, h: [u8; 32]) -> ScriptType {
         if h == DAO_HASH {
             // This condition checks that's a deposit, not a withdrawal request or an unknown cell
-            if is_deposit_data(self.index, self.source) {
+            if is_deposit_data(self.cells, self.index, self.source) {
                 return ScriptType::DaoDeposit;
             }
             return ScriptType::Unknown;
@@ -113,7 +113,11 @@ impl CellTypeIter {
     }
 }
 
-pub fn cell_type_iter(source: Source, ickb_logic_hash: [u8; 32]) -> CellTypeIter {
+pub fn cell_type_iter<C: CellSource>(
+    cells: &C,
+    source: Source,
+    ickb_logic_hash: [u8; 32],
+) -> CellTypeIter<'_, C> {
     let ickb_xudt_args = [ickb_logic_hash.as_slice(), XUDT_ARGS_FLAGS.as_slice()].concat();
     let ickb_xudt_hash: [u8; 32] = ScriptBuilder::default()
         .code_hash(Byte32::from_slice(&XUDT_CODE_HASH).unwrap())
@@ -126,6 +130,7 @@ pub fn cell_type_iter(source: Source, ickb_logic_hash: [u8; 32]) -> CellTypeIter
         .unwrap();
 
     CellTypeIter {
+        cells,
         index: 0,
         source,
         ickb_logic_hash,
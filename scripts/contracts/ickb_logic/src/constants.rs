@@ -1,7 +1,9 @@
 // https://github.com/nervosnetwork/rfcs/blob/41a469a10cce2104656767944e9aba9a61ab497d/rfcs/0052-extensible-udt/0052-extensible-udt.md#owner-mode-update
 pub const XUDT_ARGS_FLAGS: [u8; 4] = [0, 0, 0, 128]; // Flag 0x80000000
 
-// iCKB deposit constants
-pub const CKB_MINIMUM_UNOCCUPIED_CAPACITY_PER_DEPOSIT: u64 = 1_000 * 100_000_000; // 1000 CKB
-pub const CKB_MAXIMUM_UNOCCUPIED_CAPACITY_PER_DEPOSIT: u64 = 1_000_000 * 100_000_000; // 1M CKB
-pub const ICKB_SOFT_CAP_PER_DEPOSIT: u128 = 100_000 * 100_000_000; // 100_000 iCKB
+// Lock hash of Script { code_hash: [0; 32], hash_type: Data, args: [] }, only empty cell data hashes
+// to zero and an empty cell has no executable code, so cells with this lock can never be consumed
+pub const PARAMS_LOCK_HASH: [u8; 32] = [
+    0x77, 0xc9, 0x3b, 0x06, 0x32, 0xb5, 0xb6, 0xc3, 0xef, 0x92, 0x2c, 0x5b, 0x7c, 0xea, 0x20, 0x8f,
+    0xb0, 0xa7, 0xc4, 0x27, 0xa1, 0x3d, 0x50, 0xe1, 0x3d, 0x3f, 0xef, 0xad, 0x17, 0xe0, 0xc5, 0x90,
+];
@@ -16,6 +16,10 @@ pub enum Error {
     ReceiptMismatch,
     AmountMismatch,
     AmountUnreasonablyBig,
+    ParamsMissing,
+    ParamsInvalid,
+    ParamsMutable,
+    InvalidAccumulatedRate,
 }
 
 impl From<SysError> for Error {
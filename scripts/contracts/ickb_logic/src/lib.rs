@@ -0,0 +1,16 @@
+//! iCKB validation logic, shared by the script and host side tests.
+
+#![no_std]
+extern crate alloc;
+
+mod celltype;
+mod constants;
+pub mod error;
+mod params;
+mod utils;
+mod validate;
+
+pub use crate::utils::extract_receipt_data;
+pub use constants::PARAMS_LOCK_HASH;
+pub use params::{deposit_limits, load_deposit_limits, params_type_hash};
+pub use validate::{check_input, check_output};
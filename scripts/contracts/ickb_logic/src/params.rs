@@ -0,0 +1,85 @@
+use core::{convert::TryInto, result::Result};
+
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::{
+        bytes::Bytes,
+        packed::{Byte32, ScriptBuilder},
+        prelude::*,
+    },
+    error::SysError,
+};
+use ickb_rate::DepositLimits;
+use ickb_types::{
+    molecule::prelude::Reader, DepositLimits as DepositLimitsData, DepositLimitsReader,
+};
+use utils::{CellSource, TYPE_ID_CODE_HASH, TYPE_ID_HASH_TYPE};
+
+use crate::{constants::PARAMS_LOCK_HASH, error::Error};
+
+// Deposit limits selected by ickb_logic args:
+// - empty args: v1, the hard-coded limits
+// - 32 bytes args: v2, the limits stored in the cell dep whose type is the type id with these args
+pub fn deposit_limits(cells: &impl CellSource, args: &[u8]) -> Result<DepositLimits, Error> {
+    match <[u8; 32]>::try_from(args) {
+        _ if args.is_empty() => Ok(DepositLimits::V1),
+        Ok(type_id_args) => load_deposit_limits(cells, params_type_hash(type_id_args)),
+        Err(_) => Err(Error::NotEmptyArgs),
+    }
+}
+
+// Type script hash of the parameter cell, a type id is unique, so its limits cannot be forged
+pub fn params_type_hash(type_id_args: [u8; 32]) -> [u8; 32] {
+    ScriptBuilder::default()
+        .code_hash(Byte32::from_slice(&TYPE_ID_CODE_HASH).unwrap())
+        .hash_type(TYPE_ID_HASH_TYPE.into())
+        .args(Bytes::from(type_id_args.to_vec()).pack())
+        .build()
+        .calc_script_hash()
+        .as_slice()
+        .try_into()
+        .unwrap()
+}
+
+// The parameter cell must be locked by the unspendable PARAMS_LOCK_HASH, so its limits cannot be updated
+pub fn load_deposit_limits(
+    cells: &impl CellSource,
+    params_type_hash: [u8; 32],
+) -> Result<DepositLimits, Error> {
+    for index in 0.. {
+        match cells.load_cell_type_hash(index, Source::CellDep) {
+            Ok(Some(type_hash)) if type_hash == params_type_hash => {
+                if cells.load_cell_lock_hash(index, Source::CellDep)? != PARAMS_LOCK_HASH {
+                    return Err(Error::ParamsMutable);
+                }
+                return extract_deposit_limits(cells, index);
+            }
+            Ok(_) => {}
+            Err(SysError::IndexOutOfBound) => break,
+            Err(err) => return Err(Error::from(err)),
+        }
+    }
+
+    Err(Error::ParamsMissing)
+}
+
+fn extract_deposit_limits(cells: &impl CellSource, index: usize) -> Result<DepositLimits, Error> {
+    let mut data = [0u8; DepositLimitsData::TOTAL_SIZE];
+    match cells.load_cell_data(&mut data, 0, index, Source::CellDep) {
+        Ok(DepositLimitsData::TOTAL_SIZE) => (),
+        Ok(_) | Err(SysError::LengthNotEnough(_)) => return Err(Error::ParamsInvalid),
+        Err(err) => return Err(Error::from(err)),
+    };
+
+    let params = DepositLimitsReader::new_unchecked(&data);
+    let limits = DepositLimits {
+        min_unoccupied_capacity: params.min_unoccupied_capacity().unpack(),
+        max_unoccupied_capacity: params.max_unoccupied_capacity().unpack(),
+        soft_cap: params.soft_cap().unpack(),
+    };
+    if !limits.is_valid() {
+        return Err(Error::ParamsInvalid);
+    }
+
+    Ok(limits)
+}
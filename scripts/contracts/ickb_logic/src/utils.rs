@@ -1,40 +1,30 @@
-use core::{convert::TryInto, result::Result};
+use core::result::Result;
 
-use ckb_std::{
-    ckb_constants::Source,
-    syscalls::{load_cell_data, SysError},
-};
+use ckb_std::{ckb_constants::Source, syscalls::SysError};
+use ickb_types::{molecule::prelude::Reader, ReceiptData, ReceiptDataReader};
+use utils::CellSource;
 
 use crate::error::Error;
 
-// Data layout in bytes
-// {
-const DEPOSIT_QUANTITY_SIZE: usize = 4;
-const DEPOSIT_AMOUNT_SIZE: usize = 8;
-// }
-
-const RECEIPT_SIZE: usize = DEPOSIT_QUANTITY_SIZE + DEPOSIT_AMOUNT_SIZE;
-
-pub fn extract_receipt_data(index: usize, source: Source) -> Result<(u32, u64), Error> {
-    let mut data = [0u8; RECEIPT_SIZE];
-    let mut raw_data = match load_cell_data(&mut data, 0, index, source) {
-        Ok(RECEIPT_SIZE) | Err(SysError::LengthNotEnough(_)) => data.as_slice(),
+pub fn extract_receipt_data(
+    cells: &impl CellSource,
+    index: usize,
+    source: Source,
+) -> Result<(u32, u64), Error> {
+    let mut data = [0u8; ReceiptData::TOTAL_SIZE];
+    match cells.load_cell_data(&mut data, 0, index, source) {
+        Ok(ReceiptData::TOTAL_SIZE) | Err(SysError::LengthNotEnough(_)) => (),
         Ok(_) => return Err(Error::Encoding),
         Err(err) => return Err(Error::from(err)),
     };
 
-    // Data splitter
-    let mut load = |size: usize| {
-        let field_data: &[u8];
-        (field_data, raw_data) = raw_data.split_at(size);
-        field_data
-    };
+    let receipt = ReceiptDataReader::new_unchecked(&data);
 
     // The quantity of the deposits
-    let deposit_quantity = u32::from_le_bytes(load(DEPOSIT_QUANTITY_SIZE).try_into().unwrap());
+    let deposit_quantity = receipt.deposit_quantity().unpack();
 
     // Stored in little endian is the amount of a single deposit
-    let deposit_amount = u64::from_le_bytes(load(DEPOSIT_AMOUNT_SIZE).try_into().unwrap());
+    let deposit_amount = receipt.deposit_amount().unpack();
 
     Ok((deposit_quantity, deposit_amount))
 }
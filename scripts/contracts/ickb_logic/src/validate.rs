@@ -0,0 +1,130 @@
+use alloc::collections::BTreeMap;
+use core::result::Result;
+
+use ckb_std::ckb_constants::Source;
+
+use ickb_rate::DepositLimits;
+use utils::{extract_accumulated_rate, extract_udt_amount, extract_unused_capacity, CellSource};
+
+use crate::celltype::{cell_type_iter, CellType};
+use crate::error::Error;
+use crate::utils::extract_receipt_data;
+
+pub fn check_input(
+    cells: &impl CellSource,
+    ickb_logic_hash: [u8; 32],
+    limits: &DepositLimits,
+) -> Result<(u128, u128, u128), Error> {
+    let mut total_udt_ickb = 0;
+    let mut total_receipts_ickb = 0;
+    let mut total_deposits_ickb = 0;
+
+    for maybe_cell_info in cell_type_iter(cells, Source::Input, ickb_logic_hash) {
+        let (index, source, cell_type) = maybe_cell_info?;
+
+        match cell_type {
+            CellType::Deposit => {
+                let deposit_amount = extract_unused_capacity(cells, index, source)?;
+
+                // Convert to iCKB and apply a 10% discount for the amount exceeding the soft iCKB cap per deposit
+                total_deposits_ickb +=
+                    deposit_to_ickb(cells, index, source, limits, deposit_amount)?;
+            }
+            CellType::Receipt => {
+                let (deposit_quantity, deposit_amount) =
+                    extract_receipt_data(cells, index, source)?;
+
+                // Convert to iCKB and apply a 10% fee for the amount exceeding the soft iCKB cap per deposit
+                total_receipts_ickb += u128::from(deposit_quantity)
+                    * deposit_to_ickb(cells, index, source, limits, deposit_amount)?;
+            }
+            CellType::Udt => {
+                total_udt_ickb += extract_udt_amount(cells, index, source)?;
+            }
+            CellType::Unknown => {}
+        }
+    }
+
+    Ok((total_udt_ickb, total_receipts_ickb, total_deposits_ickb))
+}
+
+fn deposit_to_ickb(
+    cells: &impl CellSource,
+    index: usize,
+    source: Source,
+    limits: &DepositLimits,
+    amount: u64,
+) -> Result<u128, Error> {
+    let ar_m = extract_accumulated_rate(cells, index, source)?;
+
+    limits
+        .ickb_for_deposit(amount, ar_m)
+        .ok_or(Error::InvalidAccumulatedRate)
+}
+
+pub fn check_output(
+    cells: &impl CellSource,
+    ickb_logic_hash: [u8; 32],
+    limits: &DepositLimits,
+) -> Result<u128, Error> {
+    let mut amount_2_accounting: BTreeMap<u64, Accounting> = BTreeMap::new();
+    let default = Accounting {
+        deposited: 0,
+        receipted: 0,
+    };
+
+    let mut total_udt_ickb = 0;
+
+    for maybe_cell_info in cell_type_iter(cells, Source::Output, ickb_logic_hash) {
+        let (index, source, cell_type) = maybe_cell_info?;
+
+        match cell_type {
+            CellType::Deposit => {
+                let amount = extract_unused_capacity(cells, index, source)?;
+                if amount < limits.min_unoccupied_capacity {
+                    return Err(Error::DepositTooSmall);
+                }
+                if amount > limits.max_unoccupied_capacity {
+                    return Err(Error::DepositTooBig);
+                }
+
+                let accounting = amount_2_accounting.entry(amount).or_insert(default);
+                accounting.deposited += 1;
+            }
+            CellType::Receipt => {
+                let (deposit_quantity, deposit_amount) =
+                    extract_receipt_data(cells, index, source)?;
+
+                if deposit_quantity == 0 {
+                    return Err(Error::EmptyReceipt);
+                }
+
+                let accounting = amount_2_accounting.entry(deposit_amount).or_insert(default);
+                accounting.receipted += u128::from(deposit_quantity);
+            }
+            CellType::Udt => {
+                let amount = extract_udt_amount(cells, index, source)?;
+                if amount > u128::from(u64::MAX) {
+                    return Err(Error::AmountUnreasonablyBig);
+                }
+                total_udt_ickb += amount;
+            }
+            CellType::Unknown => {}
+        }
+    }
+
+    if amount_2_accounting
+        .into_values()
+        .any(|a| a.deposited != a.receipted)
+    {
+        return Err(Error::ReceiptMismatch);
+    }
+
+    Ok(total_udt_ickb)
+}
+
+#[derive(Clone, Copy)]
+struct Accounting {
+    deposited: u128,
+    receipted: u128,
+}
@@ -0,0 +1,122 @@
+#![no_std]
+
+// iCKB exchange rate, shared by ickb_logic and off-chain code, so quotes cannot diverge from on-chain validation
+
+// https://github.com/nervosnetwork/rfcs/blob/master/rfcs/0023-dao-deposit-withdraw/0023-dao-deposit-withdraw.md#calculation
+pub const GENESIS_ACCUMULATED_RATE: u128 = 10_000_000_000_000_000; // 10^16 Genesis block accumulated rate
+
+// iCKB v1 deposit constants
+pub const CKB_MINIMUM_UNOCCUPIED_CAPACITY_PER_DEPOSIT: u64 = 1_000 * 100_000_000; // 1000 CKB
+pub const CKB_MAXIMUM_UNOCCUPIED_CAPACITY_PER_DEPOSIT: u64 = 1_000_000 * 100_000_000; // 1M CKB
+pub const ICKB_SOFT_CAP_PER_DEPOSIT: u128 = 100_000 * 100_000_000; // 100_000 iCKB
+
+// Deposit limits enforced by ickb_logic: v1 uses the constants above, while v2 reads them
+// from a parameter cell referenced through cell deps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DepositLimits {
+    pub min_unoccupied_capacity: u64,
+    pub max_unoccupied_capacity: u64,
+    pub soft_cap: u128,
+}
+
+impl DepositLimits {
+    pub const V1: Self = DepositLimits {
+        min_unoccupied_capacity: CKB_MINIMUM_UNOCCUPIED_CAPACITY_PER_DEPOSIT,
+        max_unoccupied_capacity: CKB_MAXIMUM_UNOCCUPIED_CAPACITY_PER_DEPOSIT,
+        soft_cap: ICKB_SOFT_CAP_PER_DEPOSIT,
+    };
+
+    // Limits must accept at least one deposit size and never discount the whole deposit
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        0 < self.min_unoccupied_capacity
+            && self.min_unoccupied_capacity <= self.max_unoccupied_capacity
+            && 0 < self.soft_cap
+    }
+
+    // Convert the unoccupied capacity of a deposit to iCKB, accumulated_rate is the one of the deposit header
+    // Apply a 10% discount for the amount exceeding the soft iCKB cap per deposit
+    // None for a zero accumulated_rate, which no valid header has
+    #[must_use]
+    pub fn ickb_for_deposit(&self, unoccupied: u64, accumulated_rate: u64) -> Option<u128> {
+        let ickb_amount = (u128::from(unoccupied) * GENESIS_ACCUMULATED_RATE)
+            .checked_div(u128::from(accumulated_rate))?;
+
+        if ickb_amount > self.soft_cap {
+            return Some(ickb_amount - (ickb_amount - self.soft_cap) / 10);
+        }
+
+        Some(ickb_amount)
+    }
+
+    // iCKB value of a receipt, accumulated_rate is the one of the receipt header
+    // None for a zero accumulated_rate or an overflowing value
+    #[must_use]
+    pub fn ickb_for_receipt(
+        &self,
+        deposit_quantity: u32,
+        deposit_amount: u64,
+        accumulated_rate: u64,
+    ) -> Option<u128> {
+        u128::from(deposit_quantity)
+            .checked_mul(self.ickb_for_deposit(deposit_amount, accumulated_rate)?)
+    }
+
+    // Largest unoccupied capacity whose deposit at accumulated_rate is not discounted,
+    // capped by the maximum unoccupied capacity per deposit
+    #[must_use]
+    pub fn max_deposit_under_soft_cap(&self, accumulated_rate: u64) -> u64 {
+        // No deposit has a quote at a zero accumulated_rate
+        if accumulated_rate == 0 {
+            return 0;
+        }
+
+        // unoccupied * GENESIS_ACCUMULATED_RATE / accumulated_rate <= soft_cap
+        // ~ unoccupied * GENESIS_ACCUMULATED_RATE < (soft_cap + 1) * accumulated_rate
+        let max = self
+            .soft_cap
+            .checked_add(1)
+            .and_then(|c| c.checked_mul(u128::from(accumulated_rate)))
+            .map(|c| (c - 1) / GENESIS_ACCUMULATED_RATE);
+
+        match max.map(u64::try_from) {
+            Some(Ok(max)) if max < self.max_unoccupied_capacity => max,
+            _ => self.max_unoccupied_capacity,
+        }
+    }
+}
+
+impl Default for DepositLimits {
+    fn default() -> Self {
+        Self::V1
+    }
+}
+
+// Convert the unoccupied capacity of a deposit to iCKB with the v1 limits
+#[must_use]
+pub fn ickb_for_deposit(unoccupied: u64, accumulated_rate: u64) -> Option<u128> {
+    DepositLimits::V1.ickb_for_deposit(unoccupied, accumulated_rate)
+}
+
+// iCKB value of a receipt with the v1 limits
+#[must_use]
+pub fn ickb_for_receipt(
+    deposit_quantity: u32,
+    deposit_amount: u64,
+    accumulated_rate: u64,
+) -> Option<u128> {
+    DepositLimits::V1.ickb_for_receipt(deposit_quantity, deposit_amount, accumulated_rate)
+}
+
+// Convert iCKB to CKB at accumulated_rate, rounding down, no discount applies
+// None for an overflowing value
+#[must_use]
+pub fn ckb_for_ickb(ickb_amount: u128, accumulated_rate: u64) -> Option<u128> {
+    Some(ickb_amount.checked_mul(u128::from(accumulated_rate))? / GENESIS_ACCUMULATED_RATE)
+}
+
+// Largest unoccupied capacity under the v1 soft cap, see DepositLimits::max_deposit_under_soft_cap
+#[must_use]
+pub fn max_deposit_under_soft_cap(accumulated_rate: u64) -> u64 {
+    DepositLimits::V1.max_deposit_under_soft_cap(accumulated_rate)
+}
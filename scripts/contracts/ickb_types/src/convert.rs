@@ -0,0 +1,60 @@
+use molecule::prelude::*;
+
+use crate::encoding::*;
+
+// Conversions between molecule arrays and the little endian integers they store
+macro_rules! impl_uint {
+    ($entity:ident, $reader:ident, $builder:ident, $int:ty, $size:literal) => {
+        impl From<$int> for $entity {
+            fn from(value: $int) -> Self {
+                $builder::default()
+                    .set(value.to_le_bytes().map(Byte::new))
+                    .build()
+            }
+        }
+
+        impl $entity {
+            #[must_use]
+            pub fn unpack(&self) -> $int {
+                self.as_reader().unpack()
+            }
+        }
+
+        impl<'r> $reader<'r> {
+            #[must_use]
+            pub fn unpack(&self) -> $int {
+                let mut data = [0u8; $size];
+                data.copy_from_slice(self.raw_data());
+                <$int>::from_le_bytes(data)
+            }
+        }
+    };
+}
+
+impl_uint!(Uint8, Uint8Reader, Uint8Builder, u8, 1);
+impl_uint!(Uint32, Uint32Reader, Uint32Builder, u32, 4);
+impl_uint!(Uint64, Uint64Reader, Uint64Builder, u64, 8);
+impl_uint!(Uint128, Uint128Reader, Uint128Builder, u128, 16);
+impl_uint!(Int32, Int32Reader, Int32Builder, i32, 4);
+
+impl From<[u8; 32]> for Byte32 {
+    fn from(value: [u8; 32]) -> Self {
+        Byte32Builder::default().set(value.map(Byte::new)).build()
+    }
+}
+
+impl Byte32 {
+    #[must_use]
+    pub fn unpack(&self) -> [u8; 32] {
+        self.as_reader().unpack()
+    }
+}
+
+impl<'r> Byte32Reader<'r> {
+    #[must_use]
+    pub fn unpack(&self) -> [u8; 32] {
+        let mut data = [0u8; 32];
+        data.copy_from_slice(self.raw_data());
+        data
+    }
+}
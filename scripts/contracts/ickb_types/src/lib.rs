@@ -0,0 +1,17 @@
+#![no_std]
+extern crate alloc;
+
+// Generated from schemas/encoding.mol, do not edit by hand, instead regenerate it with:
+// moleculec --language rust --schema-file schemas/encoding.mol | rustfmt > scripts/contracts/ickb_types/src/encoding.rs
+mod convert;
+#[allow(clippy::all, dead_code)]
+mod encoding;
+
+pub use encoding::*;
+pub use molecule;
+
+// UdtLayout strategy: UdtData followed by XudtData, as for xUDT with extension data
+pub const UDT_LAYOUT_XUDT_DATA: u8 = 0;
+
+// OrderTerms max_matcher_fee is in parts per million, so it must be lower than this
+pub const MATCHER_FEE_DENOMINATOR: u32 = 1_000_000;
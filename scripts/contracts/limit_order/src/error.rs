@@ -25,6 +25,13 @@ pub enum Error {
     AttemptToChangeFulfilled,
     InsufficientMatch,
     InvalidConfiguration,
+    InvalidUdtLayout,
+    UdtDataChanged,
+    InvalidExpiry,
+    NotExpired,
+    InsufficientRefund,
+    InvalidMatcherFee,
+    InvalidMasterData,
 }
 
 impl From<SysError> for Error {
@@ -0,0 +1,11 @@
+//! Order validation logic, shared by the script and host side tests.
+
+#![no_std]
+extern crate alloc;
+
+pub mod error;
+mod order;
+mod validate;
+
+pub use order::{check_orders, extract_order};
+pub use validate::{validate, Data, Expiry, Info, Ratio, UdtLayout};
@@ -0,0 +1,530 @@
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use core::{cmp::Ordering, result::Result};
+use ickb_types::{
+    molecule::{prelude::Reader, NUMBER_SIZE},
+    MatchOrderData, MatchOrderDataReader, MintOrderDataReader, OrderTermsReader,
+    PartialOrderDataReader, PartialOrderDataUnionReader, RatioReader, UdtData, UdtDataReader,
+    UdtLayout as UdtLayoutData, UdtLayoutReader, XudtDataReader, MATCHER_FEE_DENOMINATOR,
+    UDT_LAYOUT_XUDT_DATA,
+};
+use utils::{extract_metapoint, CellSource, MetaPoint, C256};
+
+use ckb_std::{ckb_constants::Source, error::SysError, high_level::QueryIter, since::Since};
+
+use crate::{
+    error::Error,
+    validate::{validate, Data, Expiry, Info, Ratio, UdtLayout},
+};
+
+pub fn check_orders(cells: &impl CellSource, script_hash: [u8; 32]) -> Result<(), Error> {
+    let is_script = |index: usize, source: Source| {
+        Ok((
+            cells.load_cell_lock_hash(index, source)? == script_hash,
+            cells.load_cell_type_hash(index, source)? == Some(script_hash),
+        ))
+    };
+
+    let mut metapoint_2_order: BTreeMap<MetaPoint, [Order; 2]> = BTreeMap::new();
+    let default = [Order {
+        data: None,
+        has_master: false,
+        index: 0,
+        master_index: 0,
+    }; 2];
+
+    for source in [Source::Input, Source::Output] {
+        for (index, is_script) in QueryIter::new(is_script, source).enumerate() {
+            match is_script {
+                (false, false) => (),
+                (false, true) => {
+                    // Master Cell
+                    let metapoint = extract_metapoint(cells, index, source)?;
+                    let io_accounting = metapoint_2_order.entry(metapoint).or_insert(default);
+                    // No two cells exists with the same outpoint, so this should not happen
+                    if io_accounting[source as usize - 1].has_master {
+                        return Err(Error::DuplicatedMaster);
+                    }
+                    io_accounting[source as usize - 1].has_master = true;
+                    io_accounting[source as usize - 1].master_index = index;
+                }
+                (true, false) => {
+                    // Limit Order Cell
+                    let (metapoint, data) = extract_order(cells, index, source)?;
+                    let io_accounting = metapoint_2_order.entry(metapoint).or_insert(default);
+                    if io_accounting[source as usize - 1].data.is_some() {
+                        return Err(Error::SameMaster);
+                    }
+                    io_accounting[source as usize - 1].data = Some(data);
+                    io_accounting[source as usize - 1].index = index;
+                }
+                (true, true) => return Err(Error::ScriptMisuse),
+            }
+        }
+    }
+
+    // CKB and UDT owed to each master lock by the expired orders melted without their master
+    let mut refunds = Refunds::new();
+
+    // Validate actions
+    for [Order {
+        data: in_maybe_data,
+        has_master: in_has_master,
+        index: in_index,
+        master_index: in_master_index,
+    }, Order {
+        data: out_maybe_data,
+        has_master: out_has_master,
+        index: out_index,
+        master_index: out_master_index,
+    }] in metapoint_2_order.into_values()
+    {
+        match (in_maybe_data, in_has_master, out_maybe_data, out_has_master) {
+            // Mint Order
+            (None, false, Some(o), true) => {
+                let master_since = load_master_since(cells, out_master_index, Source::Output)?;
+                match (o.info.expiry, master_since) {
+                    (None, None) => (),
+                    (Some(expiry), Some(since)) if since == expiry.since => {
+                        let master_lock_hash =
+                            cells.load_cell_lock_hash(out_master_index, Source::Output)?;
+                        if master_lock_hash != expiry.master_lock_hash {
+                            return Err(Error::InvalidExpiry);
+                        }
+                    }
+                    _ => return Err(Error::InvalidMasterData),
+                }
+            }
+            // Melt Order
+            (Some(_), true, None, false) => (),
+            // Melt an expired Order without its Master, anyone can do it
+            (Some(i), false, None, false) => match i.info.expiry {
+                Some(expiry) => {
+                    check_expired(cells, in_index, expiry.since)?;
+                    refunds.add(expiry.master_lock_hash, i);
+                }
+                None => return Err(Error::InvalidConfiguration),
+            },
+            // Burn a Master without its Order, only past the expiry it carries,
+            // as its Order may have been melted without it
+            (None, true, None, false) => {
+                match load_master_since(cells, in_master_index, Source::Input)? {
+                    Some(since) => check_expired(cells, in_master_index, since)?,
+                    None => return Err(Error::InvalidConfiguration),
+                }
+            }
+            // Match Order
+            (Some(i), false, Some(o), false) => {
+                validate(i, o)?;
+                check_udt_data(cells, in_index, out_index, i.info.udt_layout)?;
+            }
+            // Every other configuration is invalid
+            _ => return Err(Error::InvalidConfiguration),
+        }
+    }
+
+    refunds.check(cells)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct Order {
+    data: Option<Data>,
+    has_master: bool,
+    // Index of the limit order cell, if any
+    index: usize,
+    // Index of the master cell, if any
+    master_index: usize,
+}
+
+// The input since proves that the expiry is past
+fn check_expired(cells: &impl CellSource, index: usize, expiry: Since) -> Result<(), Error> {
+    let since = Since::new(cells.load_input_since(index, Source::Input)?);
+    if since.flags() != expiry.flags() {
+        return Err(Error::NotExpired);
+    }
+    match since.partial_cmp(&expiry) {
+        Some(Ordering::Greater | Ordering::Equal) => Ok(()),
+        _ => Err(Error::NotExpired),
+    }
+}
+
+// The master of an expiring order carries its expiry since as data, while other masters have no data
+fn load_master_since(
+    cells: &impl CellSource,
+    index: usize,
+    source: Source,
+) -> Result<Option<Since>, Error> {
+    let mut data = [0u8; 8];
+    match cells.load_cell_data(&mut data, 0, index, source) {
+        Ok(0) => Ok(None),
+        Ok(8) => {
+            let since = Since::new(u64::from_le_bytes(data));
+            check_expiry_flags(since)?;
+            Ok(Some(since))
+        }
+        Ok(_) | Err(SysError::LengthNotEnough(_)) => Err(Error::InvalidMasterData),
+        Err(err) => Err(Error::from(err)),
+    }
+}
+
+// Amounts indexed by master lock hash and UDT hash, None for CKB
+struct Refunds(BTreeMap<([u8; 32], Option<[u8; 32]>), C256>);
+
+impl Refunds {
+    fn new() -> Self {
+        Refunds(BTreeMap::new())
+    }
+
+    fn add(&mut self, master_lock_hash: [u8; 32], order: Data) {
+        for (key, amount) in [
+            ((master_lock_hash, None), order.ckb),
+            ((master_lock_hash, Some(order.info.udt_hash)), order.udt),
+        ] {
+            let owed = self.0.entry(key).or_insert(C256::from(0u64));
+            *owed = *owed + amount;
+        }
+    }
+
+    // Outputs locked by each master lock must hold at least the owed CKB and UDT
+    fn check(mut self, cells: &impl CellSource) -> Result<(), Error> {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+
+        let lock_hash = |index: usize, source: Source| cells.load_cell_lock_hash(index, source);
+        for (index, lock_hash) in QueryIter::new(lock_hash, Source::Output).enumerate() {
+            if let Some(owed) = self.0.get_mut(&(lock_hash, None)) {
+                deduct(
+                    owed,
+                    C256::from(cells.load_cell_capacity(index, Source::Output)?),
+                );
+            }
+            let Some(type_hash) = cells.load_cell_type_hash(index, Source::Output)? else {
+                continue;
+            };
+            if let Some(owed) = self.0.get_mut(&(lock_hash, Some(type_hash))) {
+                let mut raw_udt_data = [0u8; UdtData::TOTAL_SIZE];
+                load_exact(cells, &mut raw_udt_data, 0, index, Source::Output)?;
+                deduct(owed, C256::from(udt_amount(&raw_udt_data)));
+            }
+        }
+
+        if self.0.values().any(|owed| !owed.is_zero()) {
+            return Err(Error::InsufficientRefund);
+        }
+        Ok(())
+    }
+}
+
+fn deduct(owed: &mut C256, amount: C256) {
+    *owed = if amount < *owed {
+        *owed - amount
+    } else {
+        C256::from(0u64)
+    };
+}
+
+// UDT data following the UDT amount must be the same in the matched order,
+// same layout is already enforced by validate, as part of the order info
+fn check_udt_data(
+    cells: &impl CellSource,
+    in_index: usize,
+    out_index: usize,
+    udt_layout: UdtLayout,
+) -> Result<(), Error> {
+    match udt_layout {
+        UdtLayout::Udt => Ok(()),
+        UdtLayout::XudtData { udt_data_size } => {
+            let size = udt_data_size as usize - UdtData::TOTAL_SIZE;
+            let mut in_data = vec![0u8; size];
+            let mut out_data = vec![0u8; size];
+            load_exact(
+                cells,
+                &mut in_data,
+                UdtData::TOTAL_SIZE,
+                in_index,
+                Source::Input,
+            )?;
+            load_exact(
+                cells,
+                &mut out_data,
+                UdtData::TOTAL_SIZE,
+                out_index,
+                Source::Output,
+            )?;
+            if in_data != out_data {
+                return Err(Error::UdtDataChanged);
+            }
+            Ok(())
+        }
+    }
+}
+
+pub fn extract_order(
+    cells: &impl CellSource,
+    index: usize,
+    source: Source,
+) -> Result<(MetaPoint, Data), Error> {
+    let (udt_amount, udt_layout, raw_order_data) = extract_udt_data(cells, index, source)?;
+
+    // Sizes are already checked, so mostly an unknown item id can fail verification
+    let order_data = match PartialOrderDataReader::from_slice(&raw_order_data) {
+        Ok(order_data) => order_data,
+        Err(_) => return Err(Error::InvalidAction),
+    };
+
+    let mint_master = |mint: MintOrderDataReader| -> Result<MetaPoint, Error> {
+        if mint.padding().raw_data() != [0u8; 32] {
+            return Err(Error::NonZeroPadding);
+        }
+        let master_distance = mint.master_distance().unpack();
+        let metapoint = extract_metapoint(cells, index, source)?;
+        Ok(MetaPoint {
+            tx_hash: metapoint.tx_hash,
+            index: metapoint.index + i64::from(master_distance),
+        })
+    };
+    let match_master = |match_: MatchOrderDataReader| -> MetaPoint {
+        let master_outpoint = match_.master_outpoint();
+        MetaPoint {
+            tx_hash: Some(master_outpoint.tx_hash().unpack()),
+            index: i64::from(master_outpoint.index().unpack()),
+        }
+    };
+
+    let (master_metapoint, order_info, terms) = match order_data.to_enum() {
+        PartialOrderDataUnionReader::MintOrderData(mint) => {
+            (mint_master(mint)?, mint.order_info(), None)
+        }
+        PartialOrderDataUnionReader::MatchOrderData(match_) => {
+            (match_master(match_), match_.order_info(), None)
+        }
+        PartialOrderDataUnionReader::MintOrderWithTerms(mint) => {
+            let order = mint.order();
+            (mint_master(order)?, order.order_info(), Some(mint.terms()))
+        }
+        PartialOrderDataUnionReader::MatchOrderWithTerms(match_) => {
+            let order = match_.order();
+            (
+                match_master(order),
+                order.order_info(),
+                Some(match_.terms()),
+            )
+        }
+    };
+    let expiry = extract_expiry(terms)?;
+    let max_matcher_fee = extract_max_matcher_fee(terms)?;
+
+    let load_ratio = |ratio: RatioReader| -> Result<Option<Ratio>, Error> {
+        let ckb_mul = C256::from(ratio.ckb_multiplier().unpack());
+        let udt_mul = C256::from(ratio.udt_multiplier().unpack());
+        match (ckb_mul.is_zero(), udt_mul.is_zero()) {
+            (false, false) => Ok(Some(Ratio { ckb_mul, udt_mul })),
+            (true, true) => Ok(None),
+            _ => Err(Error::InvalidRatio),
+        }
+    };
+
+    let ckb_to_udt = load_ratio(order_info.ckb_to_udt())?;
+    let udt_to_ckb = load_ratio(order_info.udt_to_ckb())?;
+    let ckb_min_match = match order_info.ckb_min_match_log().unpack() {
+        n @ 0..=64 => C256::from(1u128 << n),
+        _ => return Err(Error::InvalidCkbMinMatchLog),
+    };
+
+    // Validate both ratio
+    match (ckb_to_udt, udt_to_ckb) {
+        (Some(c2u), Some(u2c)) => {
+            // Check that if we convert from ckb to udt and then back from udt to ckb, it doesn't lose value.
+            // ((initial_ckb * c2u.ckb_mul / c2u.udt_mul) * u2c.udt_mul / u2c.ckb_mul) >= initial_ckb
+            // ~ initial_ckb * c2u.ckb_mul * u2c.udt_mul >= initial_ckb * c2u.udt_mul * u2c.ckb_mul
+            // ~ c2u.ckb_mul * u2c.udt_mul >= c2u.udt_mul * u2c.ckb_mul
+            if c2u.ckb_mul * u2c.udt_mul < c2u.udt_mul * u2c.ckb_mul {
+                return Err(Error::ConcaveRatio);
+            }
+        }
+        (None, None) => return Err(Error::BothRatioNull),
+        _ => (),
+    };
+
+    let ckb = C256::from(cells.load_cell_capacity(index, source)?);
+    let ckb_unoccupied = ckb - C256::from(cells.load_cell_occupied_capacity(index, source)?);
+
+    let udt = C256::from(udt_amount);
+    let udt_hash = match cells.load_cell_type_hash(index, source)? {
+        Some(h) => h,
+        None => return Err(Error::MissingUdtType),
+    };
+
+    let order_data = Data {
+        ckb,
+        udt,
+        ckb_unoccupied,
+        info: Info {
+            udt_hash,
+            udt_layout,
+            ckb_to_udt,
+            udt_to_ckb,
+            ckb_min_match,
+            expiry,
+            max_matcher_fee,
+        },
+    };
+
+    Ok((master_metapoint, order_data))
+}
+
+fn extract_max_matcher_fee(terms: Option<OrderTermsReader>) -> Result<C256, Error> {
+    match terms.and_then(|terms| terms.max_matcher_fee().to_opt()) {
+        None => Ok(C256::from(0u64)),
+        Some(fee) if fee.unpack() < MATCHER_FEE_DENOMINATOR => {
+            Ok(C256::from(u64::from(fee.unpack())))
+        }
+        Some(_) => Err(Error::InvalidMatcherFee),
+    }
+}
+
+// Since flags of the absolute block number and epoch metrics
+const SINCE_ABSOLUTE_BLOCK_NUMBER_FLAGS: u64 = 0x0000_0000_0000_0000;
+const SINCE_ABSOLUTE_EPOCH_FLAGS: u64 = 0x2000_0000_0000_0000;
+
+// Only absolute since by block number or by epoch are valid expiries
+fn extract_expiry(terms: Option<OrderTermsReader>) -> Result<Option<Expiry>, Error> {
+    let Some(expiry) = terms.and_then(|terms| terms.expiry().to_opt()) else {
+        return Ok(None);
+    };
+
+    let since = Since::new(expiry.since().unpack());
+    check_expiry_flags(since)?;
+
+    Ok(Some(Expiry {
+        since,
+        master_lock_hash: expiry.master_lock_hash().unpack(),
+    }))
+}
+
+// Flags are checked before any value extraction, as timestamp extraction can overflow
+fn check_expiry_flags(since: Since) -> Result<(), Error> {
+    match since.flags() {
+        SINCE_ABSOLUTE_BLOCK_NUMBER_FLAGS | SINCE_ABSOLUTE_EPOCH_FLAGS => Ok(()),
+        _ => Err(Error::InvalidExpiry),
+    }
+}
+
+// UDT amount, UDT layout and raw PartialOrderData of a limit order cell data, which is either:
+// - UdtData followed by PartialOrderData
+// - UDT data followed by PartialOrderData and UdtLayout, describing the UDT data
+fn extract_udt_data(
+    cells: &impl CellSource,
+    index: usize,
+    source: Source,
+) -> Result<(u128, UdtLayout, Vec<u8>), Error> {
+    // Large enough for the whole data of orders without UdtLayout nor terms
+    let mut data = [0u8; ORDER_SIZE];
+    let size = match cells.load_cell_data(&mut data, 0, index, source) {
+        Ok(size) | Err(SysError::LengthNotEnough(size)) if size >= ORDER_HEAD_SIZE => size,
+        Ok(_) => return Err(Error::Encoding),
+        Err(err) => return Err(Error::from(err)),
+    };
+    let udt_amount = udt_amount(&data[..UdtData::TOTAL_SIZE]);
+
+    // Without UdtLayout, UdtData is directly followed by PartialOrderData
+    let order_size = size - UdtData::TOTAL_SIZE;
+    let head = &data[UdtData::TOTAL_SIZE..ORDER_HEAD_SIZE];
+    if matches!(partial_order_size(head), Ok(size) if size == order_size) {
+        let mut order_data = vec![0u8; order_size];
+        if size <= ORDER_SIZE {
+            order_data.copy_from_slice(&data[UdtData::TOTAL_SIZE..size]);
+        } else {
+            load_exact(cells, &mut order_data, UdtData::TOTAL_SIZE, index, source)?;
+        }
+        return Ok((udt_amount, UdtLayout::Udt, order_data));
+    }
+
+    let mut raw_layout = [0u8; UdtLayoutData::TOTAL_SIZE];
+    if size < ORDER_HEAD_SIZE + UdtLayoutData::TOTAL_SIZE {
+        return Err(Error::Encoding);
+    }
+    load_exact(
+        cells,
+        &mut raw_layout,
+        size - UdtLayoutData::TOTAL_SIZE,
+        index,
+        source,
+    )?;
+    let layout = UdtLayoutReader::new_unchecked(&raw_layout);
+    let udt_data_size = layout.udt_data_size().unpack();
+    let order_offset = udt_data_size as usize;
+    if order_offset + ORDER_HEAD_SIZE + UdtLayoutData::TOTAL_SIZE > size {
+        return Err(Error::Encoding);
+    }
+    let order_size = size - order_offset - UdtLayoutData::TOTAL_SIZE;
+    let mut order_head = [0u8; ORDER_HEAD_SIZE - UdtData::TOTAL_SIZE];
+    load_exact(cells, &mut order_head, order_offset, index, source)?;
+    if partial_order_size(&order_head)? != order_size {
+        return Err(Error::Encoding);
+    }
+
+    let udt_layout = match layout.strategy().unpack() {
+        UDT_LAYOUT_XUDT_DATA if order_offset > UdtData::TOTAL_SIZE => {
+            let mut xudt_data = vec![0u8; order_offset - UdtData::TOTAL_SIZE];
+            load_exact(cells, &mut xudt_data, UdtData::TOTAL_SIZE, index, source)?;
+            if XudtDataReader::verify(&xudt_data, false).is_err() {
+                return Err(Error::InvalidUdtLayout);
+            }
+            UdtLayout::XudtData { udt_data_size }
+        }
+        _ => return Err(Error::InvalidUdtLayout),
+    };
+
+    let mut order_data = vec![0u8; order_size];
+    load_exact(cells, &mut order_data, order_offset, index, source)?;
+
+    Ok((udt_amount, udt_layout, order_data))
+}
+
+// Size of a PartialOrderData from its first bytes: the union item id,
+// followed by the total size of the item, if it's a table
+fn partial_order_size(head: &[u8]) -> Result<usize, Error> {
+    let read_u32 = |offset: usize| {
+        let mut raw = [0u8; NUMBER_SIZE];
+        raw.copy_from_slice(&head[offset..offset + NUMBER_SIZE]);
+        u32::from_le_bytes(raw) as usize
+    };
+    match read_u32(0) {
+        // MintOrderData and MatchOrderData
+        0 | 1 => Ok(PARTIAL_ORDER_SIZE),
+        // MintOrderWithTerms and MatchOrderWithTerms
+        2 | 3 => Ok(NUMBER_SIZE + read_u32(NUMBER_SIZE)),
+        _ => Err(Error::InvalidAction),
+    }
+}
+
+fn udt_amount(raw_udt_data: &[u8]) -> u128 {
+    UdtDataReader::new_unchecked(raw_udt_data)
+        .udt_amount()
+        .unpack()
+}
+
+// Fill buf with the cell data at offset, more data may follow
+fn load_exact(
+    cells: &impl CellSource,
+    buf: &mut [u8],
+    offset: usize,
+    index: usize,
+    source: Source,
+) -> Result<(), Error> {
+    match cells.load_cell_data(buf, offset, index, source) {
+        Ok(size) | Err(SysError::LengthNotEnough(size)) if size >= buf.len() => Ok(()),
+        Ok(_) => Err(Error::Encoding),
+        Err(err) => Err(Error::from(err)),
+    }
+}
+
+// MintOrderData and MatchOrderData share the same size
+const PARTIAL_ORDER_SIZE: usize = NUMBER_SIZE + MatchOrderData::TOTAL_SIZE;
+
+// Limit order cell data without UdtLayout nor terms is UdtData followed by PartialOrderData
+const ORDER_SIZE: usize = UdtData::TOTAL_SIZE + PARTIAL_ORDER_SIZE;
+
+// UdtData, then PartialOrderData item id and, for orders with terms, its total size
+const ORDER_HEAD_SIZE: usize = UdtData::TOTAL_SIZE + 2 * NUMBER_SIZE;
@@ -0,0 +1,111 @@
+use ckb_std::since::Since;
+use core::result::Result;
+use ickb_types::MATCHER_FEE_DENOMINATOR;
+use utils::C256;
+
+use crate::error::Error;
+
+pub fn validate(i: Data, o: Data) -> Result<(), Error> {
+    if i.info != o.info {
+        return Err(Error::DifferentInfo);
+    }
+
+    let (is_ckb_to_udt, Ratio { ckb_mul, udt_mul }, ckb_min_match) = match (
+        i.info.ckb_to_udt,
+        i.ckb > o.ckb,
+        i.info.udt_to_ckb,
+        i.udt > o.udt,
+    ) {
+        (Some(ratio), true, _, false) => (true, ratio, i.info.ckb_min_match),
+        (_, false, Some(ratio), true) => (false, ratio, i.info.ckb_min_match),
+        _ => return Err(Error::InvalidMatch),
+    };
+
+    // Check that limit order does not lose more value than the matcher fee allows
+    let i_value = i.ckb * ckb_mul + i.udt * udt_mul;
+    let o_value = o.ckb * ckb_mul + o.udt * udt_mul;
+    if i_value > o_value {
+        let taken_value = if is_ckb_to_udt {
+            (i.ckb - o.ckb) * ckb_mul
+        } else {
+            (i.udt - o.udt) * udt_mul
+        };
+        let fee_denominator = C256::from(u64::from(MATCHER_FEE_DENOMINATOR));
+        if (i_value - o_value) * fee_denominator > taken_value * i.info.max_matcher_fee {
+            return Err(Error::DecreasingValue);
+        }
+    }
+
+    // Validate limit order match
+    if is_ckb_to_udt {
+        // CKB -> UDT
+        // Check that an already fulfilled order is not modified
+        if i.ckb_unoccupied.is_zero() {
+            return Err(Error::AttemptToChangeFulfilled);
+        }
+
+        // DOS prevention: disallow partial match lower than the equivalent of ckb_min_match
+        if !o.ckb_unoccupied.is_zero() && i.ckb < o.ckb + ckb_min_match {
+            return Err(Error::InsufficientMatch);
+        }
+    } else {
+        // UDT -> CKB
+        // Check that an already fulfilled order is not modified
+        if i.udt.is_zero() {
+            return Err(Error::AttemptToChangeFulfilled);
+        }
+
+        // DOS prevention: disallow partial match lower than the equivalent of ckb_min_match
+        if !o.udt.is_zero() && i.udt * udt_mul < o.udt * udt_mul + ckb_min_match * ckb_mul {
+            return Err(Error::InsufficientMatch);
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct Data {
+    pub ckb: C256,
+    pub udt: C256,
+    pub ckb_unoccupied: C256,
+    pub info: Info,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct Info {
+    pub udt_hash: [u8; 32],
+    pub udt_layout: UdtLayout,
+    pub ckb_to_udt: Option<Ratio>,
+    pub udt_to_ckb: Option<Ratio>,
+    pub ckb_min_match: C256,
+    pub expiry: Option<Expiry>,
+    // Parts per million, zero if the order has no such term
+    pub max_matcher_fee: C256,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct Ratio {
+    pub ckb_mul: C256,
+    pub udt_mul: C256,
+}
+
+// Layout of the UDT data at the start of a limit order cell data, the UDT amount is always
+// its first 16 bytes, while the rest must be preserved by matches
+#[derive(Clone, Copy, PartialEq)]
+pub enum UdtLayout {
+    // Just UdtData, as for sUDT and xUDT without extension data, no trailer
+    Udt,
+    // UdtData followed by XudtData, udt_data_size bytes in total
+    XudtData { udt_data_size: u32 },
+}
+
+// After since, anyone can melt the order, as long as its CKB and UDT are refunded to cells
+// locked by master_lock_hash. Matches past since are not rejected: scripts only see lower bounds on
+// the commit time, input since and header deps, so no match can prove it is early enough. Stale
+// fills are only prevented by matchers skipping expired orders and by the melt racing them
+#[derive(Clone, Copy, PartialEq)]
+pub struct Expiry {
+    pub since: Since,
+    pub master_lock_hash: [u8; 32],
+}
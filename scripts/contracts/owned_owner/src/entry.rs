@@ -1,16 +1,12 @@
 use core::result::Result;
 
-use alloc::collections::BTreeMap;
 use ckb_std::{
     ckb_constants::Source,
-    high_level::{load_cell_lock_hash, load_cell_type_hash, load_script_hash, QueryIter},
-    syscalls::{load_cell_data, SysError},
+    error::SysError,
+    high_level::{load_cell_lock_hash, load_script_hash, QueryIter},
 };
-use utils::{
-    extract_metapoint, has_dao_type, has_empty_args, is_withdrawal_request_data, MetaPoint,
-};
-
-use crate::error::Error;
+use owned_owner::{check_owned_owner, error::Error};
+use utils::{has_empty_args, Syscalls};
 
 pub fn main() -> Result<(), Error> {
     if !has_empty_args()? {
@@ -18,73 +14,26 @@ pub fn main() -> Result<(), Error> {
     }
 
     let script_hash = load_script_hash()?;
-    let is_script = |index: usize, source: Source| {
-        Ok((
-            load_cell_lock_hash(index, source)? == script_hash,
-            load_cell_type_hash(index, source)? == Some(script_hash),
-        ))
-    };
-
-    let default = Accounting { owned: 0, owner: 0 };
-    for source in [Source::Input, Source::Output] {
-        let mut metapoint_2_accounting: BTreeMap<MetaPoint, Accounting> = BTreeMap::new();
-
-        for (index, is_script) in QueryIter::new(is_script, source).enumerate() {
-            match is_script {
-                (false, false) => (),
-                (false, true) => {
-                    // Owner Cell
-                    let metapoint = extract_owned_metapoint(index, source)?;
-                    let accounting = metapoint_2_accounting.entry(metapoint).or_insert(default);
-                    accounting.owner += 1;
-                }
-                (true, false) => {
-                    // Owned Cell
-
-                    // Check that is a Withdrawal Request
-                    if !has_dao_type(index, source)? || !is_withdrawal_request_data(index, source) {
-                        return Err(Error::NotWithdrawalRequest);
-                    }
 
-                    let metapoint = extract_metapoint(index, source)?;
-                    let accounting = metapoint_2_accounting.entry(metapoint).or_insert(default);
-                    accounting.owned += 1;
-                }
-                (true, true) => return Err(Error::ScriptMisuse),
-            }
-        }
-
-        if metapoint_2_accounting
-            .into_values()
-            .any(|a| a.owned != 1 || a.owner != 1)
-        {
-            return Err(Error::Mismatch);
-        }
+    // Both the lock group and the type group validate the whole transaction, so when the lock
+    // group runs, as an input is locked by this script, the type group skips the same validation
+    if !is_lock_group(script_hash)? && is_input_lock(script_hash) {
+        return Ok(());
     }
 
-    Ok(())
+    check_owned_owner(&Syscalls, script_hash)
 }
 
-#[derive(Clone, Copy)]
-struct Accounting {
-    owned: u64,
-    owner: u64,
+// Inputs of the lock group are locked by this script, while inputs of the type group, if any,
+// are not, as a cell using this script as both lock and type is rejected by check_owned_owner
+fn is_lock_group(script_hash: [u8; 32]) -> Result<bool, Error> {
+    match load_cell_lock_hash(0, Source::GroupInput) {
+        Ok(lock_hash) => Ok(lock_hash == script_hash),
+        Err(SysError::IndexOutOfBound) => Ok(false),
+        Err(err) => Err(Error::from(err)),
+    }
 }
 
-const OWNED_DISTANCE_SIZE: usize = 4;
-
-fn extract_owned_metapoint(index: usize, source: Source) -> Result<MetaPoint, Error> {
-    let metapoint = extract_metapoint(index, source)?;
-
-    let mut data = [0u8; OWNED_DISTANCE_SIZE];
-    let d = match load_cell_data(&mut data, 0, index, source) {
-        Ok(OWNED_DISTANCE_SIZE) | Err(SysError::LengthNotEnough(_)) => i32::from_le_bytes(data),
-        Ok(_) => return Err(Error::Encoding),
-        Err(err) => return Err(Error::from(err)),
-    };
-
-    Ok(MetaPoint {
-        tx_hash: metapoint.tx_hash,
-        index: metapoint.index + i64::from(d),
-    })
+fn is_input_lock(script_hash: [u8; 32]) -> bool {
+    QueryIter::new(load_cell_lock_hash, Source::Input).any(|lock_hash| lock_hash == script_hash)
 }
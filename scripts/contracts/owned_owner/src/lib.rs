@@ -0,0 +1,9 @@
+//! Owned owner validation logic, shared by the script and host side tests.
+
+#![no_std]
+extern crate alloc;
+
+pub mod error;
+mod validate;
+
+pub use validate::{check_owned_owner, extract_owned_metapoint};
@@ -2,14 +2,13 @@
 //!
 //! `main.rs` is used to define rust lang items and modules.
 //! See `entry.rs` for the `main` function.
-//! See `error.rs` for the `Error` type.
+//! See `error.rs` for the `Error` type and `validate.rs` for the cells validation, both in `lib.rs`.
 
 #![no_std]
 #![cfg_attr(not(test), no_main)]
 
 // define modules
 mod entry;
-mod error;
 
 #[cfg(test)]
 extern crate alloc;
@@ -0,0 +1,88 @@
+use core::result::Result;
+
+use alloc::collections::BTreeMap;
+use ckb_std::{ckb_constants::Source, high_level::QueryIter, syscalls::SysError};
+use ickb_types::{molecule::prelude::Reader, OwnedOwnerData, OwnedOwnerDataReader};
+use utils::{extract_metapoint, is_withdrawal_request_data, CellSource, MetaPoint, DAO_HASH};
+
+use crate::error::Error;
+
+pub fn check_owned_owner(cells: &impl CellSource, script_hash: [u8; 32]) -> Result<(), Error> {
+    // Type hash is loaded once and reused for the Withdrawal Request check
+    let load_hashes = |index: usize, source: Source| {
+        Ok((
+            cells.load_cell_lock_hash(index, source)?,
+            cells.load_cell_type_hash(index, source)?,
+        ))
+    };
+
+    let default = Accounting { owned: 0, owner: 0 };
+    for source in [Source::Input, Source::Output] {
+        let mut metapoint_2_accounting: BTreeMap<MetaPoint, Accounting> = BTreeMap::new();
+
+        for (index, (lock_hash, type_hash)) in QueryIter::new(load_hashes, source).enumerate() {
+            match (lock_hash == script_hash, type_hash == Some(script_hash)) {
+                (false, false) => (),
+                (false, true) => {
+                    // Owner Cell
+                    let metapoint = extract_owned_metapoint(cells, index, source)?;
+                    let accounting = metapoint_2_accounting.entry(metapoint).or_insert(default);
+                    accounting.owner += 1;
+                }
+                (true, false) => {
+                    // Owned Cell
+
+                    // Check that is a Withdrawal Request
+                    if type_hash != Some(DAO_HASH)
+                        || !is_withdrawal_request_data(cells, index, source)
+                    {
+                        return Err(Error::NotWithdrawalRequest);
+                    }
+
+                    let metapoint = extract_metapoint(cells, index, source)?;
+                    let accounting = metapoint_2_accounting.entry(metapoint).or_insert(default);
+                    accounting.owned += 1;
+                }
+                (true, true) => return Err(Error::ScriptMisuse),
+            }
+        }
+
+        if metapoint_2_accounting
+            .into_values()
+            .any(|a| a.owned != 1 || a.owner != 1)
+        {
+            return Err(Error::Mismatch);
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Copy)]
+struct Accounting {
+    owned: u64,
+    owner: u64,
+}
+
+pub fn extract_owned_metapoint(
+    cells: &impl CellSource,
+    index: usize,
+    source: Source,
+) -> Result<MetaPoint, Error> {
+    let metapoint = extract_metapoint(cells, index, source)?;
+
+    let mut data = [0u8; OwnedOwnerData::TOTAL_SIZE];
+    match cells.load_cell_data(&mut data, 0, index, source) {
+        Ok(OwnedOwnerData::TOTAL_SIZE) | Err(SysError::LengthNotEnough(_)) => (),
+        Ok(_) => return Err(Error::Encoding),
+        Err(err) => return Err(Error::from(err)),
+    };
+    let d = OwnedOwnerDataReader::new_unchecked(&data)
+        .owned_distance()
+        .unpack();
+
+    Ok(MetaPoint {
+        tx_hash: metapoint.tx_hash,
+        index: metapoint.index + i64::from(d),
+    })
+}
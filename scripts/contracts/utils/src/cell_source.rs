@@ -0,0 +1,230 @@
+use alloc::vec::Vec;
+use core::result::Result;
+
+use ckb_std::{
+    ckb_constants::{InputField, Source},
+    error::SysError,
+    high_level, syscalls,
+};
+
+// Cell loading used by the scripts validation logic, so the same logic can run both on-chain
+// with Syscalls and on the host with MemoryCellSource
+pub trait CellSource {
+    // Same semantic as ckb_std::syscalls::load_cell_data
+    fn load_cell_data(
+        &self,
+        buf: &mut [u8],
+        offset: usize,
+        index: usize,
+        source: Source,
+    ) -> Result<usize, SysError>;
+
+    // Same semantic as ckb_std::syscalls::load_header
+    fn load_header(
+        &self,
+        buf: &mut [u8],
+        offset: usize,
+        index: usize,
+        source: Source,
+    ) -> Result<usize, SysError>;
+
+    fn load_cell_capacity(&self, index: usize, source: Source) -> Result<u64, SysError>;
+
+    fn load_cell_occupied_capacity(&self, index: usize, source: Source) -> Result<u64, SysError>;
+
+    fn load_cell_lock_hash(&self, index: usize, source: Source) -> Result<[u8; 32], SysError>;
+
+    fn load_cell_type_hash(
+        &self,
+        index: usize,
+        source: Source,
+    ) -> Result<Option<[u8; 32]>, SysError>;
+
+    // OutPoint of an input cell as tx_hash and index
+    fn load_input_out_point(
+        &self,
+        index: usize,
+        source: Source,
+    ) -> Result<([u8; 32], u32), SysError>;
+
+    // Raw since of an input
+    fn load_input_since(&self, index: usize, source: Source) -> Result<u64, SysError>;
+}
+
+// On-chain CellSource, backed by ckb-std syscalls
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Syscalls;
+
+const TX_HASH_SIZE: usize = 32;
+const INDEX_SIZE: usize = 4;
+const OUT_POINT_SIZE: usize = TX_HASH_SIZE + INDEX_SIZE;
+
+impl CellSource for Syscalls {
+    fn load_cell_data(
+        &self,
+        buf: &mut [u8],
+        offset: usize,
+        index: usize,
+        source: Source,
+    ) -> Result<usize, SysError> {
+        syscalls::load_cell_data(buf, offset, index, source)
+    }
+
+    fn load_header(
+        &self,
+        buf: &mut [u8],
+        offset: usize,
+        index: usize,
+        source: Source,
+    ) -> Result<usize, SysError> {
+        syscalls::load_header(buf, offset, index, source)
+    }
+
+    fn load_cell_capacity(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        high_level::load_cell_capacity(index, source)
+    }
+
+    fn load_cell_occupied_capacity(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        high_level::load_cell_occupied_capacity(index, source)
+    }
+
+    fn load_cell_lock_hash(&self, index: usize, source: Source) -> Result<[u8; 32], SysError> {
+        high_level::load_cell_lock_hash(index, source)
+    }
+
+    fn load_cell_type_hash(
+        &self,
+        index: usize,
+        source: Source,
+    ) -> Result<Option<[u8; 32]>, SysError> {
+        high_level::load_cell_type_hash(index, source)
+    }
+
+    fn load_input_out_point(
+        &self,
+        index: usize,
+        source: Source,
+    ) -> Result<([u8; 32], u32), SysError> {
+        let mut d = [0u8; OUT_POINT_SIZE];
+        match syscalls::load_input_by_field(&mut d, 0, index, source, InputField::OutPoint) {
+            Ok(OUT_POINT_SIZE) => Ok((
+                d[..TX_HASH_SIZE].try_into().unwrap(),
+                u32::from_le_bytes(d[TX_HASH_SIZE..].try_into().unwrap()),
+            )),
+            Ok(_) => Err(SysError::Encoding),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn load_input_since(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        high_level::load_input_since(index, source)
+    }
+}
+
+// Host side CellSource, cells are looked up by their position in inputs, outputs and cell deps
+#[derive(Clone, Debug, Default)]
+pub struct MemoryCellSource {
+    pub inputs: Vec<MemoryCell>,
+    pub outputs: Vec<MemoryCell>,
+    pub cell_deps: Vec<MemoryCell>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct MemoryCell {
+    // Only input cells have an OutPoint and a since
+    pub out_point: Option<([u8; 32], u32)>,
+    pub since: u64,
+    pub capacity: u64,
+    pub occupied_capacity: u64,
+    pub lock_hash: [u8; 32],
+    pub type_hash: Option<[u8; 32]>,
+    pub data: Vec<u8>,
+    // Raw header of the block including the cell, if available as header dep
+    pub header: Option<Vec<u8>>,
+}
+
+impl MemoryCellSource {
+    fn cell(&self, index: usize, source: Source) -> Result<&MemoryCell, SysError> {
+        let cells = match source {
+            Source::Input => &self.inputs,
+            Source::Output => &self.outputs,
+            Source::CellDep => &self.cell_deps,
+            _ => return Err(SysError::IndexOutOfBound),
+        };
+        cells.get(index).ok_or(SysError::IndexOutOfBound)
+    }
+}
+
+// Partial loading as done by CKB-VM: at most buf.len() bytes are copied, while the returned
+// length is always the full length available from offset
+fn load_slice(data: &[u8], buf: &mut [u8], offset: usize) -> Result<usize, SysError> {
+    let data = &data[offset.min(data.len())..];
+    let size = data.len().min(buf.len());
+    buf[..size].copy_from_slice(&data[..size]);
+    if data.len() > buf.len() {
+        return Err(SysError::LengthNotEnough(data.len()));
+    }
+    Ok(data.len())
+}
+
+impl CellSource for MemoryCellSource {
+    fn load_cell_data(
+        &self,
+        buf: &mut [u8],
+        offset: usize,
+        index: usize,
+        source: Source,
+    ) -> Result<usize, SysError> {
+        load_slice(&self.cell(index, source)?.data, buf, offset)
+    }
+
+    fn load_header(
+        &self,
+        buf: &mut [u8],
+        offset: usize,
+        index: usize,
+        source: Source,
+    ) -> Result<usize, SysError> {
+        match &self.cell(index, source)?.header {
+            Some(header) => load_slice(header, buf, offset),
+            None => Err(SysError::ItemMissing),
+        }
+    }
+
+    fn load_cell_capacity(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        Ok(self.cell(index, source)?.capacity)
+    }
+
+    fn load_cell_occupied_capacity(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        Ok(self.cell(index, source)?.occupied_capacity)
+    }
+
+    fn load_cell_lock_hash(&self, index: usize, source: Source) -> Result<[u8; 32], SysError> {
+        Ok(self.cell(index, source)?.lock_hash)
+    }
+
+    fn load_cell_type_hash(
+        &self,
+        index: usize,
+        source: Source,
+    ) -> Result<Option<[u8; 32]>, SysError> {
+        Ok(self.cell(index, source)?.type_hash)
+    }
+
+    fn load_input_out_point(
+        &self,
+        index: usize,
+        source: Source,
+    ) -> Result<([u8; 32], u32), SysError> {
+        self.cell(index, source)?
+            .out_point
+            .ok_or(SysError::IndexOutOfBound)
+    }
+
+    fn load_input_since(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        match source {
+            Source::Input => Ok(self.cell(index, source)?.since),
+            _ => Err(SysError::IndexOutOfBound),
+        }
+    }
+}
@@ -16,9 +16,6 @@ pub const DAO_HASH: [u8; 32] =
 pub const DAO_DEPOSIT_DATA: [u8; 8] = [0, 0, 0, 0, 0, 0, 0, 0];
 pub const DAO_DEPOSIT_DATA_SIZE: usize = DAO_DEPOSIT_DATA.len();
 
-// https://github.com/nervosnetwork/rfcs/blob/master/rfcs/0023-dao-deposit-withdraw/0023-dao-deposit-withdraw.md#calculation
-pub const GENESIS_ACCUMULATED_RATE: u128 = 10_000_000_000_000_000; // 10^16 Genesis block accumulated rate
-
 // UDT
 
 // https://github.com/nervosnetwork/rfcs/blob/master/rfcs/0025-simple-udt/0025-simple-udt.md#sudt-cell
@@ -29,6 +26,13 @@ pub const XUDT_CODE_HASH: [u8; 32] =
     from_hex("0x50bd8d6680b8b9cf98b73f3c08faf8b2a21914311954118ad6609be6e78a1b95");
 pub const XUDT_HASH_TYPE: u8 = ScriptHashType::Data1 as u8;
 
+// Type ID
+
+// https://github.com/nervosnetwork/rfcs/blob/master/rfcs/0022-transaction-structure/0022-transaction-structure.md#type-id
+pub const TYPE_ID_CODE_HASH: [u8; 32] =
+    from_hex("0x00000000000000000000000000000000000000000000000000545950455f4944");
+pub const TYPE_ID_HASH_TYPE: u8 = ScriptHashType::Type as u8;
+
 // Hex utils
 
 const fn from_hex(hex_string: &str) -> [u8; 32] {
@@ -2,11 +2,13 @@
 extern crate alloc;
 
 mod c256;
+mod cell_source;
 mod constants;
 mod dao;
 mod utils;
 
 pub use c256::*;
+pub use cell_source::*;
 pub use constants::*;
 pub use dao::*;
 pub use utils::*;
@@ -1,70 +1,81 @@
+use alloc::vec::Vec;
 use core::result::Result;
 
 use ckb_std::{
-    ckb_constants::{InputField, Source},
+    ckb_constants::Source,
     error::SysError,
-    high_level::{
-        load_cell_capacity, load_cell_lock, load_cell_occupied_capacity, load_script, QueryIter,
-    },
-    syscalls::{load_cell_data, load_header, load_input_by_field},
+    high_level::{load_cell_lock, load_script, QueryIter},
 };
 
-use crate::constants::UDT_SIZE;
+use crate::{constants::UDT_SIZE, CellSource};
 
 pub fn has_empty_args() -> Result<bool, SysError> {
+    // Script args cover input lock args, input type args and output type args,
+    // while output lock args are covered by load_unique_args
+    Ok(load_unique_args()?.is_some_and(|args| args.is_empty()))
+}
+
+// Args of the current script, None if an output is locked by the same code with different args,
+// as output locks are not executed, so no other instance of this script would validate them
+pub fn load_unique_args() -> Result<Option<Vec<u8>>, SysError> {
     let s = load_script()?;
     let code_hash = s.code_hash();
     let hash_type = s.hash_type();
     let args = s.args();
 
-    //The following check covers:
-    // - Input lock args
-    // - Input type args
-    // - Output type args
-    if !args.is_empty() {
-        return Ok(false);
-    }
-
-    //Check that Output lock args are empty
+    //Check that Output lock args are the same
     if QueryIter::new(load_cell_lock, Source::Output)
         .any(|s| code_hash == s.code_hash() && hash_type == s.hash_type() && args != s.args())
     {
-        return Ok(false);
+        return Ok(None);
     }
 
-    Ok(true)
+    Ok(Some(args.raw_data().to_vec()))
 }
 
-pub fn extract_udt_amount(index: usize, source: Source) -> Result<u128, SysError> {
+pub fn extract_udt_amount(
+    cells: &impl CellSource,
+    index: usize,
+    source: Source,
+) -> Result<u128, SysError> {
     let mut data = [0u8; UDT_SIZE];
-    match load_cell_data(&mut data, 0, index, source) {
+    match cells.load_cell_data(&mut data, 0, index, source) {
         Ok(UDT_SIZE) | Err(SysError::LengthNotEnough(_)) => Ok(u128::from_le_bytes(data)),
         Ok(_) => Err(SysError::Encoding),
         Err(err) => Err(err),
     }
 }
 
-pub fn extract_unused_capacity(index: usize, source: Source) -> Result<u64, SysError> {
-    Ok(load_cell_capacity(index, source)? - load_cell_occupied_capacity(index, source)?)
+pub fn extract_unused_capacity(
+    cells: &impl CellSource,
+    index: usize,
+    source: Source,
+) -> Result<u64, SysError> {
+    Ok(cells.load_cell_capacity(index, source)?
+        - cells.load_cell_occupied_capacity(index, source)?)
 }
 
 const AR_OFFSET: usize = 160 + 8;
 const AR_SIZE: usize = 8;
 
-pub fn extract_accumulated_rate(index: usize, source: Source) -> Result<u64, SysError> {
+pub fn extract_accumulated_rate(
+    cells: &impl CellSource,
+    index: usize,
+    source: Source,
+) -> Result<u64, SysError> {
     let mut data = [0u8; AR_SIZE];
-    match load_header(&mut data, AR_OFFSET, index, source) {
+    match cells.load_header(&mut data, AR_OFFSET, index, source) {
         Ok(AR_SIZE) | Err(SysError::LengthNotEnough(_)) => Ok(u64::from_le_bytes(data)),
         Ok(_) => Err(SysError::Encoding),
         Err(err) => Err(err),
     }
 }
 
-const TX_HASH_SIZE: usize = 32;
-const INDEX_SIZE: usize = 4;
-const OUT_POINT_SIZE: usize = TX_HASH_SIZE + INDEX_SIZE;
-
-pub fn extract_metapoint(index: usize, source: Source) -> Result<MetaPoint, SysError> {
+pub fn extract_metapoint(
+    cells: &impl CellSource,
+    index: usize,
+    source: Source,
+) -> Result<MetaPoint, SysError> {
     if source == Source::Output {
         return Ok(MetaPoint {
             tx_hash: None,
@@ -72,15 +83,11 @@ pub fn extract_metapoint(index: usize, source: Source) -> Result<MetaPoint, SysE
         });
     }
 
-    let mut d = [0u8; OUT_POINT_SIZE];
-    match load_input_by_field(&mut d, 0, index, source, InputField::OutPoint) {
-        Ok(OUT_POINT_SIZE) => Ok(MetaPoint {
-            tx_hash: Some(d[..TX_HASH_SIZE].try_into().unwrap()),
-            index: i64::from(u32::from_le_bytes(d[TX_HASH_SIZE..].try_into().unwrap())),
-        }),
-        Ok(_) => Err(SysError::Encoding),
-        Err(err) => Err(err),
-    }
+    let (tx_hash, index) = cells.load_input_out_point(index, source)?;
+    Ok(MetaPoint {
+        tx_hash: Some(tx_hash),
+        index: i64::from(index),
+    })
 }
 
 // MetaPoint is an extension of OutPoint functionalities
@@ -1,13 +1,52 @@
 #! /bin/bash
-genesis_block=$(ckb-cli rpc get_block_by_number --number 0 --no-color);
-export genesis_tx_hash=$(echo "$genesis_block" | grep --color=never -Po 'hash:\s\K.*' | head -n 5 | tail -n 1);
-export address="ckt1qzda0cr08m85hc8jlnfp3zer7xulejywt49kt2rr0vthywaa50xwsqwgx292hnvmn68xf779vmzrshpmm6epn4c0cgwga";
+# Usage: [funding=tx_hash:index:capacity] [ckb_rpc=http://127.0.0.1:8114] ./deploy_on_new_devchain.sh
+# funding is a live cell of $address, by default its largest plain cell as returned by the node indexer,
+# the genesis hashes and the secp256k1_blake160 dep group are read from the genesis block via ckb_rpc
+ckb_rpc="${ckb_rpc:-http://127.0.0.1:8114}";
+address="ckt1qzda0cr08m85hc8jlnfp3zer7xulejywt49kt2rr0vthywaa50xwsqwgx292hnvmn68xf779vmzrshpmm6epn4c0cgwga";
+address_lock_args="0xc8328aabcd9b9e8e64fbc566c4385c3bdeb219d7";
 
-cat template.toml | envsubst > deployment.toml;
-rm -fr migrations/*;
-rm deploy-tx.json;
+for command in ckb-cli curl jq; do
+    command -v $command > /dev/null || { echo "missing $command" >&2; exit 1; };
+done
 
-ckb-cli deploy gen-txs --deployment-config ./deployment.toml --migration-dir ./migrations --from-address $address --sign-now --info-file deploy-tx.json;
-ckb-cli deploy apply-txs --info-file ./deploy-tx.json --migration-dir ./migrations;
+rpc() {
+    curl -sf -X POST -H 'Content-Type: application/json' \
+        -d "{\"id\": 0, \"jsonrpc\": \"2.0\", \"method\": \"$1\", \"params\": $2}" \
+        "$ckb_rpc" || { echo "no CKB node at $ckb_rpc" >&2; exit 1; };
+}
 
-mv migrations/* migrations/latest.json;
+if [[ -z "$funding" ]]; then
+    # Live cells of $address without type and data, the genesis issued cells come first
+    address_lock='{"code_hash": "0x9bd7e06f3ecf4be0f2fcd2188b23f1b9fcc88e5d4b65a8637b17723bbda3cce8", "hash_type": "type", "args": "'$address_lock_args'"}';
+    search_key='{"script": '$address_lock', "script_type": "lock", "filter": {"output_data_len_range": ["0x0", "0x1"]}, "with_data": false}';
+    cell=$(rpc get_cells "[$search_key, \"asc\", \"0x64\"]" | jq -er '
+        [.result.objects[] | select(.output.type == null)]
+        | max_by(.output.capacity | ltrimstr("0x") | [length, .])
+        | [.out_point.tx_hash, .out_point.index, .output.capacity] | join(" ")') \
+        || { echo "no live cell of $address, is the node indexer enabled?" >&2; exit 1; };
+    read -r tx_hash index capacity <<< "$cell";
+    funding="$tx_hash:$((index)):$((capacity))";
+fi
+
+if [[ ! "$funding" =~ ^0x[0-9a-fA-F]{64}:[0-9]+:[0-9]+$ ]]; then
+    echo "funding must be tx_hash:index:capacity, with capacity in shannons, got: '$funding'" >&2;
+    exit 1;
+fi
+
+genesis_block=$(rpc get_block_by_number '["0x0"]') || exit 1;
+# Genesis cellbase, holding the system scripts, followed by the dep groups transaction,
+# whose first output is the secp256k1_blake160_sighash_all dep group
+genesis_tx_hash=$(echo "$genesis_block" | jq -er '.result.transactions[0].hash') || exit 1;
+secp_dep_group="$(echo "$genesis_block" | jq -er '.result.transactions[1].hash'):0" || exit 1;
+
+rm -fr migrations/* deploy-tx-*.json;
+
+cargo run --manifest-path ../../Cargo.toml -p ickb_tools --bin devnet -- \
+    --genesis-tx-hash $genesis_tx_hash --devnet-dir . --release-dir ../../build/release \
+    --funding $funding --funding-lock-args $address_lock_args --funding-cell-dep $secp_dep_group || exit 1;
+
+for tx_file in deploy-tx-0.json deploy-tx-1.json; do
+    ckb-cli tx sign-inputs --tx-file $tx_file --from-account $address --add-signatures || exit 1;
+    ckb-cli tx send --tx-file $tx_file || exit 1;
+done
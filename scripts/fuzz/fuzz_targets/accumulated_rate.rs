@@ -0,0 +1,44 @@
+#![no_main]
+
+use ckb_std::{ckb_constants::Source, error::SysError};
+use libfuzzer_sys::fuzz_target;
+use utils::{extract_accumulated_rate, MemoryCell, MemoryCellSource};
+
+// Accumulated rate is the little endian u64 at bytes 168..176 of the header, within the DAO field
+const AR_OFFSET: usize = 160 + 8;
+const AR_SIZE: usize = 8;
+const HEADER_SIZE: usize = 208;
+
+fn cells(header: Option<Vec<u8>>) -> MemoryCellSource {
+    MemoryCellSource {
+        inputs: vec![MemoryCell {
+            header,
+            ..MemoryCell::default()
+        }],
+        ..MemoryCellSource::default()
+    }
+}
+
+fuzz_target!(|header: Option<Vec<u8>>| {
+    let rate = extract_accumulated_rate(&cells(header.clone()), 0, Source::Input);
+    let Some(header) = header else {
+        assert_eq!(rate, Err(SysError::ItemMissing));
+        return;
+    };
+    if header.len() < AR_OFFSET + AR_SIZE {
+        assert_eq!(rate, Err(SysError::Encoding));
+        return;
+    }
+
+    let mut expected = [0u8; AR_SIZE];
+    expected.copy_from_slice(&header[AR_OFFSET..AR_OFFSET + AR_SIZE]);
+    let expected = u64::from_le_bytes(expected);
+    assert_eq!(rate, Ok(expected));
+
+    let mut encoded = vec![0u8; HEADER_SIZE];
+    encoded[AR_OFFSET..AR_OFFSET + AR_SIZE].copy_from_slice(&expected.to_le_bytes());
+    assert_eq!(
+        extract_accumulated_rate(&cells(Some(encoded)), 0, Source::Input),
+        Ok(expected)
+    );
+});
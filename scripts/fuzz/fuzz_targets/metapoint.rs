@@ -0,0 +1,29 @@
+#![no_main]
+
+use ckb_std::error::SysError;
+use ickb_fuzz::Position;
+use libfuzzer_sys::fuzz_target;
+use utils::{extract_metapoint, MemoryCell, MetaPoint};
+
+// Inputs are identified by their OutPoint, outputs by their index
+fuzz_target!(|position: Position| {
+    let cells = position.cells(MemoryCell::default());
+    let metapoint = extract_metapoint(&cells, position.index(), position.source());
+    let expected = match position {
+        Position::Input {
+            out_point: Some((tx_hash, index)),
+            ..
+        } => Ok(MetaPoint {
+            tx_hash: Some(tx_hash),
+            index: i64::from(index),
+        }),
+        Position::Input {
+            out_point: None, ..
+        } => Err(SysError::IndexOutOfBound),
+        Position::Output { index } => Ok(MetaPoint {
+            tx_hash: None,
+            index: i64::from(index),
+        }),
+    };
+    assert_eq!(metapoint, expected);
+});
@@ -0,0 +1,224 @@
+#![no_main]
+
+use ckb_std::since::Since;
+use ckb_types::{packed::OutPoint, prelude::*};
+use ickb_fuzz::Position;
+use ickb_sdk::order::{encode_order_data, OrderAction, OrderExpiry, OrderInfo, Ratio, UdtLayout};
+use ickb_types::{molecule::prelude::Byte, XudtData, MATCHER_FEE_DENOMINATOR};
+use libfuzzer_sys::{
+    arbitrary::{self, Arbitrary},
+    fuzz_target,
+};
+use limit_order::{extract_order, Data, Expiry};
+use utils::{extract_metapoint, MemoryCell, MetaPoint, C256};
+
+const OCCUPIED: u64 = 200 * 100_000_000;
+const UDT_HASH: [u8; 32] = [5u8; 32];
+
+#[derive(Arbitrary, Debug)]
+enum Input {
+    // Garbage cell, decoding can fail but never panic
+    Raw {
+        position: Position,
+        data: Vec<u8>,
+        capacity: u64,
+        occupied_capacity: u64,
+        type_hash: Option<[u8; 32]>,
+    },
+    // Cell data from the SDK encoder, decoding agrees with is_valid and round-trips
+    Encoded {
+        position: Position,
+        order: Order,
+    },
+}
+
+#[derive(Arbitrary, Debug)]
+struct Order {
+    udt_amount: u128,
+    unoccupied: u32,
+    xudt_lock: Option<Vec<u8>>,
+    master: Master,
+    ckb_to_udt: (u64, u64),
+    udt_to_ckb: (u64, u64),
+    ckb_min_match_log: u8,
+    expiry: Option<(u64, [u8; 32])>,
+    max_matcher_fee: Option<u32>,
+}
+
+#[derive(Arbitrary, Debug)]
+enum Master {
+    Mint { distance: i32 },
+    Match { tx_hash: [u8; 32], index: u32 },
+}
+
+fn ratio((ckb_multiplier, udt_multiplier): (u64, u64)) -> Ratio {
+    Ratio {
+        ckb_multiplier,
+        udt_multiplier,
+    }
+}
+
+fn decoded_ratio(r: Ratio) -> Option<limit_order::Ratio> {
+    (!r.is_null()).then(|| limit_order::Ratio {
+        ckb_mul: C256::from(r.ckb_multiplier),
+        udt_mul: C256::from(r.udt_multiplier),
+    })
+}
+
+// Terms accepted by the script, written out independently of the script and the SDK
+fn is_valid(info: &OrderInfo) -> bool {
+    let expiry = info
+        .expiry
+        .map_or(true, |e| matches!(e.since >> 56, 0x00 | 0x20));
+    let fee = info
+        .max_matcher_fee
+        .map_or(true, |fee| fee < MATCHER_FEE_DENOMINATOR);
+    let ratio = |r: &Ratio| (r.ckb_multiplier == 0) == (r.udt_multiplier == 0);
+    let (c2u, u2c) = (&info.ckb_to_udt, &info.udt_to_ckb);
+    let convex = c2u.is_null()
+        || u2c.is_null()
+        || u128::from(c2u.ckb_multiplier) * u128::from(u2c.udt_multiplier)
+            >= u128::from(c2u.udt_multiplier) * u128::from(u2c.ckb_multiplier);
+    expiry
+        && fee
+        && ratio(c2u)
+        && ratio(u2c)
+        && info.ckb_min_match_log <= 64
+        && !(c2u.is_null() && u2c.is_null())
+        && convex
+}
+
+fn check_encoded(position: Position, order: Order) {
+    let info = OrderInfo {
+        ckb_to_udt: ratio(order.ckb_to_udt),
+        udt_to_ckb: ratio(order.udt_to_ckb),
+        ckb_min_match_log: order.ckb_min_match_log,
+        expiry: order.expiry.map(|(since, master_lock_hash)| OrderExpiry {
+            since,
+            master_lock_hash,
+        }),
+        max_matcher_fee: order.max_matcher_fee,
+    };
+    let udt_layout = match &order.xudt_lock {
+        None => UdtLayout::Udt,
+        Some(lock) => {
+            let lock = ickb_types::Bytes::new_builder()
+                .set(lock.iter().copied().map(Byte::new).collect())
+                .build();
+            UdtLayout::XudtData(XudtData::new_builder().lock(lock).build().as_bytes())
+        }
+    };
+    let action = match order.master {
+        Master::Mint { distance } => OrderAction::Mint {
+            master_distance: distance,
+        },
+        Master::Match { tx_hash, index } => OrderAction::Match {
+            master: OutPoint::new_builder()
+                .tx_hash(tx_hash.pack())
+                .index(index.pack())
+                .build(),
+        },
+    };
+
+    let data = encode_order_data(order.udt_amount, &udt_layout, &action, &info);
+    let capacity = OCCUPIED + u64::from(order.unoccupied);
+    let cells = position.cells(MemoryCell {
+        capacity,
+        occupied_capacity: OCCUPIED,
+        type_hash: Some(UDT_HASH),
+        data: data.to_vec(),
+        ..MemoryCell::default()
+    });
+    let decoded = extract_order(&cells, position.index(), position.source());
+
+    let metapoint = extract_metapoint(&cells, position.index(), position.source());
+    let master = match (&order.master, metapoint) {
+        (Master::Mint { .. }, Err(_)) => {
+            // Input without OutPoint
+            assert!(decoded.is_err());
+            return;
+        }
+        (Master::Mint { distance }, Ok(metapoint)) => MetaPoint {
+            tx_hash: metapoint.tx_hash,
+            index: metapoint.index + i64::from(*distance),
+        },
+        (Master::Match { tx_hash, index }, _) => MetaPoint {
+            tx_hash: Some(*tx_hash),
+            index: i64::from(*index),
+        },
+    };
+
+    if !is_valid(&info) {
+        assert!(decoded.is_err());
+        return;
+    }
+    let Ok((
+        decoded_master,
+        Data {
+            ckb,
+            udt,
+            ckb_unoccupied,
+            info: decoded_info,
+        },
+    )) = decoded
+    else {
+        panic!("valid order must decode");
+    };
+    assert_eq!(decoded_master, master);
+    assert_eq!(ckb, C256::from(capacity));
+    assert_eq!(ckb_unoccupied, C256::from(u64::from(order.unoccupied)));
+    assert_eq!(udt, C256::from(order.udt_amount));
+    assert_eq!(decoded_info.udt_hash, UDT_HASH);
+    assert!(match (&udt_layout, decoded_info.udt_layout) {
+        (UdtLayout::Udt, limit_order::UdtLayout::Udt) => true,
+        (UdtLayout::XudtData(xudt), limit_order::UdtLayout::XudtData { udt_data_size }) => {
+            udt_data_size as usize == 16 + xudt.len()
+        }
+        _ => false,
+    });
+    assert!(decoded_info.ckb_to_udt == decoded_ratio(info.ckb_to_udt));
+    assert!(decoded_info.udt_to_ckb == decoded_ratio(info.udt_to_ckb));
+    assert_eq!(decoded_info.ckb_min_match, C256::from(info.ckb_min_match()));
+    assert!(
+        decoded_info.expiry
+            == info.expiry.map(|e| Expiry {
+                since: Since::new(e.since),
+                master_lock_hash: e.master_lock_hash,
+            })
+    );
+    assert_eq!(
+        decoded_info.max_matcher_fee,
+        C256::from(u64::from(info.max_matcher_fee.unwrap_or(0)))
+    );
+}
+
+fuzz_target!(|input: Input| {
+    match input {
+        Input::Raw {
+            position,
+            data,
+            capacity,
+            occupied_capacity,
+            type_hash,
+        } => {
+            // Documented C256 overflow: unoccupied capacity of a cell holding less than it occupies,
+            // which no valid transaction contains
+            if occupied_capacity > capacity {
+                return;
+            }
+            let cells = position.cells(MemoryCell {
+                capacity,
+                occupied_capacity,
+                type_hash,
+                data,
+                ..MemoryCell::default()
+            });
+            let decoded = extract_order(&cells, position.index(), position.source());
+            // Without a type script there is no UDT to trade
+            if type_hash.is_none() {
+                assert!(decoded.is_err());
+            }
+        }
+        Input::Encoded { position, order } => check_encoded(position, order),
+    }
+});
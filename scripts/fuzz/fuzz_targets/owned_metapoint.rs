@@ -0,0 +1,51 @@
+#![no_main]
+
+use ickb_fuzz::Position;
+use ickb_types::{molecule::prelude::*, OwnedOwnerData};
+use libfuzzer_sys::{
+    arbitrary::{self, Arbitrary},
+    fuzz_target,
+};
+use owned_owner::{error::Error, extract_owned_metapoint};
+use utils::{extract_metapoint, MemoryCell, MetaPoint};
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    position: Position,
+    data: Vec<u8>,
+}
+
+fn extract(position: &Position, data: &[u8]) -> Result<MetaPoint, Error> {
+    let cells = position.cells(MemoryCell {
+        data: data.to_vec(),
+        ..MemoryCell::default()
+    });
+    extract_owned_metapoint(&cells, position.index(), position.source())
+}
+
+// Owner data is the signed distance from the owner cell to its owned cell
+fuzz_target!(|input: Input| {
+    let Input { position, data } = input;
+    let owned = extract(&position, &data);
+
+    let cells = position.cells(MemoryCell::default());
+    let Ok(owner) = extract_metapoint(&cells, position.index(), position.source()) else {
+        assert!(matches!(owned, Err(Error::IndexOutOfBound)));
+        return;
+    };
+    if data.len() < OwnedOwnerData::TOTAL_SIZE {
+        assert!(matches!(owned, Err(Error::Encoding)));
+        return;
+    }
+
+    let Ok(owned) = owned else {
+        panic!("owner data long enough must decode");
+    };
+    assert_eq!(owned.tx_hash, owner.tx_hash);
+    let distance = i32::try_from(owned.index - owner.index).expect("distance within i32");
+    let encoded = OwnedOwnerData::new_builder()
+        .owned_distance(distance.into())
+        .build();
+    assert_eq!(encoded.as_slice(), &data[..OwnedOwnerData::TOTAL_SIZE]);
+    assert!(matches!(extract(&position, encoded.as_slice()), Ok(m) if m == owned));
+});
@@ -0,0 +1,27 @@
+#![no_main]
+
+use ckb_std::ckb_constants::Source;
+use ickb_fuzz::data_cell;
+use ickb_logic::{error::Error, extract_receipt_data};
+use ickb_types::{molecule::prelude::*, ReceiptData};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let receipt = extract_receipt_data(&data_cell(data), 0, Source::Output);
+    if data.len() < ReceiptData::TOTAL_SIZE {
+        assert!(matches!(receipt, Err(Error::Encoding)));
+        return;
+    }
+
+    // Trailing bytes are ignored, while the decoded values encode back to the same prefix
+    let Ok((deposit_quantity, deposit_amount)) = receipt else {
+        panic!("receipt long enough must decode");
+    };
+    let encoded = ReceiptData::new_builder()
+        .deposit_quantity(deposit_quantity.into())
+        .deposit_amount(deposit_amount.into())
+        .build();
+    assert_eq!(encoded.as_slice(), &data[..ReceiptData::TOTAL_SIZE]);
+    let receipt = extract_receipt_data(&data_cell(encoded.as_slice()), 0, Source::Output);
+    assert!(matches!(receipt, Ok(r) if r == (deposit_quantity, deposit_amount)));
+});
@@ -0,0 +1,26 @@
+#![no_main]
+
+use ckb_std::{ckb_constants::Source, error::SysError};
+use ickb_fuzz::data_cell;
+use libfuzzer_sys::fuzz_target;
+use utils::{extract_udt_amount, UDT_SIZE};
+
+// UDT data is the amount in little endian, optionally followed by extension data
+fuzz_target!(|data: &[u8]| {
+    let amount = extract_udt_amount(&data_cell(data), 0, Source::Output);
+    if data.len() < UDT_SIZE {
+        assert_eq!(amount, Err(SysError::Encoding));
+        return;
+    }
+
+    let mut expected = [0u8; UDT_SIZE];
+    expected.copy_from_slice(&data[..UDT_SIZE]);
+    let expected = u128::from_le_bytes(expected);
+    assert_eq!(amount, Ok(expected));
+
+    let encoded = expected.to_le_bytes();
+    assert_eq!(
+        extract_udt_amount(&data_cell(&encoded), 0, Source::Output),
+        Ok(expected)
+    );
+});
@@ -0,0 +1,71 @@
+//! Mocked syscall layer shared by the fuzz targets: decoders read cells from a MemoryCellSource.
+
+use ckb_std::ckb_constants::Source;
+use libfuzzer_sys::arbitrary::{self, Arbitrary};
+use utils::{MemoryCell, MemoryCellSource};
+
+// Where the decoded cell sits in the transaction, only inputs may have an OutPoint
+#[derive(Arbitrary, Debug, Clone, Copy)]
+pub enum Position {
+    Input {
+        index: u8,
+        out_point: Option<([u8; 32], u32)>,
+    },
+    Output {
+        index: u8,
+    },
+}
+
+impl Position {
+    #[must_use]
+    pub fn source(&self) -> Source {
+        match self {
+            Position::Input { .. } => Source::Input,
+            Position::Output { .. } => Source::Output,
+        }
+    }
+
+    #[must_use]
+    pub fn index(&self) -> usize {
+        match self {
+            Position::Input { index, .. } | Position::Output { index } => usize::from(*index),
+        }
+    }
+
+    #[must_use]
+    pub fn out_point(&self) -> Option<([u8; 32], u32)> {
+        match self {
+            Position::Input { out_point, .. } => *out_point,
+            Position::Output { .. } => None,
+        }
+    }
+
+    // Transaction with cell at this position, preceded by empty cells
+    #[must_use]
+    pub fn cells(&self, cell: MemoryCell) -> MemoryCellSource {
+        let mut cells = vec![MemoryCell::default(); self.index()];
+        cells.push(MemoryCell {
+            out_point: self.out_point(),
+            ..cell
+        });
+        match self {
+            Position::Input { .. } => MemoryCellSource {
+                inputs: cells,
+                ..MemoryCellSource::default()
+            },
+            Position::Output { .. } => MemoryCellSource {
+                outputs: cells,
+                ..MemoryCellSource::default()
+            },
+        }
+    }
+}
+
+// Cell with the given data at the first output
+#[must_use]
+pub fn data_cell(data: &[u8]) -> MemoryCellSource {
+    Position::Output { index: 0 }.cells(MemoryCell {
+        data: data.to_vec(),
+        ..MemoryCell::default()
+    })
+}
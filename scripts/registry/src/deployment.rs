@@ -0,0 +1,184 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use ckb_types::{packed::CellOutput, prelude::*, H256};
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+// deployment.toml, as consumed by `ckb-cli deploy gen-txs --deployment-config`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeploymentConfig {
+    #[serde(default)]
+    pub cells: Vec<CellConfig>,
+    #[serde(default)]
+    pub dep_groups: Vec<DepGroupConfig>,
+    pub lock: LockConfig,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CellConfig {
+    pub name: String,
+    pub enable_type_id: bool,
+    pub location: CellLocation,
+}
+
+// Cells are either deployed from a local file or already live on chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CellLocation {
+    File { file: String },
+    OutPoint { tx_hash: String, index: u32 },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DepGroupConfig {
+    pub name: String,
+    pub cells: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockConfig {
+    pub code_hash: String,
+    pub args: String,
+    pub hash_type: String,
+}
+
+impl DeploymentConfig {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let content = read(path)?;
+        toml::from_str(&content).map_err(|err| Error::Parse(path.to_path_buf(), err.to_string()))
+    }
+}
+
+// Migration JSON, as written by `ckb-cli deploy apply-txs --migration-dir`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Migration {
+    pub cell_recipes: Vec<CellRecipe>,
+    pub dep_group_recipes: Vec<DepGroupRecipe>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CellRecipe {
+    pub name: String,
+    pub tx_hash: String,
+    pub index: u32,
+    pub occupied_capacity: u64,
+    pub data_hash: String,
+    pub type_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DepGroupRecipe {
+    pub name: String,
+    pub tx_hash: String,
+    pub index: u32,
+    pub data_hash: String,
+    pub occupied_capacity: u64,
+}
+
+impl Migration {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let content = read(path)?;
+        serde_json::from_str(&content)
+            .map_err(|err| Error::Parse(path.to_path_buf(), err.to_string()))
+    }
+
+    // Migration files are named after their timestamp, so the latest is the last in name order
+    pub fn latest(dir: &Path) -> Result<(PathBuf, Self), Error> {
+        let path = migration_paths(dir)?
+            .pop()
+            .ok_or_else(|| Error::MissingMigration(dir.to_path_buf()))?;
+        let migration = Migration::load(&path)?;
+        Ok((path, migration))
+    }
+
+    #[must_use]
+    pub fn cell_recipe(&self, name: &str) -> Option<&CellRecipe> {
+        self.cell_recipes.iter().find(|r| r.name == name)
+    }
+}
+
+// All migration files in dir, sorted from the oldest to the latest
+pub fn migration_paths(dir: &Path) -> Result<Vec<PathBuf>, Error> {
+    let entries = fs::read_dir(dir).map_err(|err| Error::Io(dir.to_path_buf(), err))?;
+    let mut paths = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|err| Error::Io(dir.to_path_buf(), err))?
+            .path();
+        if path.extension().is_some_and(|e| e == "json") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+// A network directory, such as deployment/testnet, with its configuration and latest migration
+#[derive(Debug, Clone)]
+pub struct Network {
+    pub name: String,
+    pub dir: PathBuf,
+    pub config: DeploymentConfig,
+    pub migration: Migration,
+}
+
+impl Network {
+    pub fn load(deployment_dir: &Path, name: &str) -> Result<Self, Error> {
+        let dir = deployment_dir.join(name);
+        let config = DeploymentConfig::load(&dir.join("deployment.toml"))?;
+        let (_, migration) = Migration::latest(&dir.join("migrations"))?;
+        Ok(Network {
+            name: name.to_string(),
+            dir,
+            config,
+            migration,
+        })
+    }
+}
+
+// Blake2b hash of the cell data, as used by the Data1 hash type
+#[must_use]
+pub fn data_hash(data: &[u8]) -> [u8; 32] {
+    let hash: H256 = CellOutput::calc_data_hash(data).unpack();
+    hash.0
+}
+
+#[must_use]
+pub fn to_hex(bytes: &[u8]) -> String {
+    let mut hex = String::from("0x");
+    for b in bytes {
+        hex.push_str(&format!("{b:02x}"));
+    }
+    hex
+}
+
+pub fn parse_hash(hex: &str) -> Result<[u8; 32], Error> {
+    let invalid = || Error::InvalidHex(hex.to_string());
+    let digits = hex.strip_prefix("0x").ok_or_else(invalid)?;
+    if digits.len() != 64 || !digits.is_ascii() {
+        return Err(invalid());
+    }
+    let mut hash = [0u8; 32];
+    for (i, b) in hash.iter_mut().enumerate() {
+        *b = u8::from_str_radix(&digits[2 * i..2 * i + 2], 16).map_err(|_| invalid())?;
+    }
+    Ok(hash)
+}
+
+// Find dir from the current directory, either scripts or one of its crates
+#[must_use]
+pub fn find_dir(dir: &str) -> PathBuf {
+    let path = PathBuf::from(dir);
+    if path.exists() {
+        return path;
+    }
+    Path::new("..").join(dir)
+}
+
+pub fn read(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|err| Error::Io(path.to_path_buf(), err))
+}
@@ -0,0 +1,27 @@
+use std::{fmt, io, path::PathBuf};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, String),
+    MissingMigration(PathBuf),
+    // Network and recipe name
+    MissingRecipe(String, String),
+    InvalidHex(String),
+    Unsupported(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(path, err) => write!(f, "{}: {err}", path.display()),
+            Error::Parse(path, err) => write!(f, "{}: {err}", path.display()),
+            Error::MissingMigration(path) => write!(f, "{}: no migration found", path.display()),
+            Error::MissingRecipe(network, name) => write!(f, "{network}: no {name} recipe"),
+            Error::InvalidHex(s) => write!(f, "invalid hex string: {s}"),
+            Error::Unsupported(s) => write!(f, "unsupported {s}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,9 @@
+//! Typed registry of the iCKB deployments: parsing of the `ckb-cli deploy` configurations and
+//! migrations in `deployment/`, also embedded at compile time for the public networks, with the
+//! cell deps, out points and data hashes they record.
+
+pub mod deployment;
+mod error;
+pub mod registry;
+
+pub use error::Error;
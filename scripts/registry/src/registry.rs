@@ -0,0 +1,232 @@
+use std::{collections::BTreeMap, path::Path};
+
+use ckb_types::{
+    core::DepType,
+    packed::{CellDep, OutPoint},
+    prelude::*,
+};
+
+use crate::{
+    deployment::{parse_hash, CellLocation, DeploymentConfig, Migration, Network},
+    Error,
+};
+
+// Deployment records of the public networks, embedded at compile time.
+// Migrations are listed from the oldest to the latest, test_registry_embeds_every_migration
+// fails when a new migration file is not listed here.
+const MAINNET_CONFIG: &str = include_str!("../../deployment/mainnet/deployment.toml");
+const MAINNET_MIGRATIONS: &[(&str, &str)] = &[(
+    "2024-09-12-151119.json",
+    include_str!("../../deployment/mainnet/migrations/2024-09-12-151119.json"),
+)];
+
+const TESTNET_CONFIG: &str = include_str!("../../deployment/testnet/deployment.toml");
+const TESTNET_MIGRATIONS: &[(&str, &str)] = &[
+    (
+        "2024-05-20-135612.json",
+        include_str!("../../deployment/testnet/migrations/2024-05-20-135612.json"),
+    ),
+    (
+        "2024-05-21-092618.json",
+        include_str!("../../deployment/testnet/migrations/2024-05-21-092618.json"),
+    ),
+    (
+        "2024-05-21-151311.json",
+        include_str!("../../deployment/testnet/migrations/2024-05-21-151311.json"),
+    ),
+    (
+        "2024-08-01-090441.json",
+        include_str!("../../deployment/testnet/migrations/2024-08-01-090441.json"),
+    ),
+    (
+        "2024-09-12-141730.json",
+        include_str!("../../deployment/testnet/migrations/2024-09-12-141730.json"),
+    ),
+];
+
+// Typed deployment of a network: every cell and dep group of its deployment.toml,
+// located through its latest migration when deployed from a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deployment {
+    pub network: String,
+    pub config: DeploymentConfig,
+    pub migration: Migration,
+    pub cells: BTreeMap<String, DeployedCell>,
+    pub dep_groups: BTreeMap<String, DeployedDepGroup>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeployedCell {
+    pub out_point: OutPoint,
+    // Only known for cells deployed by a migration, None for cells already live, such as DAO
+    pub data_hash: Option<[u8; 32]>,
+    pub occupied_capacity: Option<u64>,
+    pub type_id: Option<[u8; 32]>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeployedDepGroup {
+    pub out_point: OutPoint,
+    pub data_hash: [u8; 32],
+    pub occupied_capacity: u64,
+    // Names of the grouped cells, in dep group order
+    pub cells: Vec<String>,
+}
+
+impl DeployedCell {
+    #[must_use]
+    pub fn cell_dep(&self) -> CellDep {
+        CellDep::new_builder()
+            .out_point(self.out_point.clone())
+            .dep_type(DepType::Code.into())
+            .build()
+    }
+}
+
+impl DeployedDepGroup {
+    #[must_use]
+    pub fn cell_dep(&self) -> CellDep {
+        CellDep::new_builder()
+            .out_point(self.out_point.clone())
+            .dep_type(DepType::DepGroup.into())
+            .build()
+    }
+}
+
+impl Deployment {
+    pub fn new(
+        network: &str,
+        config: DeploymentConfig,
+        migration: Migration,
+    ) -> Result<Self, Error> {
+        let missing = |name: &str| Error::MissingRecipe(network.to_string(), name.to_string());
+
+        let mut cells = BTreeMap::new();
+        for cell in &config.cells {
+            let deployed = match &cell.location {
+                CellLocation::File { .. } => {
+                    let recipe = migration
+                        .cell_recipe(&cell.name)
+                        .ok_or_else(|| missing(&cell.name))?;
+                    DeployedCell {
+                        out_point: out_point(&recipe.tx_hash, recipe.index)?,
+                        data_hash: Some(parse_hash(&recipe.data_hash)?),
+                        occupied_capacity: Some(recipe.occupied_capacity),
+                        type_id: recipe.type_id.as_deref().map(parse_hash).transpose()?,
+                    }
+                }
+                CellLocation::OutPoint { tx_hash, index } => DeployedCell {
+                    out_point: out_point(tx_hash, *index)?,
+                    data_hash: None,
+                    occupied_capacity: None,
+                    type_id: None,
+                },
+            };
+            cells.insert(cell.name.clone(), deployed);
+        }
+
+        let mut dep_groups = BTreeMap::new();
+        for dep_group in &config.dep_groups {
+            if let Some(name) = dep_group.cells.iter().find(|c| !cells.contains_key(*c)) {
+                return Err(missing(name));
+            }
+            let recipe = migration
+                .dep_group_recipes
+                .iter()
+                .find(|r| r.name == dep_group.name)
+                .ok_or_else(|| missing(&dep_group.name))?;
+            dep_groups.insert(
+                dep_group.name.clone(),
+                DeployedDepGroup {
+                    out_point: out_point(&recipe.tx_hash, recipe.index)?,
+                    data_hash: parse_hash(&recipe.data_hash)?,
+                    occupied_capacity: recipe.occupied_capacity,
+                    cells: dep_group.cells.clone(),
+                },
+            );
+        }
+
+        Ok(Deployment {
+            network: network.to_string(),
+            config,
+            migration,
+            cells,
+            dep_groups,
+        })
+    }
+
+    // Read from a network directory, such as deployment/devnet once generated
+    pub fn load(deployment_dir: &Path, name: &str) -> Result<Self, Error> {
+        let network = Network::load(deployment_dir, name)?;
+        Deployment::new(name, network.config, network.migration)
+    }
+
+    // Records embedded at compile time, no file access needed
+    pub fn mainnet() -> Result<Self, Error> {
+        Deployment::embedded("mainnet")
+    }
+
+    pub fn testnet() -> Result<Self, Error> {
+        Deployment::embedded("testnet")
+    }
+
+    pub fn embedded(name: &str) -> Result<Self, Error> {
+        let (config, _) = embedded_files(name)?;
+        let path = Path::new(name).join("deployment.toml");
+        let config = toml::from_str(config).map_err(|err| Error::Parse(path, err.to_string()))?;
+        let (_, migration) = embedded_migrations(name)?
+            .pop()
+            .ok_or_else(|| Error::MissingMigration(name.into()))?;
+        Deployment::new(name, config, migration)
+    }
+
+    #[must_use]
+    pub fn cell(&self, name: &str) -> Option<&DeployedCell> {
+        self.cells.get(name)
+    }
+
+    #[must_use]
+    pub fn dep_group(&self, name: &str) -> Option<&DeployedDepGroup> {
+        self.dep_groups.get(name)
+    }
+
+    // Cell dep of a dep group or, failing that, of a code cell
+    #[must_use]
+    pub fn cell_dep(&self, name: &str) -> Option<CellDep> {
+        self.dep_group(name)
+            .map(DeployedDepGroup::cell_dep)
+            .or_else(|| self.cell(name).map(DeployedCell::cell_dep))
+    }
+}
+
+// File name and content of each migration
+type MigrationFiles = &'static [(&'static str, &'static str)];
+
+fn embedded_files(name: &str) -> Result<(&'static str, MigrationFiles), Error> {
+    match name {
+        "mainnet" => Ok((MAINNET_CONFIG, MAINNET_MIGRATIONS)),
+        "testnet" => Ok((TESTNET_CONFIG, TESTNET_MIGRATIONS)),
+        _ => Err(Error::Unsupported(format!("embedded network {name}"))),
+    }
+}
+
+// Embedded migrations of a network by file name, from the oldest to the latest
+pub fn embedded_migrations(name: &str) -> Result<Vec<(String, Migration)>, Error> {
+    let (_, migrations) = embedded_files(name)?;
+    migrations
+        .iter()
+        .map(|(file_name, content)| {
+            let path = Path::new(name).join("migrations").join(file_name);
+            let migration =
+                serde_json::from_str(content).map_err(|err| Error::Parse(path, err.to_string()))?;
+            Ok((file_name.to_string(), migration))
+        })
+        .collect()
+}
+
+fn out_point(tx_hash: &str, index: u32) -> Result<OutPoint, Error> {
+    Ok(OutPoint::new_builder()
+        .tx_hash(parse_hash(tx_hash)?.pack())
+        .index(index.pack())
+        .build())
+}
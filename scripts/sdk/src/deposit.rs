@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+
+use ckb_types::{
+    bytes::Bytes,
+    core::{HeaderView, TransactionBuilder, TransactionView},
+    packed::{Byte32, CellInput, CellOutput, OutPoint, Script},
+    prelude::*,
+};
+use ickb_rate::DepositLimits;
+use ickb_types::{
+    DepositLimits as DepositLimitsData, ReceiptData, ReceiptDataReader, UdtData, UdtDataReader,
+};
+
+use crate::{
+    accumulated_rate, new_cell_output,
+    utils::{output_cells, DAO_DEPOSIT_DATA},
+    Error, Scripts,
+};
+
+// Deposits to be made in phase 1, grouped by unoccupied capacity, receipts are locked by lock
+#[derive(Debug, Clone)]
+pub struct DepositRequest {
+    lock: Script,
+    deposits: BTreeMap<u64, u32>,
+}
+
+impl DepositRequest {
+    #[must_use]
+    pub fn new(lock: Script) -> Self {
+        DepositRequest {
+            lock,
+            deposits: BTreeMap::new(),
+        }
+    }
+
+    // Add quantity deposits, each with the specified unoccupied capacity,
+    // a receipt cannot account for more than u32::MAX deposits of the same capacity
+    pub fn deposit(mut self, unoccupied: u64, quantity: u32) -> Result<Self, Error> {
+        let total = self.deposits.entry(unoccupied).or_insert(0);
+        *total = total.checked_add(quantity).ok_or(Error::ReceiptMismatch)?;
+        Ok(self)
+    }
+}
+
+// Deposit phase 1: the DAO deposits locked by ickb_logic, followed by one receipt per deposit amount
+pub fn deposit_phase_one(
+    scripts: &Scripts,
+    inputs: &[OutPoint],
+    request: &DepositRequest,
+) -> Result<TransactionView, Error> {
+    let mut outputs = Vec::new();
+    for (&amount, &quantity) in &request.deposits {
+        let deposit = new_cell_output(
+            &scripts.ickb_logic,
+            Some(&scripts.dao),
+            DAO_DEPOSIT_DATA.len(),
+            amount,
+        );
+        for _ in 0..quantity {
+            outputs.push((deposit.clone(), Bytes::from(DAO_DEPOSIT_DATA.to_vec())));
+        }
+    }
+    for (&amount, &quantity) in &request.deposits {
+        let data = ReceiptData::new_builder()
+            .deposit_quantity(quantity.into())
+            .deposit_amount(amount.into())
+            .build()
+            .as_bytes();
+        let receipt = new_cell_output(&request.lock, Some(&scripts.ickb_logic), data.len(), 0);
+        outputs.push((receipt, data));
+    }
+
+    let tx = new_transaction(scripts, inputs, Vec::new(), outputs);
+    check_output(scripts, &tx)?;
+    Ok(tx)
+}
+
+// Receipt cell, header is the one of the block that includes it
+#[derive(Debug, Clone)]
+pub struct Receipt {
+    pub out_point: OutPoint,
+    pub deposit_quantity: u32,
+    pub deposit_amount: u64,
+    pub header: HeaderView,
+}
+
+impl Receipt {
+    pub fn new(out_point: OutPoint, data: &[u8], header: HeaderView) -> Result<Self, Error> {
+        let (deposit_quantity, deposit_amount) = extract_receipt_data(data)?;
+        Ok(Receipt {
+            out_point,
+            deposit_quantity,
+            deposit_amount,
+            header,
+        })
+    }
+
+    // iCKB value of the receipt, as computed by ickb_logic with limits,
+    // None if the header has a zero accumulated rate
+    #[must_use]
+    pub fn ickb_amount(&self, limits: &DepositLimits) -> Option<u128> {
+        limits.ickb_for_receipt(
+            self.deposit_quantity,
+            self.deposit_amount,
+            accumulated_rate(&self.header),
+        )
+    }
+}
+
+// Deposit phase 2: burn the receipts into a single iCKB xUDT cell locked by lock
+pub fn deposit_phase_two(
+    scripts: &Scripts,
+    receipts: &[Receipt],
+    lock: &Script,
+) -> Result<TransactionView, Error> {
+    let inputs: Vec<_> = receipts.iter().map(|r| r.out_point.clone()).collect();
+    let mut header_deps: Vec<Byte32> = Vec::new();
+    for receipt in receipts {
+        if !header_deps.contains(&receipt.header.hash()) {
+            header_deps.push(receipt.header.hash());
+        }
+    }
+
+    let amount: u128 = receipts
+        .iter()
+        .map(|r| r.ickb_amount(&scripts.deposit_limits))
+        .sum::<Option<u128>>()
+        .ok_or(Error::InvalidAccumulatedRate)?;
+    let data = UdtData::new_builder()
+        .udt_amount(amount.into())
+        .build()
+        .as_bytes();
+    let udt = new_cell_output(lock, Some(&scripts.ickb_udt), data.len(), 0);
+
+    let tx = new_transaction(scripts, &inputs, header_deps, vec![(udt, data)]);
+    check_output(scripts, &tx)?;
+    Ok(tx)
+}
+
+// Data of a v2 parameter cell, see Scripts::ickb_logic_v2
+#[must_use]
+pub fn encode_deposit_limits(limits: &DepositLimits) -> Bytes {
+    DepositLimitsData::new_builder()
+        .min_unoccupied_capacity(limits.min_unoccupied_capacity.into())
+        .max_unoccupied_capacity(limits.max_unoccupied_capacity.into())
+        .soft_cap(limits.soft_cap.into())
+        .build()
+        .as_bytes()
+}
+
+pub(crate) fn new_transaction(
+    scripts: &Scripts,
+    inputs: &[OutPoint],
+    header_deps: Vec<Byte32>,
+    outputs: Vec<(CellOutput, Bytes)>,
+) -> TransactionView {
+    let inputs = inputs
+        .iter()
+        .map(|out_point| CellInput::new(out_point.clone(), 0));
+    let (outputs, outputs_data): (Vec<_>, Vec<_>) = outputs.into_iter().unzip();
+    TransactionBuilder::default()
+        .cell_deps(scripts.cell_deps.clone())
+        .header_deps(header_deps)
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .build()
+}
+
+// Runs ickb_logic::check_output on the outputs, returns the total iCKB xUDT in outputs
+pub fn check_output(scripts: &Scripts, tx: &TransactionView) -> Result<u128, Error> {
+    let ickb_logic_hash = scripts.ickb_logic.calc_script_hash().unpack();
+    Ok(ickb_logic::check_output(
+        &output_cells(tx),
+        ickb_logic_hash,
+        &scripts.deposit_limits,
+    )?)
+}
+
+// As on-chain, data longer than expected is accepted and only its prefix is read
+fn extract_receipt_data(data: &[u8]) -> Result<(u32, u64), Error> {
+    let data = data.get(..ReceiptData::TOTAL_SIZE).ok_or(Error::Encoding)?;
+    let receipt = ReceiptDataReader::new_unchecked(data);
+    Ok((
+        receipt.deposit_quantity().unpack(),
+        receipt.deposit_amount().unpack(),
+    ))
+}
+
+pub(crate) fn extract_udt_amount(data: &[u8]) -> Result<u128, Error> {
+    let data = data.get(..UdtData::TOTAL_SIZE).ok_or(Error::Encoding)?;
+    Ok(UdtDataReader::new_unchecked(data).udt_amount().unpack())
+}
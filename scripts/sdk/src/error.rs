@@ -0,0 +1,132 @@
+// Errors mirror the ones returned on-chain by the iCKB scripts, so a transaction rejected locally
+// would have been rejected on-chain with the same error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    IndexOutOfBound,
+    ItemMissing,
+    LengthNotEnough,
+    Encoding,
+    NotEmptyArgs,
+    ScriptMisuse,
+    // ickb_logic
+    DepositTooSmall,
+    DepositTooBig,
+    EmptyReceipt,
+    ReceiptMismatch,
+    AmountMismatch,
+    AmountUnreasonablyBig,
+    InvalidAccumulatedRate,
+    ParamsMissing,
+    ParamsInvalid,
+    ParamsMutable,
+    // owned_owner
+    NotWithdrawalRequest,
+    Mismatch,
+    // limit_order
+    InvalidAction,
+    NonZeroPadding,
+    InvalidRatio,
+    InvalidCkbMinMatchLog,
+    ConcaveRatio,
+    BothRatioNull,
+    MissingUdtType,
+    DifferentInfo,
+    InvalidMatch,
+    DecreasingValue,
+    AttemptToChangeFulfilled,
+    InsufficientMatch,
+    InvalidConfiguration,
+    InvalidUdtLayout,
+    UdtDataChanged,
+    InvalidExpiry,
+    NotExpired,
+    InsufficientRefund,
+    InvalidMatcherFee,
+    DuplicatedMaster,
+    SameMaster,
+    InvalidMasterData,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ickb_logic::error::Error> for Error {
+    fn from(err: ickb_logic::error::Error) -> Self {
+        use ickb_logic::error::Error as E;
+        match err {
+            E::IndexOutOfBound => Self::IndexOutOfBound,
+            E::ItemMissing => Self::ItemMissing,
+            E::LengthNotEnough => Self::LengthNotEnough,
+            E::Encoding => Self::Encoding,
+            E::NotEmptyArgs => Self::NotEmptyArgs,
+            E::ScriptMisuse => Self::ScriptMisuse,
+            E::DepositTooSmall => Self::DepositTooSmall,
+            E::DepositTooBig => Self::DepositTooBig,
+            E::EmptyReceipt => Self::EmptyReceipt,
+            E::ReceiptMismatch => Self::ReceiptMismatch,
+            E::AmountMismatch => Self::AmountMismatch,
+            E::AmountUnreasonablyBig => Self::AmountUnreasonablyBig,
+            E::ParamsMissing => Self::ParamsMissing,
+            E::ParamsInvalid => Self::ParamsInvalid,
+            E::ParamsMutable => Self::ParamsMutable,
+            E::InvalidAccumulatedRate => Self::InvalidAccumulatedRate,
+        }
+    }
+}
+
+impl From<owned_owner::error::Error> for Error {
+    fn from(err: owned_owner::error::Error) -> Self {
+        use owned_owner::error::Error as E;
+        match err {
+            E::IndexOutOfBound => Self::IndexOutOfBound,
+            E::ItemMissing => Self::ItemMissing,
+            E::LengthNotEnough => Self::LengthNotEnough,
+            E::Encoding => Self::Encoding,
+            E::NotEmptyArgs => Self::NotEmptyArgs,
+            E::NotWithdrawalRequest => Self::NotWithdrawalRequest,
+            E::ScriptMisuse => Self::ScriptMisuse,
+            E::Mismatch => Self::Mismatch,
+        }
+    }
+}
+
+impl From<limit_order::error::Error> for Error {
+    fn from(err: limit_order::error::Error) -> Self {
+        use limit_order::error::Error as E;
+        match err {
+            E::IndexOutOfBound => Self::IndexOutOfBound,
+            E::ItemMissing => Self::ItemMissing,
+            E::LengthNotEnough => Self::LengthNotEnough,
+            E::Encoding => Self::Encoding,
+            E::NotEmptyArgs => Self::NotEmptyArgs,
+            E::DuplicatedMaster => Self::DuplicatedMaster,
+            E::InvalidAction => Self::InvalidAction,
+            E::NonZeroPadding => Self::NonZeroPadding,
+            E::InvalidRatio => Self::InvalidRatio,
+            E::InvalidCkbMinMatchLog => Self::InvalidCkbMinMatchLog,
+            E::ConcaveRatio => Self::ConcaveRatio,
+            E::BothRatioNull => Self::BothRatioNull,
+            E::MissingUdtType => Self::MissingUdtType,
+            E::SameMaster => Self::SameMaster,
+            E::ScriptMisuse => Self::ScriptMisuse,
+            E::DifferentInfo => Self::DifferentInfo,
+            E::InvalidMatch => Self::InvalidMatch,
+            E::DecreasingValue => Self::DecreasingValue,
+            E::AttemptToChangeFulfilled => Self::AttemptToChangeFulfilled,
+            E::InsufficientMatch => Self::InsufficientMatch,
+            E::InvalidConfiguration => Self::InvalidConfiguration,
+            E::InvalidUdtLayout => Self::InvalidUdtLayout,
+            E::UdtDataChanged => Self::UdtDataChanged,
+            E::InvalidExpiry => Self::InvalidExpiry,
+            E::NotExpired => Self::NotExpired,
+            E::InsufficientRefund => Self::InsufficientRefund,
+            E::InvalidMatcherFee => Self::InvalidMatcherFee,
+            E::InvalidMasterData => Self::InvalidMasterData,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! Off-chain transaction builders for the iCKB scripts.
+//!
+//! Builders only assemble the iCKB specific part of a transaction: cell deps, header deps, inputs,
+//! outputs and outputs data. Balancing capacity, paying fees and signing are left to the wallet,
+//! for example by extending the returned transaction with `as_advanced_builder()`.
+
+pub mod deposit;
+mod error;
+pub mod matcher;
+pub mod order;
+mod scripts;
+mod udt;
+mod utils;
+pub mod withdrawal;
+
+pub use error::Error;
+pub use scripts::Scripts;
+pub use udt::UdtCell;
+pub use utils::{accumulated_rate, new_cell_output};
@@ -0,0 +1,275 @@
+use std::collections::BTreeMap;
+
+use ckb_types::{
+    core::{HeaderView, TransactionView},
+    packed::Script,
+    prelude::*,
+};
+
+use crate::{
+    deposit::new_transaction,
+    order::{check_match, Match, Order, Ratio},
+    Error, Scripts,
+};
+
+// Live limit orders sharing the same UDT
+#[derive(Debug, Clone)]
+pub struct OrderBook {
+    pub udt: Script,
+    pub orders: Vec<Order>,
+}
+
+// Group orders by udt_hash, fulfilled orders are skipped as they cannot be matched anymore,
+// while orders expired at tip are skipped as the contract does not enforce expiry on matches
+#[must_use]
+pub fn order_books(orders: &[Order], tip: &HeaderView) -> BTreeMap<[u8; 32], OrderBook> {
+    let mut books: BTreeMap<[u8; 32], OrderBook> = BTreeMap::new();
+    for order in orders
+        .iter()
+        .filter(|o| !o.is_fulfilled() && !o.is_expired(tip))
+    {
+        books
+            .entry(order.udt_hash().unpack())
+            .or_insert_with(|| OrderBook {
+                udt: order.output.type_().to_opt().unwrap(),
+                orders: Vec::new(),
+            })
+            .orders
+            .push(order.clone());
+    }
+    books
+}
+
+// Matches of a single order book, deltas and profit are from the matcher point of view
+#[derive(Debug, Clone, Default)]
+pub struct Batch {
+    pub matches: Vec<(Order, Match)>,
+    // CKB received minus CKB given, in shannons
+    pub ckb_delta: i128,
+    // UDT received minus UDT given
+    pub udt_delta: i128,
+    // ckb_delta plus udt_delta valued at the reference ratio, in shannons
+    pub profit: i128,
+}
+
+// Orders searched by best_batch, the ones with the most profitable complete match, as the search is
+// exponential in their count, the others are left to the next batch
+pub const MAX_BATCH_ORDERS: usize = 16;
+
+impl OrderBook {
+    // Optimal batch: the most profitable set of legal matches, every order completely filled but at
+    // most one, partially filled with the balances left by the others. Profits are linear in the
+    // match size, so, up to rounding and ckb_min_match, an optimal batch never needs two partial
+    // matches: growing a partial match of each direction together keeps the UDT balance while the
+    // CKB balance can only grow. It is found by branch and bound over the complete matches of
+    // MAX_BATCH_ORDERS orders, in either direction.
+    // udt_value is the reference price: ckb * ckb_multiplier == udt * udt_multiplier.
+    // The matcher can spend at most ckb_available CKB and udt_available UDT plus what it receives
+    // from the other matches, as they all settle in the same transaction, so UDT bought from one
+    // order can be sold to another.
+    #[must_use]
+    pub fn best_batch(&self, ckb_available: u64, udt_available: u128, udt_value: &Ratio) -> Batch {
+        if udt_value.ckb_multiplier == 0 {
+            return Batch::default();
+        }
+
+        // Profitable complete matches of each order, the orders with the most profitable first
+        let mut candidates: Vec<(usize, Vec<Candidate>)> = self
+            .orders
+            .iter()
+            .enumerate()
+            .map(|(index, order)| (index, complete_matches(order, udt_value)))
+            .filter(|(_, complete)| !complete.is_empty())
+            .collect();
+        candidates.sort_by_key(|(_, complete)| std::cmp::Reverse(max_profit(complete)));
+        candidates.truncate(MAX_BATCH_ORDERS);
+
+        // Highest profit the candidates from each index on can add
+        let mut bounds = vec![0; candidates.len() + 1];
+        for index in (0..candidates.len()).rev() {
+            bounds[index] = bounds[index + 1] + max_profit(&candidates[index].1);
+        }
+
+        let mut search = Search {
+            book: self,
+            candidates: &candidates,
+            bounds,
+            ckb_available: i128::from(ckb_available),
+            udt_available: i128::try_from(udt_available).unwrap_or(i128::MAX),
+            udt_value,
+            picks: vec![None; candidates.len()],
+            best: Batch::default(),
+        };
+        search.branch(0, Totals::default(), 0);
+        search.best
+    }
+}
+
+// Deltas and profit of the matches picked so far
+#[derive(Clone, Copy, Default)]
+struct Totals {
+    ckb_delta: i128,
+    udt_delta: i128,
+    profit: i128,
+}
+
+impl Totals {
+    fn add(self, candidate: &Candidate) -> Self {
+        Totals {
+            ckb_delta: self.ckb_delta + candidate.ckb_delta,
+            udt_delta: self.udt_delta + candidate.udt_delta,
+            profit: self.profit + candidate.profit,
+        }
+    }
+}
+
+struct Search<'a> {
+    book: &'a OrderBook,
+    // Book order index and its profitable complete matches
+    candidates: &'a [(usize, Vec<Candidate>)],
+    bounds: Vec<i128>,
+    ckb_available: i128,
+    udt_available: i128,
+    udt_value: &'a Ratio,
+    // Complete match picked for each candidate, if any
+    picks: Vec<Option<usize>>,
+    best: Batch,
+}
+
+impl Search<'_> {
+    // Pick or skip the complete matches of the candidate at index, skipped_profit is the highest
+    // profit of the candidates skipped so far, the bound of the partial match one of them may get
+    fn branch(&mut self, index: usize, totals: Totals, skipped_profit: i128) {
+        if totals.profit + self.bounds[index] + skipped_profit <= self.best.profit {
+            return;
+        }
+        if index == self.candidates.len() {
+            self.complete(totals);
+            return;
+        }
+
+        let complete = &self.candidates[index].1;
+        for (pick, candidate) in complete.iter().enumerate() {
+            self.picks[index] = Some(pick);
+            self.branch(index + 1, totals.add(candidate), skipped_profit);
+        }
+        self.picks[index] = None;
+        let skipped_profit = skipped_profit.max(max_profit(complete));
+        self.branch(index + 1, totals, skipped_profit);
+    }
+
+    // Complete the picked matches with the best partial match of a skipped candidate, if any
+    fn complete(&mut self, totals: Totals) {
+        let ckb = self.ckb_available + totals.ckb_delta;
+        let udt = self.udt_available + totals.udt_delta;
+        let (Ok(ckb), Ok(udt)) = (u64::try_from(ckb), u128::try_from(udt)) else {
+            return;
+        };
+
+        let partial = self
+            .candidates
+            .iter()
+            .zip(&self.picks)
+            .filter(|(_, pick)| pick.is_none())
+            .filter_map(|((order_index, _), _)| {
+                let candidate =
+                    best_match(&self.book.orders[*order_index], ckb, udt, self.udt_value)?;
+                Some((*order_index, candidate))
+            })
+            .filter(|(_, candidate)| candidate.profit > 0)
+            .max_by_key(|(_, candidate)| candidate.profit);
+
+        let profit = totals.profit + partial.as_ref().map_or(0, |(_, c)| c.profit);
+        if profit <= self.best.profit {
+            return;
+        }
+
+        let mut matches: Vec<(usize, &Candidate)> = self
+            .candidates
+            .iter()
+            .zip(&self.picks)
+            .filter_map(|((order_index, complete), pick)| Some((*order_index, &complete[(*pick)?])))
+            .collect();
+        matches.extend(partial.as_ref().map(|(order_index, c)| (*order_index, c)));
+        // Matches in order book order
+        matches.sort_by_key(|(order_index, _)| *order_index);
+
+        let mut batch = Batch::default();
+        for (order_index, candidate) in matches {
+            batch
+                .matches
+                .push((self.book.orders[order_index].clone(), candidate.m));
+            batch.ckb_delta += candidate.ckb_delta;
+            batch.udt_delta += candidate.udt_delta;
+            batch.profit += candidate.profit;
+        }
+        self.best = batch;
+    }
+}
+
+struct Candidate {
+    m: Match,
+    ckb_delta: i128,
+    udt_delta: i128,
+    profit: i128,
+}
+
+// Profitable matches filling order completely, one for each direction allowed by its ratios
+fn complete_matches(order: &Order, udt_value: &Ratio) -> Vec<Candidate> {
+    [
+        order.max_ckb_to_udt_match(u128::MAX),
+        order.max_udt_to_ckb_match(u64::MAX),
+    ]
+    .into_iter()
+    .filter_map(|m| candidate(order, m.ok()?, udt_value))
+    .filter(|candidate| candidate.profit > 0)
+    .collect()
+}
+
+fn max_profit(candidates: &[Candidate]) -> i128 {
+    candidates.iter().map(|c| c.profit).max().unwrap_or(0)
+}
+
+fn candidate(order: &Order, m: Match, udt_value: &Ratio) -> Option<Candidate> {
+    let ckb_delta = i128::from(order.ckb()) - i128::from(m.ckb);
+    let udt_delta = i128::try_from(order.udt_amount).ok()? - i128::try_from(m.udt_amount).ok()?;
+    // Rounded down, so profit is never overestimated
+    let udt_delta_value = udt_delta
+        .checked_mul(i128::from(udt_value.udt_multiplier))?
+        .div_euclid(i128::from(udt_value.ckb_multiplier));
+    Some(Candidate {
+        m,
+        ckb_delta,
+        udt_delta,
+        profit: ckb_delta + udt_delta_value,
+    })
+}
+
+// Most profitable direction for matching order, None if no legal match exists
+fn best_match(order: &Order, ckb: u64, udt: u128, udt_value: &Ratio) -> Option<Candidate> {
+    let ckb_to_udt = order.max_ckb_to_udt_match(udt).ok();
+    let udt_to_ckb = order.max_udt_to_ckb_match(ckb).ok();
+    let ckb_to_udt = ckb_to_udt.and_then(|m| candidate(order, m, udt_value));
+    let udt_to_ckb = udt_to_ckb.and_then(|m| candidate(order, m, udt_value));
+    match (ckb_to_udt, udt_to_ckb) {
+        (Some(a), Some(b)) => Some(if a.profit >= b.profit { a } else { b }),
+        (a, b) => a.or(b),
+    }
+}
+
+// All the batch matches in one transaction, each Match output is at the same index of its order input.
+// The matcher adds its own inputs and outputs after them to settle ckb_delta and udt_delta.
+// Each order and its Match output go through limit_order::validate, see check_match.
+pub fn batch_transaction(scripts: &Scripts, batches: &[Batch]) -> Result<TransactionView, Error> {
+    let mut inputs = Vec::new();
+    let mut outputs = Vec::new();
+    for (order, m) in batches.iter().flat_map(|b| &b.matches) {
+        let (output, data) = order.apply(m);
+        let matched = Order::new(order.out_point.clone(), output.clone(), &data)?;
+        check_match(order, &matched)?;
+        inputs.push(order.out_point.clone());
+        outputs.push((output, data));
+    }
+
+    Ok(new_transaction(scripts, &inputs, Vec::new(), outputs))
+}
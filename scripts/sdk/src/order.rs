@@ -0,0 +1,750 @@
+use std::collections::BTreeMap;
+
+use ckb_std::ckb_constants::Source;
+use ckb_types::{
+    bytes::Bytes,
+    core::{EpochNumberWithFraction, HeaderView, TransactionView},
+    packed::{Byte32, CellInput, CellOutput, OutPoint, Script},
+    prelude::*,
+};
+use ickb_types::{
+    molecule::NUMBER_SIZE, MatchOrderData, MatchOrderDataReader, MatchOrderWithTerms,
+    MintOrderData, MintOrderDataReader, MintOrderWithTerms, OrderExpiryOpt, OrderTerms,
+    OrderTermsReader, PartialOrderData, PartialOrderDataReader, PartialOrderDataUnion,
+    PartialOrderDataUnionReader, RatioReader, UdtData, UdtDataReader, UdtLayout as UdtLayoutData,
+    UdtLayoutReader, Uint32, Uint32Opt, MATCHER_FEE_DENOMINATOR, UDT_LAYOUT_XUDT_DATA,
+};
+use primitive_types::U256;
+use utils::{MemoryCell, MemoryCellSource};
+
+use crate::{
+    deposit::new_transaction,
+    new_cell_output,
+    utils::{input_cell, memory_cell, output_cells, unoccupied_capacity},
+    Error, Scripts,
+};
+
+// MintOrderData and MatchOrderData share the same size
+const PARTIAL_ORDER_SIZE: usize = NUMBER_SIZE + MatchOrderData::TOTAL_SIZE;
+
+// Limit order cell data without UdtLayout nor terms is UdtData followed by PartialOrderData
+pub const ORDER_SIZE: usize = UdtData::TOTAL_SIZE + PARTIAL_ORDER_SIZE;
+
+// UdtData, then PartialOrderData item id and, for orders with terms, its total size
+const ORDER_HEAD_SIZE: usize = UdtData::TOTAL_SIZE + 2 * NUMBER_SIZE;
+
+// UDT data of a limit order after the UDT amount, see UdtLayout in schemas/encoding.mol
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum UdtLayout {
+    // Just UdtData, as for sUDT and xUDT without extension data
+    #[default]
+    Udt,
+    // UdtData followed by this molecule encoded XudtData, as for xUDT with extension data
+    XudtData(Bytes),
+}
+
+impl UdtLayout {
+    // Size of the limit order cell data, without terms
+    #[must_use]
+    pub fn order_size(&self) -> usize {
+        match self {
+            UdtLayout::Udt => ORDER_SIZE,
+            UdtLayout::XudtData(xudt_data) => {
+                ORDER_SIZE + xudt_data.len() + UdtLayoutData::TOTAL_SIZE
+            }
+        }
+    }
+}
+
+// Order data types, see schemas/encoding.mol
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Ratio {
+    pub ckb_multiplier: u64,
+    pub udt_multiplier: u64,
+}
+
+impl Ratio {
+    #[must_use]
+    pub fn is_null(&self) -> bool {
+        self.ckb_multiplier == 0 && self.udt_multiplier == 0
+    }
+}
+
+// After since, an absolute block number or epoch, anyone can melt the order, refunding CKB and UDT
+// to master_lock_hash, the lock hash of its master cell, whose data is since. The contract cannot
+// refuse matches past since, so order_books skips expired orders instead
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OrderExpiry {
+    pub since: u64,
+    pub master_lock_hash: [u8; 32],
+}
+
+const SINCE_FLAGS_SHIFT: u32 = 56;
+const SINCE_ABSOLUTE_BLOCK_NUMBER_FLAGS: u64 = 0x00;
+const SINCE_ABSOLUTE_EPOCH_FLAGS: u64 = 0x20;
+const SINCE_VALUE_MASK: u64 = 0x00ff_ffff_ffff_ffff;
+
+impl OrderExpiry {
+    #[must_use]
+    pub fn at_block_number(number: u64, master_lock: &Script) -> Self {
+        OrderExpiry {
+            since: number & SINCE_VALUE_MASK,
+            master_lock_hash: master_lock.calc_script_hash().unpack(),
+        }
+    }
+
+    #[must_use]
+    pub fn at_epoch(epoch: EpochNumberWithFraction, master_lock: &Script) -> Self {
+        OrderExpiry {
+            since: SINCE_ABSOLUTE_EPOCH_FLAGS << SINCE_FLAGS_SHIFT | epoch.full_value(),
+            master_lock_hash: master_lock.calc_script_hash().unpack(),
+        }
+    }
+
+    // Same checks as limit_order::order::extract_expiry
+    pub fn validate(&self) -> Result<(), Error> {
+        match self.since >> SINCE_FLAGS_SHIFT {
+            SINCE_ABSOLUTE_BLOCK_NUMBER_FLAGS | SINCE_ABSOLUTE_EPOCH_FLAGS => Ok(()),
+            _ => Err(Error::InvalidExpiry),
+        }
+    }
+
+    // Whether tip is at or past since, so a transaction melting the order is valid from now on
+    #[must_use]
+    pub fn is_expired(&self, tip: &HeaderView) -> bool {
+        let value = self.since & SINCE_VALUE_MASK;
+        match self.since >> SINCE_FLAGS_SHIFT {
+            SINCE_ABSOLUTE_BLOCK_NUMBER_FLAGS => tip.number() >= value,
+            SINCE_ABSOLUTE_EPOCH_FLAGS => {
+                tip.epoch() >= EpochNumberWithFraction::from_full_value(value)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OrderInfo {
+    pub ckb_to_udt: Ratio,
+    pub udt_to_ckb: Ratio,
+    pub ckb_min_match_log: u8,
+    pub expiry: Option<OrderExpiry>,
+    // Parts per million of the value leaving the order that the matcher can keep,
+    // there is no minimum, since matchers choose their fills and keep up to this bound
+    pub max_matcher_fee: Option<u32>,
+}
+
+impl OrderInfo {
+    #[must_use]
+    pub fn ckb_min_match(&self) -> u128 {
+        1u128 << self.ckb_min_match_log
+    }
+
+    // Optional terms, orders without any are encoded with the original PartialOrderData variants
+    fn terms(&self) -> Option<OrderTerms> {
+        if self.expiry.is_none() && self.max_matcher_fee.is_none() {
+            return None;
+        }
+        let expiry = self.expiry.map(|expiry| {
+            ickb_types::OrderExpiry::new_builder()
+                .since(expiry.since.into())
+                .master_lock_hash(expiry.master_lock_hash.into())
+                .build()
+        });
+        let max_matcher_fee = self.max_matcher_fee.map(Uint32::from);
+        let terms = OrderTerms::new_builder()
+            .expiry(OrderExpiryOpt::new_builder().set(expiry).build())
+            .max_matcher_fee(Uint32Opt::new_builder().set(max_matcher_fee).build())
+            .build();
+        Some(terms)
+    }
+
+    // Matcher fee in parts per million, zero if the order has no such term
+    fn matcher_fee(&self) -> U256 {
+        U256::from(self.max_matcher_fee.unwrap_or(0))
+    }
+
+    // Size that terms add to the limit order cell data
+    fn terms_size(&self) -> usize {
+        self.terms().map_or(0, |terms| {
+            let order = MintOrderWithTerms::new_builder().terms(terms).build();
+            order.as_slice().len() - MintOrderData::TOTAL_SIZE
+        })
+    }
+}
+
+fn decode_max_matcher_fee(terms: Option<OrderTermsReader>) -> Option<u32> {
+    Some(terms?.max_matcher_fee().to_opt()?.unpack())
+}
+
+fn decode_expiry(terms: Option<OrderTermsReader>) -> Option<OrderExpiry> {
+    let expiry = terms?.expiry().to_opt()?;
+    Some(OrderExpiry {
+        since: expiry.since().unpack(),
+        master_lock_hash: expiry.master_lock_hash().unpack(),
+    })
+}
+
+impl From<&Ratio> for ickb_types::Ratio {
+    fn from(ratio: &Ratio) -> Self {
+        ickb_types::Ratio::new_builder()
+            .ckb_multiplier(ratio.ckb_multiplier.into())
+            .udt_multiplier(ratio.udt_multiplier.into())
+            .build()
+    }
+}
+
+impl From<&OrderInfo> for ickb_types::OrderInfo {
+    fn from(info: &OrderInfo) -> Self {
+        ickb_types::OrderInfo::new_builder()
+            .ckb_to_udt((&info.ckb_to_udt).into())
+            .udt_to_ckb((&info.udt_to_ckb).into())
+            .ckb_min_match_log(info.ckb_min_match_log.into())
+            .build()
+    }
+}
+
+impl From<RatioReader<'_>> for Ratio {
+    fn from(ratio: RatioReader) -> Self {
+        Ratio {
+            ckb_multiplier: ratio.ckb_multiplier().unpack(),
+            udt_multiplier: ratio.udt_multiplier().unpack(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderAction {
+    Mint { master_distance: i32 },
+    Match { master: OutPoint },
+}
+
+// Encode limit order cell data
+#[must_use]
+pub fn encode_order_data(
+    udt_amount: u128,
+    udt_layout: &UdtLayout,
+    action: &OrderAction,
+    info: &OrderInfo,
+) -> Bytes {
+    let order_info = ickb_types::OrderInfo::from(info);
+    let order: PartialOrderDataUnion = match (action, info.terms()) {
+        (OrderAction::Mint { master_distance }, terms) => {
+            let order = MintOrderData::new_builder()
+                .master_distance((*master_distance).into())
+                .order_info(order_info)
+                .build();
+            match terms {
+                None => order.into(),
+                Some(terms) => MintOrderWithTerms::new_builder()
+                    .order(order)
+                    .terms(terms)
+                    .build()
+                    .into(),
+            }
+        }
+        (OrderAction::Match { master }, terms) => {
+            let tx_hash: [u8; 32] = master.tx_hash().unpack();
+            let index: u32 = master.index().unpack();
+            let master_outpoint = ickb_types::OutPoint::new_builder()
+                .tx_hash(tx_hash.into())
+                .index(index.into())
+                .build();
+            let order = MatchOrderData::new_builder()
+                .master_outpoint(master_outpoint)
+                .order_info(order_info)
+                .build();
+            match terms {
+                None => order.into(),
+                Some(terms) => MatchOrderWithTerms::new_builder()
+                    .order(order)
+                    .terms(terms)
+                    .build()
+                    .into(),
+            }
+        }
+    };
+    let udt_data = UdtData::new_builder().udt_amount(udt_amount.into()).build();
+    let order_data = PartialOrderData::new_builder().set(order).build();
+    match udt_layout {
+        UdtLayout::Udt => [udt_data.as_slice(), order_data.as_slice()].concat().into(),
+        UdtLayout::XudtData(xudt_data) => {
+            let udt_data_size = UdtData::TOTAL_SIZE + xudt_data.len();
+            let layout = UdtLayoutData::new_builder()
+                .strategy(UDT_LAYOUT_XUDT_DATA.into())
+                .udt_data_size(u32::try_from(udt_data_size).unwrap().into())
+                .build();
+            [
+                udt_data.as_slice(),
+                xudt_data,
+                order_data.as_slice(),
+                layout.as_slice(),
+            ]
+            .concat()
+            .into()
+        }
+    }
+}
+
+// UDT amount, layout and raw PartialOrderData, same checks as limit_order::order::extract_udt_data
+fn decode_udt_data(data: &[u8]) -> Result<(u128, UdtLayout, &[u8]), Error> {
+    if data.len() < ORDER_HEAD_SIZE {
+        return Err(Error::Encoding);
+    }
+    let udt_amount = UdtDataReader::new_unchecked(&data[..UdtData::TOTAL_SIZE])
+        .udt_amount()
+        .unpack();
+    let order_data = &data[UdtData::TOTAL_SIZE..];
+    if matches!(partial_order_size(order_data), Ok(size) if size == order_data.len()) {
+        return Ok((udt_amount, UdtLayout::Udt, order_data));
+    }
+
+    if data.len() < ORDER_HEAD_SIZE + UdtLayoutData::TOTAL_SIZE {
+        return Err(Error::Encoding);
+    }
+    let (data, raw_layout) = data.split_at(data.len() - UdtLayoutData::TOTAL_SIZE);
+    let layout = UdtLayoutReader::new_unchecked(raw_layout);
+    let udt_data_size = layout.udt_data_size().unpack() as usize;
+    if udt_data_size + ORDER_HEAD_SIZE - UdtData::TOTAL_SIZE > data.len() {
+        return Err(Error::Encoding);
+    }
+    let (udt_data, order_data) = data.split_at(udt_data_size);
+    if partial_order_size(order_data)? != order_data.len() {
+        return Err(Error::Encoding);
+    }
+    let udt_layout = match layout.strategy().unpack() {
+        UDT_LAYOUT_XUDT_DATA if udt_data_size > UdtData::TOTAL_SIZE => {
+            UdtLayout::XudtData(Bytes::copy_from_slice(&udt_data[UdtData::TOTAL_SIZE..]))
+        }
+        _ => return Err(Error::InvalidUdtLayout),
+    };
+    Ok((udt_amount, udt_layout, order_data))
+}
+
+// Size of a PartialOrderData from its item id and, for tables, their total size
+fn partial_order_size(order_data: &[u8]) -> Result<usize, Error> {
+    let read_u32 = |offset: usize| {
+        let raw = order_data[offset..offset + NUMBER_SIZE].try_into().unwrap();
+        u32::from_le_bytes(raw) as usize
+    };
+    match read_u32(0) {
+        // MintOrderData and MatchOrderData
+        0 | 1 => Ok(PARTIAL_ORDER_SIZE),
+        // MintOrderWithTerms and MatchOrderWithTerms
+        2 | 3 => Ok(NUMBER_SIZE + read_u32(NUMBER_SIZE)),
+        _ => Err(Error::InvalidAction),
+    }
+}
+
+// Limit order cell, master is the out point of its master cell
+#[derive(Debug, Clone)]
+pub struct Order {
+    pub out_point: OutPoint,
+    pub output: CellOutput,
+    pub udt_amount: u128,
+    pub udt_layout: UdtLayout,
+    pub master: OutPoint,
+    pub info: OrderInfo,
+}
+
+impl Order {
+    // Decode a limit order cell, once limit_order::extract_order accepts it
+    pub fn new(out_point: OutPoint, output: CellOutput, data: &[u8]) -> Result<Self, Error> {
+        let cells = MemoryCellSource {
+            inputs: vec![input_cell(&out_point, 0, &output, data)],
+            ..MemoryCellSource::default()
+        };
+        limit_order::extract_order(&cells, 0, Source::Input)?;
+
+        let (udt_amount, udt_layout, raw_order_data) = decode_udt_data(data)?;
+        let order_data =
+            PartialOrderDataReader::from_slice(raw_order_data).map_err(|_| Error::InvalidAction)?;
+
+        let mint_master = |mint: MintOrderDataReader| {
+            if mint.padding().raw_data() != [0u8; 32] {
+                return Err(Error::NonZeroPadding);
+            }
+            let index: u32 = out_point.index().unpack();
+            let index = i64::from(index) + i64::from(mint.master_distance().unpack());
+            let index = u32::try_from(index).map_err(|_| Error::InvalidConfiguration)?;
+            Ok(OutPoint::new(out_point.tx_hash(), index))
+        };
+        let match_master = |match_: MatchOrderDataReader| {
+            let master_outpoint = match_.master_outpoint();
+            let tx_hash: [u8; 32] = master_outpoint.tx_hash().unpack();
+            OutPoint::new(tx_hash.pack(), master_outpoint.index().unpack())
+        };
+
+        let (master, order_info, terms) = match order_data.to_enum() {
+            PartialOrderDataUnionReader::MintOrderData(mint) => {
+                (mint_master(mint)?, mint.order_info(), None)
+            }
+            PartialOrderDataUnionReader::MatchOrderData(match_) => {
+                (match_master(match_), match_.order_info(), None)
+            }
+            PartialOrderDataUnionReader::MintOrderWithTerms(mint) => {
+                let order = mint.order();
+                (mint_master(order)?, order.order_info(), Some(mint.terms()))
+            }
+            PartialOrderDataUnionReader::MatchOrderWithTerms(match_) => {
+                let order = match_.order();
+                (
+                    match_master(order),
+                    order.order_info(),
+                    Some(match_.terms()),
+                )
+            }
+        };
+
+        let info = OrderInfo {
+            ckb_to_udt: order_info.ckb_to_udt().into(),
+            udt_to_ckb: order_info.udt_to_ckb().into(),
+            ckb_min_match_log: order_info.ckb_min_match_log().unpack(),
+            expiry: decode_expiry(terms),
+            max_matcher_fee: decode_max_matcher_fee(terms),
+        };
+        Ok(Order {
+            out_point,
+            output,
+            udt_amount,
+            udt_layout,
+            master,
+            info,
+        })
+    }
+
+    #[must_use]
+    pub fn udt_hash(&self) -> Byte32 {
+        self.output.type_().to_opt().unwrap().calc_script_hash()
+    }
+
+    #[must_use]
+    pub fn ckb(&self) -> u64 {
+        self.output.capacity().unpack()
+    }
+
+    // Size of the limit order cell data
+    #[must_use]
+    pub fn data_size(&self) -> usize {
+        self.udt_layout.order_size() + self.info.terms_size()
+    }
+
+    #[must_use]
+    pub fn ckb_unoccupied(&self) -> u64 {
+        unoccupied_capacity(&self.output, self.data_size())
+    }
+
+    #[must_use]
+    pub fn is_expired(&self, tip: &HeaderView) -> bool {
+        self.info
+            .expiry
+            .is_some_and(|expiry| expiry.is_expired(tip))
+    }
+
+    #[must_use]
+    pub fn is_fulfilled(&self) -> bool {
+        let c2u_fulfilled = self.info.ckb_to_udt.is_null() || self.ckb_unoccupied() == 0;
+        let u2c_fulfilled = self.info.udt_to_ckb.is_null() || self.udt_amount == 0;
+        c2u_fulfilled && u2c_fulfilled
+    }
+
+    // Largest legal fill of the CKB -> UDT side with at most udt_available UDT from the matcher
+    pub fn max_ckb_to_udt_match(&self, udt_available: u128) -> Result<Match, Error> {
+        let ratio = self.info.ckb_to_udt;
+        if ratio.is_null() {
+            return Err(Error::InvalidMatch);
+        }
+        let unoccupied = self.ckb_unoccupied();
+        if unoccupied == 0 {
+            return Err(Error::AttemptToChangeFulfilled);
+        }
+
+        // ckb_taken * ckb_multiplier * (1 - fee) <= udt_given * udt_multiplier
+        let (ckb_mul, udt_mul) = (
+            U256::from(ratio.ckb_multiplier),
+            U256::from(ratio.udt_multiplier),
+        );
+        let (fee_denominator, fee_numerator) = (
+            fee_denominator(),
+            fee_denominator() - self.info.matcher_fee(),
+        );
+        let max_ckb =
+            U256::from(udt_available) * udt_mul * fee_denominator / (ckb_mul * fee_numerator);
+        let ckb_taken = if max_ckb < U256::from(unoccupied) {
+            max_ckb.as_u64()
+        } else {
+            unoccupied
+        };
+        if ckb_taken == 0
+            || (ckb_taken < unoccupied && u128::from(ckb_taken) < self.info.ckb_min_match())
+        {
+            return Err(Error::InsufficientMatch);
+        }
+        let udt_given = div_ceil(
+            U256::from(ckb_taken) * ckb_mul * fee_numerator,
+            udt_mul * fee_denominator,
+        )
+        .as_u128();
+
+        Ok(Match {
+            ckb: self.ckb() - ckb_taken,
+            udt_amount: self.udt_amount + udt_given,
+        })
+    }
+
+    // Largest legal fill of the UDT -> CKB side with at most ckb_available CKB from the matcher
+    pub fn max_udt_to_ckb_match(&self, ckb_available: u64) -> Result<Match, Error> {
+        let ratio = self.info.udt_to_ckb;
+        if ratio.is_null() {
+            return Err(Error::InvalidMatch);
+        }
+        if self.udt_amount == 0 {
+            return Err(Error::AttemptToChangeFulfilled);
+        }
+
+        // udt_taken * udt_multiplier * (1 - fee) <= ckb_given * ckb_multiplier
+        let (ckb_mul, udt_mul) = (
+            U256::from(ratio.ckb_multiplier),
+            U256::from(ratio.udt_multiplier),
+        );
+        let (fee_denominator, fee_numerator) = (
+            fee_denominator(),
+            fee_denominator() - self.info.matcher_fee(),
+        );
+        let max_udt =
+            U256::from(ckb_available) * ckb_mul * fee_denominator / (udt_mul * fee_numerator);
+        let udt_taken = if max_udt < U256::from(self.udt_amount) {
+            max_udt.as_u128()
+        } else {
+            self.udt_amount
+        };
+        if udt_taken == 0
+            || (udt_taken < self.udt_amount
+                && U256::from(udt_taken) * udt_mul
+                    < U256::from(self.info.ckb_min_match()) * ckb_mul)
+        {
+            return Err(Error::InsufficientMatch);
+        }
+        let ckb_given = div_ceil(
+            U256::from(udt_taken) * udt_mul * fee_numerator,
+            ckb_mul * fee_denominator,
+        )
+        .as_u64();
+
+        Ok(Match {
+            ckb: self.ckb() + ckb_given,
+            udt_amount: self.udt_amount - udt_taken,
+        })
+    }
+
+    // The order after the match, its data now references the master directly
+    #[must_use]
+    pub fn apply(&self, m: &Match) -> (CellOutput, Bytes) {
+        let output = self
+            .output
+            .clone()
+            .as_builder()
+            .capacity(m.ckb.pack())
+            .build();
+        let action = OrderAction::Match {
+            master: self.master.clone(),
+        };
+        let data = encode_order_data(m.udt_amount, &self.udt_layout, &action, &self.info);
+        (output, data)
+    }
+
+    // Order data referencing the master directly, as after a match
+    fn data(&self) -> Bytes {
+        let action = OrderAction::Match {
+            master: self.master.clone(),
+        };
+        encode_order_data(self.udt_amount, &self.udt_layout, &action, &self.info)
+    }
+
+    fn input_cell(&self, since: u64) -> MemoryCell {
+        input_cell(&self.out_point, since, &self.output, &self.data())
+    }
+
+    fn output_cell(&self) -> MemoryCell {
+        memory_cell(&self.output, &self.data())
+    }
+}
+
+// Order state after a match: its total capacity and UDT amount
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub ckb: u64,
+    pub udt_amount: u128,
+}
+
+fn fee_denominator() -> U256 {
+    U256::from(MATCHER_FEE_DENOMINATOR)
+}
+
+fn div_ceil(a: U256, b: U256) -> U256 {
+    (a + b - 1) / b
+}
+
+// UDT traded by a limit order, layout must be the one expected by its type script,
+// for example XudtData for xUDT with extensions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderUdt {
+    pub script: Script,
+    pub layout: UdtLayout,
+}
+
+impl From<&Script> for OrderUdt {
+    fn from(script: &Script) -> Self {
+        OrderUdt {
+            script: script.clone(),
+            layout: UdtLayout::Udt,
+        }
+    }
+}
+
+// Mint a limit order selling udt and/or CKB, the master cell follows the order and is locked by lock
+pub fn mint_order(
+    scripts: &Scripts,
+    inputs: &[OutPoint],
+    udt: &OrderUdt,
+    info: &OrderInfo,
+    ckb_unoccupied: u64,
+    udt_amount: u128,
+    lock: &Script,
+) -> Result<TransactionView, Error> {
+    let action = OrderAction::Mint { master_distance: 1 };
+    let data = encode_order_data(udt_amount, &udt.layout, &action, info);
+    let order = new_cell_output(
+        &scripts.limit_order,
+        Some(&udt.script),
+        data.len(),
+        ckb_unoccupied,
+    );
+    let master_data = info.expiry.map_or_else(Bytes::new, |expiry| {
+        Bytes::from(expiry.since.to_le_bytes().to_vec())
+    });
+    let master = new_cell_output(lock, Some(&scripts.limit_order), master_data.len(), 0);
+
+    let tx = new_transaction(
+        scripts,
+        inputs,
+        Vec::new(),
+        vec![(order, data), (master, master_data)],
+    );
+    check_orders(&scripts.limit_order, &output_cells(&tx))?;
+    Ok(tx)
+}
+
+// Match an order, the matcher adds the inputs and outputs for its side of the trade
+pub fn match_order(scripts: &Scripts, order: &Order, m: &Match) -> Result<TransactionView, Error> {
+    let (output, data) = order.apply(m);
+    check_match(
+        order,
+        &Order::new(order.out_point.clone(), output.clone(), &data)?,
+    )?;
+
+    Ok(new_transaction(
+        scripts,
+        &[order.out_point.clone()],
+        Vec::new(),
+        vec![(output, data)],
+    ))
+}
+
+// Melt orders together with their masters, UDT are returned to lock in one cell per UDT type,
+// CKB are left to the wallet to collect as change
+#[must_use]
+pub fn melt_orders(scripts: &Scripts, orders: &[Order], lock: &Script) -> TransactionView {
+    let inputs: Vec<_> = orders
+        .iter()
+        .flat_map(|o| [o.out_point.clone(), o.master.clone()])
+        .collect();
+
+    let mut udt_2_amount: BTreeMap<Vec<u8>, (Script, u128)> = BTreeMap::new();
+    for order in orders.iter().filter(|o| o.udt_amount > 0) {
+        let udt = order.output.type_().to_opt().unwrap();
+        udt_2_amount
+            .entry(udt.as_slice().to_vec())
+            .or_insert((udt, 0))
+            .1 += order.udt_amount;
+    }
+
+    let outputs = udt_2_amount
+        .into_values()
+        .map(|(udt, amount)| {
+            let data = UdtData::new_builder()
+                .udt_amount(amount.into())
+                .build()
+                .as_bytes();
+            (new_cell_output(lock, Some(&udt), data.len(), 0), data)
+        })
+        .collect();
+
+    new_transaction(scripts, &inputs, Vec::new(), outputs)
+}
+
+// Melt an expired order without its master, CKB and UDT are refunded to master_lock in a single cell,
+// while the transaction fee is left to the caller
+pub fn melt_expired_order(
+    scripts: &Scripts,
+    order: &Order,
+    master_lock: &Script,
+) -> Result<TransactionView, Error> {
+    let Some(expiry) = order.info.expiry else {
+        return Err(Error::InvalidConfiguration);
+    };
+
+    let udt = order.output.type_().to_opt();
+    let udt_data = UdtData::new_builder()
+        .udt_amount(order.udt_amount.into())
+        .build();
+    let data: Bytes = match &order.udt_layout {
+        UdtLayout::Udt => udt_data.as_bytes(),
+        UdtLayout::XudtData(xudt_data) => [udt_data.as_slice(), xudt_data].concat().into(),
+    };
+    let refund = new_cell_output(master_lock, udt.as_ref(), data.len(), 0)
+        .as_builder()
+        .capacity(order.ckb().pack())
+        .build();
+
+    let tx = new_transaction(scripts, &[], Vec::new(), vec![(refund, data)])
+        .as_advanced_builder()
+        .input(CellInput::new(order.out_point.clone(), expiry.since))
+        .build();
+    let mut cells = output_cells(&tx);
+    cells.inputs.push(order.input_cell(expiry.since));
+    check_orders(&scripts.limit_order, &cells)?;
+    Ok(tx)
+}
+
+// Burn the master of an expiring order alone, its order may have been melted without it.
+// The master CKB are left to the wallet to collect as change
+pub fn burn_expired_master(
+    scripts: &Scripts,
+    master: &OutPoint,
+    expiry: &OrderExpiry,
+) -> Result<TransactionView, Error> {
+    expiry.validate()?;
+
+    Ok(new_transaction(scripts, &[], Vec::new(), Vec::new())
+        .as_advanced_builder()
+        .input(CellInput::new(master.clone(), expiry.since))
+        .build())
+}
+
+// Runs limit_order::check_orders on the match of i into o, so limit_order::validate
+// and the check that UDT data is preserved
+pub fn check_match(i: &Order, o: &Order) -> Result<(), Error> {
+    let cells = MemoryCellSource {
+        inputs: vec![i.input_cell(0)],
+        outputs: vec![o.output_cell()],
+        ..MemoryCellSource::default()
+    };
+    check_orders(&i.output.lock(), &cells)
+}
+
+// Runs limit_order::check_orders on cells, as the script with code limit_order would
+fn check_orders(limit_order: &Script, cells: &MemoryCellSource) -> Result<(), Error> {
+    let limit_order_hash = limit_order.calc_script_hash().unpack();
+    Ok(limit_order::check_orders(cells, limit_order_hash)?)
+}
@@ -0,0 +1,60 @@
+use ckb_types::{
+    bytes::Bytes,
+    core::ScriptHashType,
+    packed::{Byte32, CellDep, Script},
+    prelude::*,
+};
+use ickb_rate::DepositLimits;
+
+// https://github.com/nervosnetwork/rfcs/blob/41a469a10cce2104656767944e9aba9a61ab497d/rfcs/0052-extensible-udt/0052-extensible-udt.md#owner-mode-update
+const XUDT_ARGS_FLAGS: [u8; 4] = [0, 0, 0, 128]; // Flag 0x80000000
+
+// Scripts of an iCKB deployment, together with the cell deps needed to run them
+#[derive(Debug, Clone)]
+pub struct Scripts {
+    pub dao: Script,
+    pub ickb_logic: Script,
+    pub ickb_udt: Script,
+    pub owned_owner: Script,
+    pub limit_order: Script,
+    pub cell_deps: Vec<CellDep>,
+    // Limits enforced by ickb_logic, v2 deployments also need their parameter cell in cell_deps
+    pub deposit_limits: DepositLimits,
+}
+
+impl Scripts {
+    // The iCKB xUDT is the xUDT in owner mode, owned by ickb_logic
+    #[must_use]
+    pub fn ickb_udt(ickb_logic: &Script, xudt_code_hash: Byte32) -> Script {
+        let args = [
+            ickb_logic.calc_script_hash().as_slice(),
+            XUDT_ARGS_FLAGS.as_slice(),
+        ]
+        .concat();
+        Script::new_builder()
+            .code_hash(xudt_code_hash)
+            .hash_type(ScriptHashType::Data1.into())
+            .args(Bytes::from(args).pack())
+            .build()
+    }
+
+    // The v2 ickb_logic reads its deposit limits from the parameter cell whose type is the type id
+    // with params_type_id_args, each parameter cell has its own ickb_logic script hash,
+    // so its own iCKB xUDT
+    #[must_use]
+    pub fn ickb_logic_v2(ickb_logic: &Script, params_type_id_args: Byte32) -> Script {
+        ickb_logic
+            .clone()
+            .as_builder()
+            .args(params_type_id_args.as_bytes().pack())
+            .build()
+    }
+
+    // Unspendable lock required by ickb_logic v2 on parameter cells, so their limits are frozen
+    #[must_use]
+    pub fn params_lock() -> Script {
+        Script::new_builder()
+            .hash_type(ScriptHashType::Data.into())
+            .build()
+    }
+}
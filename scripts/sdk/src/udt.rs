@@ -0,0 +1,19 @@
+use ckb_types::packed::OutPoint;
+
+use crate::{deposit::extract_udt_amount, Error};
+
+// iCKB xUDT cell
+#[derive(Debug, Clone)]
+pub struct UdtCell {
+    pub out_point: OutPoint,
+    pub amount: u128,
+}
+
+impl UdtCell {
+    pub fn new(out_point: OutPoint, data: &[u8]) -> Result<Self, Error> {
+        Ok(UdtCell {
+            out_point,
+            amount: extract_udt_amount(data)?,
+        })
+    }
+}
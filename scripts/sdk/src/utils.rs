@@ -0,0 +1,87 @@
+use ckb_types::{
+    core::{Capacity, HeaderView, TransactionView},
+    packed::{CellOutput, OutPoint, Script},
+    prelude::*,
+};
+use utils::{MemoryCell, MemoryCellSource};
+
+// https://github.com/nervosnetwork/rfcs/blob/master/rfcs/0023-dao-deposit-withdraw/0023-dao-deposit-withdraw.md#deposit
+pub(crate) const DAO_DEPOSIT_DATA: [u8; 8] = [0; 8];
+
+// Accumulated rate stored in the DAO field of a header
+#[must_use]
+pub fn accumulated_rate(header: &HeaderView) -> u64 {
+    let dao = header.dao();
+    u64::from_le_bytes(dao.as_slice()[8..16].try_into().unwrap())
+}
+
+// Cell output with exactly unoccupied shannons over its occupied capacity
+#[must_use]
+pub fn new_cell_output(
+    lock: &Script,
+    type_: Option<&Script>,
+    data_len: usize,
+    unoccupied: u64,
+) -> CellOutput {
+    let output = CellOutput::new_builder()
+        .lock(lock.clone())
+        .type_(type_.cloned().pack())
+        .build();
+    let occupied = occupied_capacity(&output, data_len);
+    output
+        .as_builder()
+        .capacity((occupied + unoccupied).pack())
+        .build()
+}
+
+pub(crate) fn occupied_capacity(output: &CellOutput, data_len: usize) -> u64 {
+    output
+        .occupied_capacity(Capacity::bytes(data_len).unwrap())
+        .unwrap()
+        .as_u64()
+}
+
+pub(crate) fn unoccupied_capacity(output: &CellOutput, data_len: usize) -> u64 {
+    let capacity: u64 = output.capacity().unpack();
+    capacity.saturating_sub(occupied_capacity(output, data_len))
+}
+
+// Cell as loaded by the iCKB scripts through utils::CellSource
+pub(crate) fn memory_cell(output: &CellOutput, data: &[u8]) -> MemoryCell {
+    MemoryCell {
+        capacity: output.capacity().unpack(),
+        occupied_capacity: occupied_capacity(output, data.len()),
+        lock_hash: output.lock().calc_script_hash().unpack(),
+        type_hash: output
+            .type_()
+            .to_opt()
+            .map(|s| s.calc_script_hash().unpack()),
+        data: data.to_vec(),
+        ..MemoryCell::default()
+    }
+}
+
+// Input cell as loaded by the iCKB scripts, together with its out point and since
+pub(crate) fn input_cell(
+    out_point: &OutPoint,
+    since: u64,
+    output: &CellOutput,
+    data: &[u8],
+) -> MemoryCell {
+    MemoryCell {
+        out_point: Some((out_point.tx_hash().unpack(), out_point.index().unpack())),
+        since,
+        ..memory_cell(output, data)
+    }
+}
+
+// Outputs of tx, so the script checks on outputs can run locally with the on-chain code
+pub(crate) fn output_cells(tx: &TransactionView) -> MemoryCellSource {
+    MemoryCellSource {
+        outputs: tx
+            .outputs_with_data_iter()
+            .map(|(output, data)| memory_cell(&output, &data))
+            .collect(),
+        ..MemoryCellSource::default()
+    }
+}
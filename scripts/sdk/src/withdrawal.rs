@@ -0,0 +1,286 @@
+use ckb_types::{
+    bytes::Bytes,
+    core::{EpochNumberWithFraction, HeaderView, TransactionView},
+    packed::{Byte32, CellInput, CellOutput, OutPoint, Script, WitnessArgs},
+    prelude::*,
+};
+use ickb_rate::DepositLimits;
+use ickb_types::{OwnedOwnerData, OwnedOwnerDataReader, UdtData};
+
+use crate::{
+    accumulated_rate,
+    deposit::{check_output, new_transaction},
+    new_cell_output,
+    utils::{occupied_capacity, output_cells, unoccupied_capacity, DAO_DEPOSIT_DATA},
+    Error, Scripts, UdtCell,
+};
+
+// https://github.com/nervosnetwork/rfcs/blob/master/rfcs/0023-dao-deposit-withdraw/0023-dao-deposit-withdraw.md#withdraw-phase-2
+const DAO_LOCK_PERIOD_EPOCHS: u64 = 180;
+const SINCE_ABSOLUTE_EPOCH_FLAG: u64 = 0x2000_0000_0000_0000;
+
+// iCKB deposit, header is the one of the block that includes it
+#[derive(Debug, Clone)]
+pub struct Deposit {
+    pub out_point: OutPoint,
+    pub output: CellOutput,
+    pub header: HeaderView,
+}
+
+impl Deposit {
+    #[must_use]
+    pub fn unoccupied(&self) -> u64 {
+        unoccupied_capacity(&self.output, DAO_DEPOSIT_DATA.len())
+    }
+
+    // iCKB value of the deposit, as computed by ickb_logic with limits,
+    // None if the header has a zero accumulated rate
+    #[must_use]
+    pub fn ickb_amount(&self, limits: &DepositLimits) -> Option<u128> {
+        limits.ickb_for_deposit(self.unoccupied(), accumulated_rate(&self.header))
+    }
+}
+
+// Pick the most valuable deposits whose total iCKB value doesn't exceed max_ickb
+#[must_use]
+pub fn select_deposits(
+    deposits: &[Deposit],
+    max_ickb: u128,
+    limits: &DepositLimits,
+) -> Vec<Deposit> {
+    let mut deposits: Vec<_> = deposits
+        .iter()
+        .filter_map(|d| Some((d.ickb_amount(limits)?, d)))
+        .collect();
+    deposits.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+    let mut total = 0;
+    let mut selected = Vec::new();
+    for (amount, deposit) in deposits {
+        if total + amount <= max_ickb {
+            total += amount;
+            selected.push(deposit.clone());
+        }
+    }
+    selected
+}
+
+// Withdrawal phase 1: burn iCKB to convert deposits into DAO withdrawal requests locked by owned_owner.
+// Withdrawal requests come first, each paired with the owner cell at the same offset in the
+// following group, then the iCKB change, if any. Owner and change cells are locked by lock.
+pub fn withdrawal_request(
+    scripts: &Scripts,
+    deposits: &[Deposit],
+    udt_cells: &[UdtCell],
+    lock: &Script,
+) -> Result<TransactionView, Error> {
+    let burned: u128 = deposits
+        .iter()
+        .map(|d| d.ickb_amount(&scripts.deposit_limits))
+        .sum::<Option<u128>>()
+        .ok_or(Error::InvalidAccumulatedRate)?;
+    let available: u128 = udt_cells.iter().map(|c| c.amount).sum();
+    if available < burned {
+        return Err(Error::AmountMismatch);
+    }
+
+    let inputs: Vec<_> = deposits
+        .iter()
+        .map(|d| d.out_point.clone())
+        .chain(udt_cells.iter().map(|c| c.out_point.clone()))
+        .collect();
+
+    let mut header_deps: Vec<Byte32> = Vec::new();
+    let mut outputs = Vec::new();
+    for deposit in deposits {
+        if !header_deps.contains(&deposit.header.hash()) {
+            header_deps.push(deposit.header.hash());
+        }
+
+        // Withdrawal request data is the deposit block number
+        let request = deposit
+            .output
+            .clone()
+            .as_builder()
+            .lock(scripts.owned_owner.clone())
+            .build();
+        let data = Bytes::from(deposit.header.number().to_le_bytes().to_vec());
+        outputs.push((request, data));
+    }
+
+    let owned_distance = -i32::try_from(deposits.len()).map_err(|_| Error::Mismatch)?;
+    let data = OwnedOwnerData::new_builder()
+        .owned_distance(owned_distance.into())
+        .build()
+        .as_bytes();
+    let owner = new_cell_output(lock, Some(&scripts.owned_owner), data.len(), 0);
+    for _ in deposits {
+        outputs.push((owner.clone(), data.clone()));
+    }
+
+    if available > burned {
+        let data = UdtData::new_builder()
+            .udt_amount((available - burned).into())
+            .build()
+            .as_bytes();
+        let change = new_cell_output(lock, Some(&scripts.ickb_udt), data.len(), 0);
+        outputs.push((change, data));
+    }
+
+    let tx = new_transaction(scripts, &inputs, header_deps, outputs);
+    check_output(scripts, &tx)?;
+    check_owned_owner(scripts, &tx)?;
+    Ok(tx)
+}
+
+// Withdrawal request locked by owned_owner together with its owner cell,
+// header is the one of the block that includes them
+#[derive(Debug, Clone)]
+pub struct WithdrawalRequest {
+    pub owned: OutPoint,
+    pub owned_output: CellOutput,
+    pub owner: OutPoint,
+    pub owner_output: CellOutput,
+    pub deposit_header: HeaderView,
+    pub header: HeaderView,
+}
+
+impl WithdrawalRequest {
+    // Pair the withdrawal requests created by tx, deposit_headers must contain the headers of the withdrawn deposits
+    pub fn from_transaction(
+        scripts: &Scripts,
+        tx: &TransactionView,
+        header: &HeaderView,
+        deposit_headers: &[HeaderView],
+    ) -> Result<Vec<Self>, Error> {
+        check_owned_owner(scripts, tx)?;
+
+        let out_point = |index: usize| OutPoint::new(tx.hash(), index as u32);
+        let mut requests = Vec::new();
+        for (index, (owner_output, data)) in tx.outputs_with_data_iter().enumerate() {
+            if owner_output.type_().to_opt() != Some(scripts.owned_owner.clone()) {
+                continue;
+            }
+
+            // Already validated by check_owned_owner
+            let owned_index = owned_index(index, &data)?;
+            let (owned_output, owned_data) = tx.output_with_data(owned_index).unwrap();
+            let deposit_block_number = u64::from_le_bytes(owned_data.as_ref().try_into().unwrap());
+            let deposit_header = deposit_headers
+                .iter()
+                .find(|h| h.number() == deposit_block_number)
+                .ok_or(Error::ItemMissing)?;
+
+            requests.push(WithdrawalRequest {
+                owned: out_point(owned_index),
+                owned_output,
+                owner: out_point(index),
+                owner_output,
+                deposit_header: deposit_header.clone(),
+                header: header.clone(),
+            });
+        }
+        Ok(requests)
+    }
+
+    // Maximum capacity withdrawable from the DAO
+    #[must_use]
+    pub fn maximum_withdraw(&self) -> u64 {
+        let occupied = occupied_capacity(&self.owned_output, DAO_DEPOSIT_DATA.len());
+        let counted = unoccupied_capacity(&self.owned_output, DAO_DEPOSIT_DATA.len());
+        let withdraw = u128::from(counted) * u128::from(accumulated_rate(&self.header))
+            / u128::from(accumulated_rate(&self.deposit_header));
+        occupied + u64::try_from(withdraw).unwrap()
+    }
+
+    // Minimal since of the withdrawal request input: the deposit must end its last lock period
+    #[must_use]
+    pub fn since(&self) -> u64 {
+        let deposit = self.deposit_header.epoch();
+        let request = self.header.epoch();
+        let mut passed_epochs = request.number() - deposit.number();
+        if request.index() * deposit.length() > deposit.index() * request.length() {
+            passed_epochs += 1;
+        }
+        let lock_epochs = passed_epochs.div_ceil(DAO_LOCK_PERIOD_EPOCHS) * DAO_LOCK_PERIOD_EPOCHS;
+        let unlock = EpochNumberWithFraction::new(
+            deposit.number() + lock_epochs,
+            deposit.index(),
+            deposit.length(),
+        );
+        SINCE_ABSOLUTE_EPOCH_FLAG | unlock.full_value()
+    }
+}
+
+// Withdrawal phase 2: withdraw from the DAO consuming both the withdrawal requests and their owner cells,
+// the whole capacity goes to a single cell locked by lock, the transaction fee is to be deducted from it
+#[must_use]
+pub fn withdrawal(
+    scripts: &Scripts,
+    requests: &[WithdrawalRequest],
+    lock: &Script,
+) -> TransactionView {
+    let mut header_deps: Vec<Byte32> = Vec::new();
+    let mut header_dep_index =
+        |header: &HeaderView| match header_deps.iter().position(|h| *h == header.hash()) {
+            Some(index) => index,
+            None => {
+                header_deps.push(header.hash());
+                header_deps.len() - 1
+            }
+        };
+
+    let mut inputs = Vec::new();
+    let mut witnesses = Vec::new();
+    let mut capacity = 0;
+    for request in requests {
+        // DAO expects the index of the deposit header in the input type of the witness
+        let deposit_header_index = header_dep_index(&request.deposit_header) as u64;
+        header_dep_index(&request.header);
+        let witness = WitnessArgs::new_builder()
+            .input_type(Some(Bytes::from(deposit_header_index.to_le_bytes().to_vec())).pack())
+            .build();
+
+        inputs.push(CellInput::new(request.owned.clone(), request.since()));
+        witnesses.push(witness.as_bytes());
+        inputs.push(CellInput::new(request.owner.clone(), 0));
+        witnesses.push(Bytes::new());
+
+        let owner_capacity: u64 = request.owner_output.capacity().unpack();
+        capacity += request.maximum_withdraw() + owner_capacity;
+    }
+
+    let output = CellOutput::new_builder()
+        .lock(lock.clone())
+        .capacity(capacity.pack())
+        .build();
+    new_transaction(scripts, &[], header_deps, vec![(output, Bytes::new())])
+        .as_advanced_builder()
+        .inputs(inputs)
+        .witnesses(witnesses.pack())
+        .build()
+}
+
+// Runs owned_owner::check_owned_owner on the outputs
+pub fn check_owned_owner(scripts: &Scripts, tx: &TransactionView) -> Result<(), Error> {
+    let owned_owner_hash = scripts.owned_owner.calc_script_hash().unpack();
+    Ok(owned_owner::check_owned_owner(
+        &output_cells(tx),
+        owned_owner_hash,
+    )?)
+}
+
+// As on-chain, data longer than expected is accepted and only its prefix is read
+fn owned_distance(data: &[u8]) -> Result<i32, Error> {
+    let data = data
+        .get(..OwnedOwnerData::TOTAL_SIZE)
+        .ok_or(Error::Encoding)?;
+    Ok(OwnedOwnerDataReader::new_unchecked(data)
+        .owned_distance()
+        .unpack())
+}
+
+fn owned_index(owner_index: usize, data: &[u8]) -> Result<usize, Error> {
+    let index = i64::from(owner_index as u32) + i64::from(owned_distance(data)?);
+    usize::try_from(index).map_err(|_| Error::Mismatch)
+}
@@ -0,0 +1,633 @@
+use crate::Loader;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_chain_spec::consensus::TYPE_ID_CODE_HASH;
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_hash::blake2b_256;
+use ckb_testtool::ckb_types::{
+    bytes::Bytes,
+    core::{
+        Capacity, Cycle, EpochNumberWithFraction, HeaderBuilder, HeaderView, ScriptHashType,
+        TransactionBuilder, TransactionView,
+    },
+    packed::*,
+    prelude::*,
+};
+use ckb_testtool::context::Context;
+use ickb_rate::DepositLimits;
+use ickb_sdk::order::{encode_order_data, UdtLayout};
+use ickb_tools::deployment::{parse_hash, to_hex};
+use ickb_tools::devnet::{parse_bytes, parse_hash_type};
+use ickb_tools::registry::Deployment;
+use ickb_tools::verify::dep_group_data;
+use ickb_types as types;
+use utils::XUDT_CODE_HASH;
+
+// Protocol constants, see contracts/ickb_logic/src/constants.rs and contracts/utils/src/constants.rs
+pub const CKB: u64 = 100_000_000;
+pub const GENESIS_ACCUMULATED_RATE: u64 = 10_000_000_000_000_000;
+const DAO_DEPOSIT_DATA: [u8; 8] = [0; 8];
+const XUDT_ARGS_FLAGS: [u8; 4] = [0, 0, 0, 128];
+
+// Genesis cells are deployed with a type id, whose args depend only on the output index,
+// so the DAO type script hash is the DAO code hash on every network
+const DAO_GENESIS_INDEX: u32 = 2;
+
+const RELEASE_BINARIES: [&str; 4] = ["ickb_logic", "limit_order", "owned_owner", "xudt"];
+
+// Binary in build/release with the data hash recorded by a network, build/release holds the
+// audited binaries, so network tests run what the network runs
+fn release_binary(data_hash: [u8; 32]) -> Bytes {
+    let loader = Loader::release();
+    RELEASE_BINARIES
+        .into_iter()
+        .map(|name| loader.load_binary(name))
+        .find(|data| blake2b_256(data) == data_hash)
+        .unwrap_or_else(|| {
+            panic!(
+                "no build/release binary with data hash {}",
+                to_hex(&data_hash)
+            )
+        })
+}
+
+#[must_use]
+pub fn genesis_type_id(index: u32) -> Script {
+    let cellbase = CellInput::new_cellbase_input(0);
+    let args = blake2b_256([cellbase.as_slice(), &u64::from(index).to_le_bytes()].concat());
+    Script::new_builder()
+        .code_hash(TYPE_ID_CODE_HASH.pack())
+        .hash_type(ScriptHashType::Type.into())
+        .args(args.as_slice().pack())
+        .build()
+}
+
+// Cell template, header is the block that includes the cell once it's created
+#[derive(Clone)]
+pub struct Cell {
+    pub output: CellOutput,
+    pub data: Bytes,
+    pub header: Option<HeaderView>,
+}
+
+impl Cell {
+    // Cell with exactly unoccupied shannons over its occupied capacity
+    #[must_use]
+    pub fn new(lock: &Script, type_: Option<&Script>, data: Bytes, unoccupied: u64) -> Self {
+        let output = CellOutput::new_builder()
+            .lock(lock.clone())
+            .type_(type_.cloned().pack())
+            .build();
+        let occupied = output
+            .occupied_capacity(Capacity::bytes(data.len()).unwrap())
+            .unwrap()
+            .as_u64();
+        let output = output
+            .as_builder()
+            .capacity((occupied + unoccupied).pack())
+            .build();
+        Cell {
+            output,
+            data,
+            header: None,
+        }
+    }
+
+    #[must_use]
+    pub fn in_block(self, header: HeaderView) -> Self {
+        Cell {
+            header: Some(header),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_lock(self, lock: &Script) -> Self {
+        Cell {
+            output: self.output.as_builder().lock(lock.clone()).build(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_data(self, data: Bytes) -> Self {
+        Cell { data, ..self }
+    }
+}
+
+// Order data types are shared with the SDK
+pub use ickb_sdk::order::{OrderAction, OrderInfo, Ratio};
+
+// Context with the iCKB scripts deployed
+pub struct Ickb {
+    pub context: Context,
+    // Lock of the test cells, on a network deployment its secp256k1 zero lock
+    pub always_success: Script,
+    pub dao: Script,
+    pub ickb_logic: Script,
+    pub ickb_udt: Script,
+    pub owned_owner: Script,
+    pub limit_order: Script,
+    // Cell deps of every transaction, empty when completed by the context
+    pub cell_deps: Vec<CellDep>,
+    // Cell deps added to every transaction on top of the script ones, such as a parameter cell
+    pub extra_cell_deps: Vec<CellDep>,
+    pub deposit_limits: DepositLimits,
+    // Counter of created transaction hashes
+    tx_count: u64,
+}
+
+#[must_use]
+pub fn deploy_ickb() -> Ickb {
+    Ickb::deploy(&Loader::default())
+}
+
+impl Ickb {
+    #[must_use]
+    pub fn deploy(loader: &Loader) -> Self {
+        let mut context = Context::default();
+        // Cells are created at out points derived from their name, script cells with a type id
+        // derived the same way, so script hashes and cycles do not change between runs
+        let mut create = |name: &str, type_: Option<Script>, data: Bytes| {
+            let seed = blake2b_256(name.as_bytes());
+            let out_point = OutPoint::new(seed.pack(), 0);
+            let type_ = type_.unwrap_or_else(|| {
+                Script::new_builder()
+                    .code_hash(TYPE_ID_CODE_HASH.pack())
+                    .hash_type(ScriptHashType::Type.into())
+                    .args(seed.as_slice().pack())
+                    .build()
+            });
+            let output = CellOutput::new_builder().type_(Some(type_).pack()).build();
+            context.create_cell_with_out_point(out_point.clone(), output, data);
+            out_point
+        };
+
+        let always_success_out_point = create("always_success", None, ALWAYS_SUCCESS.clone());
+        let ickb_logic_out_point = create("ickb_logic", None, loader.load_binary("ickb_logic"));
+        let owned_owner_out_point = create("owned_owner", None, loader.load_binary("owned_owner"));
+        let limit_order_out_point = create("limit_order", None, loader.load_binary("limit_order"));
+        // xUDT is not built by capsule, its mainnet binary is kept in build/release
+        let xudt_out_point = create("xudt", None, Loader::release().load_binary("xudt"));
+        // DAO rules are out of scope, so the genesis DAO type id is bound to always success
+        let dao_out_point = create(
+            "dao",
+            Some(genesis_type_id(DAO_GENESIS_INDEX)),
+            ALWAYS_SUCCESS.clone(),
+        );
+
+        let mut script = |out_point: &OutPoint| {
+            context
+                .build_script(out_point, Bytes::new())
+                .expect("script")
+        };
+        let always_success = script(&always_success_out_point);
+        let ickb_logic = script(&ickb_logic_out_point);
+        let owned_owner = script(&owned_owner_out_point);
+        let limit_order = script(&limit_order_out_point);
+        let dao = script(&dao_out_point);
+
+        let ickb_udt_args = [
+            ickb_logic.calc_script_hash().as_slice(),
+            XUDT_ARGS_FLAGS.as_slice(),
+        ]
+        .concat();
+        let ickb_udt = context
+            .build_script_with_hash_type(
+                &xudt_out_point,
+                ScriptHashType::Data1,
+                Bytes::from(ickb_udt_args),
+            )
+            .expect("script");
+
+        Ickb {
+            context,
+            always_success,
+            dao,
+            ickb_logic,
+            ickb_udt,
+            owned_owner,
+            limit_order,
+            cell_deps: Vec::new(),
+            extra_cell_deps: Vec::new(),
+            deposit_limits: DepositLimits::V1,
+            tx_count: 0,
+        }
+    }
+
+    // Context seeded with the cells and dep groups of a network at their recorded out points, so
+    // transactions resolve the iCKB dep group exactly as on that network. Code cells hold the
+    // build/release binaries with the data hashes recorded for that network, see release_binary.
+    // Genesis cells are always success stand-ins with the genesis type ids, so DAO and the
+    // deployment lock of the network, the secp256k1 zero lock, still resolve through the dep group.
+    #[must_use]
+    pub fn deploy_network(deployment: &Deployment, dep_group: &str) -> Self {
+        let mut context = Context::default();
+        for cell in &deployment.config.cells {
+            let deployed = deployment.cell(&cell.name).expect("deployed cell");
+            let data_hash = deployed
+                .data_hash
+                .or((cell.name == "XUDT").then_some(XUDT_CODE_HASH));
+            let (type_, data) = match data_hash {
+                Some(data_hash) => (None, release_binary(data_hash)),
+                None => {
+                    let index: u32 = deployed.out_point.index().unpack();
+                    (Some(genesis_type_id(index)), ALWAYS_SUCCESS.clone())
+                }
+            };
+            let output = Cell::new(&Script::default(), type_.as_ref(), data.clone(), 0).output;
+            context.create_cell_with_out_point(deployed.out_point.clone(), output, data);
+        }
+
+        for name in deployment.dep_groups.keys() {
+            let data = dep_group_data(deployment, name).expect("dep group cells");
+            let recorded = deployment.dep_group(name).expect("dep group");
+            assert_eq!(blake2b_256(&data), recorded.data_hash, "{name} data");
+            let output = Cell::new(&Script::default(), None, data.clone(), 0).output;
+            context.create_cell_with_out_point(recorded.out_point.clone(), output, data);
+        }
+
+        let data1 = |name: &str| {
+            let data_hash = deployment
+                .cell(name)
+                .and_then(|c| c.data_hash)
+                .expect("deployed from a file");
+            Script::new_builder()
+                .code_hash(data_hash.pack())
+                .hash_type(ScriptHashType::Data1.into())
+                .build()
+        };
+        let ickb_logic = data1("ICKB_LOGIC");
+        let ickb_udt_args = [
+            ickb_logic.calc_script_hash().as_slice(),
+            XUDT_ARGS_FLAGS.as_slice(),
+        ]
+        .concat();
+        let ickb_udt = Script::new_builder()
+            .code_hash(XUDT_CODE_HASH.pack())
+            .hash_type(ScriptHashType::Data1.into())
+            .args(Bytes::from(ickb_udt_args).pack())
+            .build();
+        let dao = Script::new_builder()
+            .code_hash(genesis_type_id(DAO_GENESIS_INDEX).calc_script_hash())
+            .hash_type(ScriptHashType::Type.into())
+            .build();
+        let lock = &deployment.config.lock;
+        let always_success = Script::new_builder()
+            .code_hash(parse_hash(&lock.code_hash).expect("lock code hash").pack())
+            .hash_type(
+                parse_hash_type(&lock.hash_type)
+                    .expect("lock hash type")
+                    .into(),
+            )
+            .args(Bytes::from(parse_bytes(&lock.args).expect("lock args")).pack())
+            .build();
+
+        Ickb {
+            context,
+            always_success,
+            dao,
+            ickb_logic,
+            ickb_udt,
+            owned_owner: data1("OWNED_OWNER"),
+            limit_order: data1("LIMIT_ORDER"),
+            cell_deps: vec![deployment.cell_dep(dep_group).expect("dep group")],
+            extra_cell_deps: Vec::new(),
+            deposit_limits: DepositLimits::V1,
+            tx_count: 0,
+        }
+    }
+
+    // Switch to ickb_logic v2, reading its deposit limits from a new type id parameter cell locked
+    // by lock, the cell is added to the cell deps of every following transaction
+    pub fn deploy_params(&mut self, limits: &DepositLimits, lock: &Script) {
+        let type_id_args = self.next_tx_hash();
+        let type_id = Script::new_builder()
+            .code_hash(TYPE_ID_CODE_HASH.pack())
+            .hash_type(ScriptHashType::Type.into())
+            .args(type_id_args.as_slice().pack())
+            .build();
+        let data = types::DepositLimits::new_builder()
+            .min_unoccupied_capacity(limits.min_unoccupied_capacity.into())
+            .max_unoccupied_capacity(limits.max_unoccupied_capacity.into())
+            .soft_cap(limits.soft_cap.into())
+            .build();
+        let out_point = self.create(Cell::new(lock, Some(&type_id), data.as_bytes(), 0));
+        self.extra_cell_deps
+            .push(CellDep::new_builder().out_point(out_point).build());
+
+        self.ickb_logic = self
+            .ickb_logic
+            .clone()
+            .as_builder()
+            .args(type_id_args.as_slice().pack())
+            .build();
+        let ickb_udt_args = [
+            self.ickb_logic.calc_script_hash().as_slice(),
+            XUDT_ARGS_FLAGS.as_slice(),
+        ]
+        .concat();
+        self.ickb_udt = self
+            .ickb_udt
+            .clone()
+            .as_builder()
+            .args(Bytes::from(ickb_udt_args).pack())
+            .build();
+        self.deposit_limits = *limits;
+    }
+
+    #[must_use]
+    pub fn capacity_cell(&self, unoccupied: u64) -> Cell {
+        Cell::new(&self.always_success, None, Bytes::new(), unoccupied)
+    }
+
+    // DAO deposit locked by ickb_logic, included in a block with the specified accumulated rate
+    #[must_use]
+    pub fn deposit_cell(&mut self, amount: u64, accumulated_rate: u64) -> Cell {
+        let data = Bytes::from(DAO_DEPOSIT_DATA.to_vec());
+        let header = self.header(accumulated_rate);
+        Cell::new(&self.ickb_logic, Some(&self.dao), data, amount).in_block(header)
+    }
+
+    // DAO withdrawal request locked by owned_owner
+    #[must_use]
+    pub fn withdrawal_request(&self, amount: u64, deposit_block_number: u64) -> Cell {
+        let data = Bytes::from(deposit_block_number.to_le_bytes().to_vec());
+        Cell::new(&self.owned_owner, Some(&self.dao), data, amount)
+    }
+
+    // ReceiptData
+    #[must_use]
+    pub fn receipt_cell(&self, quantity: u32, amount: u64) -> Cell {
+        let data = types::ReceiptData::new_builder()
+            .deposit_quantity(quantity.into())
+            .deposit_amount(amount.into())
+            .build();
+        Cell::new(
+            &self.always_success,
+            Some(&self.ickb_logic),
+            data.as_bytes(),
+            0,
+        )
+    }
+
+    // UdtData
+    #[must_use]
+    pub fn udt_cell(&self, amount: u128) -> Cell {
+        let data = types::UdtData::new_builder()
+            .udt_amount(amount.into())
+            .build();
+        Cell::new(
+            &self.always_success,
+            Some(&self.ickb_udt),
+            data.as_bytes(),
+            0,
+        )
+    }
+
+    // OwnedOwnerData
+    #[must_use]
+    pub fn owner_cell(&self, distance: i32) -> Cell {
+        let data = types::OwnedOwnerData::new_builder()
+            .owned_distance(distance.into())
+            .build();
+        Cell::new(
+            &self.always_success,
+            Some(&self.owned_owner),
+            data.as_bytes(),
+            0,
+        )
+    }
+
+    // UdtData followed by PartialOrderData
+    #[must_use]
+    pub fn limit_order_cell(
+        &self,
+        info: &OrderInfo,
+        action: &OrderAction,
+        unoccupied: u64,
+        udt_amount: u128,
+    ) -> Cell {
+        let data = encode_order_data(udt_amount, &UdtLayout::Udt, action, info);
+        Cell::new(&self.limit_order, Some(&self.ickb_udt), data, unoccupied)
+    }
+
+    #[must_use]
+    pub fn master_cell(&self) -> Cell {
+        Cell::new(
+            &self.always_success,
+            Some(&self.limit_order),
+            Bytes::new(),
+            0,
+        )
+    }
+
+    // Master of an expiring order, carrying its expiry since
+    #[must_use]
+    pub fn expiring_master_cell(&self, since: u64) -> Cell {
+        self.master_cell()
+            .with_data(Bytes::from(since.to_le_bytes().to_vec()))
+    }
+
+    // Deterministic transaction hashes, so cycles do not change between runs
+    fn next_tx_hash(&mut self) -> Byte32 {
+        self.tx_count += 1;
+        blake2b_256(self.tx_count.to_le_bytes()).pack()
+    }
+
+    // Block header whose DAO field contains the specified accumulated rate, every header is a
+    // different block, its parent hash comes from the same counter as transaction hashes
+    #[must_use]
+    pub fn header(&mut self, accumulated_rate: u64) -> HeaderView {
+        let mut dao = [0u8; 32];
+        dao[8..16].copy_from_slice(&accumulated_rate.to_le_bytes());
+        HeaderBuilder::default()
+            .number(1.pack())
+            .epoch(EpochNumberWithFraction::new(0, 1, 1000).full_value().pack())
+            .parent_hash(self.next_tx_hash())
+            .dao(dao.pack())
+            .build()
+    }
+
+    // Create a live cell
+    pub fn create(&mut self, cell: Cell) -> OutPoint {
+        let out_point = OutPoint::new(self.next_tx_hash(), 0);
+        self.insert(out_point.clone(), cell);
+        out_point
+    }
+
+    // Create live cells as the outputs of the same transaction, so relative distances are preserved
+    pub fn create_outputs(&mut self, cells: Vec<Cell>) -> Vec<OutPoint> {
+        let tx_hash = self.next_tx_hash();
+        cells
+            .into_iter()
+            .enumerate()
+            .map(|(index, cell)| {
+                let out_point = OutPoint::new(tx_hash.clone(), index as u32);
+                self.insert(out_point.clone(), cell);
+                out_point
+            })
+            .collect()
+    }
+
+    // Create the outputs of an already built transaction as live cells included in header block
+    pub fn commit(&mut self, tx: &TransactionView, header: &HeaderView) -> Vec<OutPoint> {
+        tx.outputs_with_data_iter()
+            .enumerate()
+            .map(|(index, (output, data))| {
+                let out_point = OutPoint::new(tx.hash(), index as u32);
+                let cell = Cell {
+                    output,
+                    data,
+                    header: Some(header.clone()),
+                };
+                self.insert(out_point.clone(), cell);
+                out_point
+            })
+            .collect()
+    }
+
+    fn insert(&mut self, out_point: OutPoint, cell: Cell) {
+        self.context
+            .create_cell_with_out_point(out_point.clone(), cell.output, cell.data);
+        if let Some(header) = cell.header {
+            let block_hash = header.hash();
+            self.context.insert_header(header);
+            self.context.link_cell_with_block(out_point, block_hash, 0);
+        }
+    }
+
+    // Create the pending input cells, complete cell deps and header deps
+    pub fn build(&mut self, tx: Tx) -> TransactionView {
+        let mut header_deps: Vec<Byte32> = Vec::new();
+        let mut inputs = Vec::new();
+        for (input, since) in tx.inputs {
+            let out_point = match input {
+                Input::OutPoint(out_point) => out_point,
+                Input::Cell(cell) => self.create(cell),
+            };
+            if let Some(info) = self.context.transaction_infos.get(&out_point) {
+                if tx.header_deps && !header_deps.contains(&info.block_hash) {
+                    header_deps.push(info.block_hash.clone());
+                }
+            }
+            inputs.push(
+                CellInput::new_builder()
+                    .previous_output(out_point)
+                    .since(since.pack())
+                    .build(),
+            );
+        }
+
+        let (outputs, outputs_data): (Vec<_>, Vec<_>) = tx
+            .outputs
+            .into_iter()
+            .map(|cell| (cell.output, cell.data))
+            .unzip();
+        let tx = TransactionBuilder::default()
+            .inputs(inputs)
+            .outputs(outputs)
+            .outputs_data(outputs_data.pack())
+            .header_deps(header_deps)
+            .build();
+        self.complete(tx)
+    }
+
+    // Fixed cell deps of a network deployment, otherwise the ones found by the context,
+    // followed by the extra cell deps
+    fn complete(&mut self, tx: TransactionView) -> TransactionView {
+        let tx = if self.cell_deps.is_empty() {
+            self.context.complete_tx(tx)
+        } else {
+            tx.as_advanced_builder()
+                .set_cell_deps(self.cell_deps.clone())
+                .build()
+        };
+        tx.as_advanced_builder()
+            .cell_deps(self.extra_cell_deps.clone())
+            .build()
+    }
+
+    pub fn verify(&mut self, tx: Tx, max_cycles: u64) -> Result<Cycle, Error> {
+        let tx = self.build(tx);
+        self.context.verify_tx(&tx, max_cycles)
+    }
+
+    // Complete the cell deps of an already built transaction and verify it
+    pub fn verify_tx(&mut self, tx: TransactionView, max_cycles: u64) -> Result<Cycle, Error> {
+        let tx = self.complete(tx);
+        self.context.verify_tx(&tx, max_cycles)
+    }
+
+    // SDK view of the deployed scripts, without a network deployment cell deps are completed by
+    // the context
+    #[must_use]
+    pub fn scripts(&self) -> ickb_sdk::Scripts {
+        ickb_sdk::Scripts {
+            dao: self.dao.clone(),
+            ickb_logic: self.ickb_logic.clone(),
+            ickb_udt: self.ickb_udt.clone(),
+            owned_owner: self.owned_owner.clone(),
+            limit_order: self.limit_order.clone(),
+            cell_deps: self.cell_deps.clone(),
+            deposit_limits: self.deposit_limits,
+        }
+    }
+}
+
+enum Input {
+    OutPoint(OutPoint),
+    Cell(Cell),
+}
+
+// Transaction template, by default header deps of inputs are added automatically
+pub struct Tx {
+    // Inputs with their since
+    inputs: Vec<(Input, u64)>,
+    outputs: Vec<Cell>,
+    header_deps: bool,
+}
+
+impl Default for Tx {
+    fn default() -> Self {
+        Tx {
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            header_deps: true,
+        }
+    }
+}
+
+impl Tx {
+    #[must_use]
+    pub fn input(mut self, out_point: &OutPoint) -> Self {
+        self.inputs.push((Input::OutPoint(out_point.clone()), 0));
+        self
+    }
+
+    #[must_use]
+    pub fn input_with_since(mut self, out_point: &OutPoint, since: u64) -> Self {
+        self.inputs
+            .push((Input::OutPoint(out_point.clone()), since));
+        self
+    }
+
+    // Input cell to be created just before building the transaction
+    #[must_use]
+    pub fn input_cell(mut self, cell: Cell) -> Self {
+        self.inputs.push((Input::Cell(cell), 0));
+        self
+    }
+
+    #[must_use]
+    pub fn output(mut self, cell: Cell) -> Self {
+        self.outputs.push(cell);
+        self
+    }
+
+    // Omit the header deps of inputs, for testing missing headers
+    #[must_use]
+    pub fn without_header_deps(mut self) -> Self {
+        self.header_deps = false;
+        self
+    }
+}
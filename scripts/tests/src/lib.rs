@@ -4,6 +4,8 @@ use std::fs;
 use std::path::PathBuf;
 use std::str::FromStr;
 
+pub mod fixtures;
+
 #[cfg(test)]
 mod tests;
 
@@ -39,6 +41,12 @@ impl Default for Loader {
 }
 
 impl Loader {
+    // build/release holds the audited binaries deployed on chain, never a local build
+    #[must_use]
+    pub fn release() -> Self {
+        Self::with_test_env(TestEnv::Release)
+    }
+
     fn with_test_env(env: TestEnv) -> Self {
         let load_prefix = match env {
             TestEnv::Debug => "debug",
@@ -64,6 +72,8 @@ impl Loader {
     pub fn load_binary(&self, name: &str) -> Bytes {
         let mut path = self.0.clone();
         path.push(name);
-        fs::read(path).expect("binary").into()
+        fs::read(&path)
+            .unwrap_or_else(|err| panic!("{}: {err}, run `capsule build` first", path.display()))
+            .into()
     }
 }
@@ -1,12 +1,27 @@
 use super::*;
+use crate::fixtures::{deploy_ickb, Tx, CKB, GENESIS_ACCUMULATED_RATE};
 use ckb_testtool::ckb_error::Error;
 use ckb_testtool::ckb_types::{bytes::Bytes, core::TransactionBuilder, packed::*, prelude::*};
 use ckb_testtool::context::Context;
 
+mod cycles;
+mod encoding;
+mod host;
+mod ickb_logic;
+mod ickb_rate;
+mod limit_order;
+mod limit_order_validate;
+mod network;
+mod owned_owner;
+mod replay;
+mod sdk;
+mod tools;
+
 const MAX_CYCLES: u64 = 10_000_000;
 
 // error numbers
-const ERROR_EMPTY_ARGS: i8 = 5;
+const ERROR_NOT_EMPTY_ARGS: i8 = 5;
+const ERROR_SCRIPT_MISUSE: i8 = 6;
 
 fn assert_script_error(err: Error, err_code: i8) {
     let error_string = err.to_string();
@@ -18,6 +33,22 @@ fn assert_script_error(err: Error, err_code: i8) {
 
 #[test]
 fn test_success() {
+    // deploy contract
+    let mut ickb = deploy_ickb();
+
+    // prepare transaction
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.deposit_cell(1_000 * CKB, GENESIS_ACCUMULATED_RATE))
+        .output(ickb.receipt_cell(1, 1_000 * CKB));
+
+    // run
+    let cycles = ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+    println!("consume cycles: {cycles}");
+}
+
+#[test]
+fn test_empty_args() {
     // deploy contract
     let mut context = Context::default();
     let contract_bin: Bytes = Loader::default().load_binary("ickb_logic");
@@ -25,7 +56,7 @@ fn test_success() {
 
     // prepare scripts
     let lock_script = context
-        .build_script(&out_point, Bytes::from(vec![42]))
+        .build_script(&out_point, Default::default())
         .expect("script");
 
     // prepare cells
@@ -61,14 +92,13 @@ fn test_success() {
     let tx = context.complete_tx(tx);
 
     // run
-    let cycles = context
-        .verify_tx(&tx, MAX_CYCLES)
-        .expect("pass verification");
-    println!("consume cycles: {cycles}");
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    // ickb_logic as lock is only valid for DAO deposits
+    assert_script_error(err, ERROR_SCRIPT_MISUSE);
 }
 
 #[test]
-fn test_empty_args() {
+fn test_not_empty_args() {
     // deploy contract
     let mut context = Context::default();
     let contract_bin: Bytes = Loader::default().load_binary("ickb_logic");
@@ -76,7 +106,7 @@ fn test_empty_args() {
 
     // prepare scripts
     let lock_script = context
-        .build_script(&out_point, Default::default())
+        .build_script(&out_point, Bytes::from(vec![42]))
         .expect("script");
 
     // prepare cells
@@ -113,5 +143,5 @@ fn test_empty_args() {
 
     // run
     let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
-    assert_script_error(err, ERROR_EMPTY_ARGS);
+    assert_script_error(err, ERROR_NOT_EMPTY_ARGS);
 }
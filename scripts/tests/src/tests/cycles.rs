@@ -0,0 +1,232 @@
+use crate::fixtures::{Ickb, OrderAction, OrderInfo, Ratio, Tx, CKB};
+use crate::Loader;
+use std::collections::BTreeMap;
+use std::{env, fs};
+
+// Cycle budget regression benchmarks: every transaction shape is verified and its cycles compared
+// against the checked-in baseline, failing when consumption grows past the tolerance.
+// Cycles are measured on build/release, the reproducible `capsule build --release` output, as debug
+// builds are not optimized. Refresh the baseline after a release build with:
+// UPDATE_CYCLES_BASELINE=1 cargo test cycles
+// Override the tolerance with: CYCLES_TOLERANCE_PERCENT=5 cargo test cycles
+
+const BASELINE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/cycles_baseline.txt");
+// Fixture hashes come from counters, so cycles are the same on every run and any growth fails
+const DEFAULT_TOLERANCE_PERCENT: u64 = 0;
+
+// CKB mainnet max block cycles
+const MAX_BLOCK_CYCLES: u64 = 3_500_000_000;
+
+const GENESIS_AR: u64 = crate::fixtures::GENESIS_ACCUMULATED_RATE;
+const AMOUNT: u64 = 1_000 * CKB;
+
+fn deploy() -> Ickb {
+    Ickb::deploy(&Loader::release())
+}
+
+fn deposit_phase_1(n: u32) -> (Ickb, Tx) {
+    let mut ickb = deploy();
+    let mut tx = Tx::default().input_cell(ickb.capacity_cell(u64::from(n) * 2 * AMOUNT));
+    for _ in 0..n {
+        tx = tx.output(ickb.deposit_cell(AMOUNT, GENESIS_AR));
+    }
+    tx = tx.output(ickb.receipt_cell(n, AMOUNT));
+    (ickb, tx)
+}
+
+fn deposit_phase_2(n: u32) -> (Ickb, Tx) {
+    let mut ickb = deploy();
+    let mut tx = Tx::default();
+    for _ in 0..n {
+        tx = tx.input_cell(
+            ickb.receipt_cell(1, AMOUNT)
+                .in_block(ickb.header(GENESIS_AR)),
+        );
+    }
+    tx = tx.output(ickb.udt_cell(u128::from(n) * u128::from(AMOUNT)));
+    (ickb, tx)
+}
+
+// Deposits converted into withdrawal requests, each one paired with its owner cell
+fn withdrawal_request(n: u32) -> (Ickb, Tx) {
+    let mut ickb = deploy();
+    let mut tx = Tx::default();
+    for _ in 0..n {
+        tx = tx.input_cell(ickb.deposit_cell(AMOUNT, GENESIS_AR));
+    }
+    tx = tx.input_cell(ickb.udt_cell(u128::from(n) * u128::from(AMOUNT)));
+    for _ in 0..n {
+        tx = tx.output(ickb.withdrawal_request(AMOUNT, 1));
+    }
+    for _ in 0..n {
+        tx = tx.output(ickb.owner_cell(-(n as i32)));
+    }
+    (ickb, tx)
+}
+
+fn withdrawal(n: u32) -> (Ickb, Tx) {
+    let mut ickb = deploy();
+    let mut tx = Tx::default();
+    for _ in 0..n {
+        let out_points = ickb.create_outputs(vec![
+            ickb.withdrawal_request(AMOUNT, 1),
+            ickb.owner_cell(-1),
+        ]);
+        tx = tx.input(&out_points[0]).input(&out_points[1]);
+    }
+    tx = tx.output(ickb.capacity_cell(u64::from(n) * AMOUNT));
+    (ickb, tx)
+}
+
+// Sell CKB for UDT at 1:1
+const INFO: OrderInfo = OrderInfo {
+    ckb_to_udt: Ratio {
+        ckb_multiplier: 1,
+        udt_multiplier: 1,
+    },
+    udt_to_ckb: Ratio {
+        ckb_multiplier: 0,
+        udt_multiplier: 0,
+    },
+    ckb_min_match_log: 33,
+    expiry: None,
+    max_matcher_fee: None,
+};
+
+fn limit_order_mint(n: u32) -> (Ickb, Tx) {
+    let ickb = deploy();
+    let mint = OrderAction::Mint { master_distance: 1 };
+    let mut tx = Tx::default().input_cell(ickb.capacity_cell(u64::from(n) * 3 * AMOUNT));
+    for _ in 0..n {
+        tx = tx
+            .output(ickb.limit_order_cell(&INFO, &mint, 2 * AMOUNT, 0))
+            .output(ickb.master_cell());
+    }
+    (ickb, tx)
+}
+
+// Partial match of every order, half of its CKB is bought with UDT
+fn limit_order_match(n: u32) -> (Ickb, Tx) {
+    let mut ickb = deploy();
+    let mint = OrderAction::Mint { master_distance: 1 };
+    let matched = u128::from(AMOUNT);
+    let mut tx = Tx::default();
+    let mut outputs = Vec::new();
+    for _ in 0..n {
+        let out_points = ickb.create_outputs(vec![
+            ickb.limit_order_cell(&INFO, &mint, 2 * AMOUNT, 0),
+            ickb.master_cell(),
+        ]);
+        let action = OrderAction::Match {
+            master: out_points[1].clone(),
+        };
+        tx = tx.input(&out_points[0]);
+        outputs.push(ickb.limit_order_cell(&INFO, &action, AMOUNT, matched));
+    }
+    tx = tx.input_cell(ickb.udt_cell(u128::from(n) * matched));
+    for output in outputs {
+        tx = tx.output(output);
+    }
+    tx = tx.output(ickb.capacity_cell(u64::from(n) * AMOUNT));
+    (ickb, tx)
+}
+
+fn limit_order_melt(n: u32) -> (Ickb, Tx) {
+    let mut ickb = deploy();
+    let mint = OrderAction::Mint { master_distance: 1 };
+    let mut tx = Tx::default();
+    for _ in 0..n {
+        let out_points = ickb.create_outputs(vec![
+            ickb.limit_order_cell(&INFO, &mint, 2 * AMOUNT, 0),
+            ickb.master_cell(),
+        ]);
+        tx = tx.input(&out_points[0]).input(&out_points[1]);
+    }
+    tx = tx.output(ickb.capacity_cell(u64::from(n) * 2 * AMOUNT));
+    (ickb, tx)
+}
+
+type Shape = fn(u32) -> (Ickb, Tx);
+
+const SHAPES: [(&str, Shape, &[u32]); 7] = [
+    ("ickb_logic/deposit_phase_1", deposit_phase_1, &[1, 8, 64]),
+    ("ickb_logic/deposit_phase_2", deposit_phase_2, &[1, 16, 64]),
+    (
+        "ickb_logic/withdrawal_request",
+        withdrawal_request,
+        &[1, 8, 32],
+    ),
+    ("owned_owner/withdrawal", withdrawal, &[1, 16, 64, 256]),
+    ("limit_order/mint", limit_order_mint, &[1, 16, 64]),
+    ("limit_order/match", limit_order_match, &[1, 16, 64]),
+    ("limit_order/melt", limit_order_melt, &[1, 16, 64]),
+];
+
+fn measure() -> BTreeMap<String, u64> {
+    let mut cycles = BTreeMap::new();
+    for (name, shape, sizes) in SHAPES {
+        for &n in sizes {
+            let (mut ickb, tx) = shape(n);
+            let consumed = ickb
+                .verify(tx, MAX_BLOCK_CYCLES)
+                .unwrap_or_else(|err| panic!("{name} x{n} fails verification: {err}"));
+            cycles.insert(format!("{name}/{n:02}"), consumed);
+        }
+    }
+    cycles
+}
+
+fn parse_baseline(baseline: &str) -> BTreeMap<String, u64> {
+    baseline
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (name, cycles) = line.split_once(' ').expect("name and cycles");
+            (name.to_string(), cycles.trim().parse().expect("cycles"))
+        })
+        .collect()
+}
+
+fn format_baseline(cycles: &BTreeMap<String, u64>) -> String {
+    let mut baseline = String::from("# Generated by UPDATE_CYCLES_BASELINE=1 cargo test cycles\n");
+    for (name, consumed) in cycles {
+        baseline.push_str(&format!("{name} {consumed}\n"));
+    }
+    baseline
+}
+
+#[test]
+fn test_cycles_baseline() {
+    let cycles = measure();
+    for (name, consumed) in &cycles {
+        let block_share = *consumed as f64 * 100.0 / MAX_BLOCK_CYCLES as f64;
+        println!("{name}: {consumed} cycles, {block_share:.3}% of a block");
+    }
+
+    if env::var("UPDATE_CYCLES_BASELINE").is_ok() {
+        fs::write(BASELINE_PATH, format_baseline(&cycles)).expect("write baseline");
+        return;
+    }
+
+    let tolerance: u64 = env::var("CYCLES_TOLERANCE_PERCENT")
+        .map(|t| t.parse().expect("tolerance percent"))
+        .unwrap_or(DEFAULT_TOLERANCE_PERCENT);
+    let baseline = parse_baseline(&fs::read_to_string(BASELINE_PATH).expect("read baseline"));
+
+    let regressions: Vec<String> = cycles
+        .iter()
+        .filter_map(|(name, &consumed)| match baseline.get(name) {
+            None => Some(format!("{name}: missing from baseline")),
+            Some(&expected) if consumed * 100 > expected * (100 + tolerance) => Some(format!(
+                "{name}: {consumed} cycles, baseline {expected} plus {tolerance}%"
+            )),
+            Some(_) => None,
+        })
+        .collect();
+    assert!(
+        regressions.is_empty(),
+        "cycles regressions:\n{}",
+        regressions.join("\n")
+    );
+}
@@ -0,0 +1,66 @@
+use crate::fixtures::{deploy_ickb, OrderAction, OrderInfo, Ratio};
+use ickb_types::{
+    molecule::prelude::*, PartialOrderDataReader, PartialOrderDataUnionReader, ReceiptDataReader,
+    UdtData,
+};
+
+// The scripts rely on these sizes when loading cell data
+#[test]
+fn test_encoding_sizes() {
+    let ickb = deploy_ickb();
+    assert_eq!(ickb.receipt_cell(1, 1).data.len(), 12);
+    assert_eq!(ickb.owner_cell(1).data.len(), 4);
+    assert_eq!(ickb.udt_cell(1).data.len(), 16);
+
+    let info = OrderInfo::default();
+    let mint = OrderAction::Mint { master_distance: 1 };
+    assert_eq!(ickb.limit_order_cell(&info, &mint, 0, 0).data.len(), 89);
+}
+
+#[test]
+fn test_encoding_round_trip() {
+    let ickb = deploy_ickb();
+
+    let receipt = ickb.receipt_cell(3, 42).data;
+    let receipt = ReceiptDataReader::from_slice(&receipt).unwrap();
+    assert_eq!(receipt.deposit_quantity().unpack(), 3);
+    assert_eq!(receipt.deposit_amount().unpack(), 42);
+
+    let info = OrderInfo {
+        ckb_to_udt: Ratio {
+            ckb_multiplier: 5,
+            udt_multiplier: 7,
+        },
+        udt_to_ckb: Ratio::default(),
+        ckb_min_match_log: 33,
+        expiry: None,
+        max_matcher_fee: None,
+    };
+    let mint = OrderAction::Mint {
+        master_distance: -1,
+    };
+    let order = ickb.limit_order_cell(&info, &mint, 0, 11).data;
+    let (udt_data, order_data) = order.split_at(UdtData::TOTAL_SIZE);
+    assert_eq!(
+        UdtData::from_slice(udt_data).unwrap().udt_amount().unpack(),
+        11
+    );
+    match PartialOrderDataReader::from_slice(order_data)
+        .unwrap()
+        .to_enum()
+    {
+        PartialOrderDataUnionReader::MintOrderData(mint) => {
+            assert_eq!(mint.master_distance().unpack(), -1);
+            let order_info = mint.order_info();
+            assert_eq!(order_info.ckb_to_udt().ckb_multiplier().unpack(), 5);
+            assert_eq!(order_info.ckb_to_udt().udt_multiplier().unpack(), 7);
+            assert_eq!(order_info.ckb_min_match_log().unpack(), 33);
+        }
+        _ => panic!("expected a mint order"),
+    }
+
+    // Unknown union item ids are rejected
+    let mut invalid = order_data.to_vec();
+    invalid[0] = 4;
+    assert!(PartialOrderDataReader::from_slice(&invalid).is_err());
+}
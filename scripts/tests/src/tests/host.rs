@@ -0,0 +1,1004 @@
+use super::*;
+use crate::fixtures::{OrderAction, OrderInfo, Ratio, CKB};
+use ::ickb_rate::DepositLimits;
+use ckb_std::{ckb_constants::Source, error::SysError};
+use ckb_testtool::ckb_types::core::ScriptHashType;
+use ickb_sdk::order::{encode_order_data, OrderExpiry, UdtLayout};
+use ickb_types::MATCHER_FEE_DENOMINATOR;
+use utils::{CellSource, MemoryCell, MemoryCellSource, DAO_DEPOSIT_DATA, DAO_HASH, XUDT_CODE_HASH};
+
+// Script validation logic run natively against MemoryCellSource, no RISC-V binary involved
+
+const ICKB_LOGIC_HASH: [u8; 32] = [1u8; 32];
+const OWNED_OWNER_HASH: [u8; 32] = [2u8; 32];
+const LIMIT_ORDER_HASH: [u8; 32] = [3u8; 32];
+const USER_LOCK_HASH: [u8; 32] = [4u8; 32];
+const UDT_HASH: [u8; 32] = [5u8; 32];
+const TX_HASH: [u8; 32] = [6u8; 32];
+
+const OCCUPIED: u64 = 82 * CKB;
+
+fn ickb_udt_hash() -> [u8; 32] {
+    let args = [ICKB_LOGIC_HASH.as_slice(), [0, 0, 0, 128].as_slice()].concat();
+    Script::new_builder()
+        .code_hash(XUDT_CODE_HASH.pack())
+        .hash_type(ScriptHashType::Data1.into())
+        .args(Bytes::from(args).pack())
+        .build()
+        .calc_script_hash()
+        .unpack()
+}
+
+// Header with the accumulated rate at the same offset of DAO field
+fn header(accumulated_rate: u64) -> Vec<u8> {
+    let mut header = vec![0u8; 208];
+    header[168..176].copy_from_slice(&accumulated_rate.to_le_bytes());
+    header
+}
+
+fn cell(
+    lock_hash: [u8; 32],
+    type_hash: Option<[u8; 32]>,
+    unoccupied: u64,
+    data: &[u8],
+) -> MemoryCell {
+    MemoryCell {
+        capacity: OCCUPIED + unoccupied,
+        occupied_capacity: OCCUPIED,
+        lock_hash,
+        type_hash,
+        data: data.to_vec(),
+        ..MemoryCell::default()
+    }
+}
+
+fn deposit(unoccupied: u64) -> MemoryCell {
+    cell(
+        ICKB_LOGIC_HASH,
+        Some(DAO_HASH),
+        unoccupied,
+        &DAO_DEPOSIT_DATA,
+    )
+}
+
+fn receipt(quantity: u32, amount: u64) -> MemoryCell {
+    let data = [
+        quantity.to_le_bytes().as_slice(),
+        amount.to_le_bytes().as_slice(),
+    ]
+    .concat();
+    cell(USER_LOCK_HASH, Some(ICKB_LOGIC_HASH), 0, &data)
+}
+
+fn udt(amount: u128) -> MemoryCell {
+    cell(
+        USER_LOCK_HASH,
+        Some(ickb_udt_hash()),
+        0,
+        &amount.to_le_bytes(),
+    )
+}
+
+// Give each input an OutPoint of the same transaction
+fn with_out_points(mut cells: Vec<MemoryCell>) -> Vec<MemoryCell> {
+    for (index, cell) in cells.iter_mut().enumerate() {
+        cell.out_point = Some((TX_HASH, index as u32));
+    }
+    cells
+}
+
+#[test]
+fn test_memory_cell_source_partial_loading() {
+    let cells = MemoryCellSource {
+        inputs: vec![],
+        outputs: vec![udt(7)],
+        cell_deps: vec![],
+    };
+
+    let mut buf = [0u8; 8];
+    let res = cells.load_cell_data(&mut buf, 0, 0, Source::Output);
+    assert!(matches!(res, Err(SysError::LengthNotEnough(16))));
+    assert_eq!(buf, 7u64.to_le_bytes());
+
+    let mut buf = [0u8; 32];
+    let res = cells.load_cell_data(&mut buf, 8, 0, Source::Output);
+    assert!(matches!(res, Ok(8)));
+
+    let res = cells.load_cell_data(&mut buf, 0, 1, Source::Output);
+    assert!(matches!(res, Err(SysError::IndexOutOfBound)));
+    let res = cells.load_header(&mut buf, 0, 0, Source::Output);
+    assert!(matches!(res, Err(SysError::ItemMissing)));
+}
+
+#[test]
+fn test_ickb_logic_check_output() {
+    let amount = 10_000 * CKB;
+    let cells = MemoryCellSource {
+        inputs: vec![],
+        outputs: vec![
+            deposit(amount),
+            deposit(amount),
+            receipt(2, amount),
+            udt(42),
+        ],
+        cell_deps: vec![],
+    };
+    let res = ::ickb_logic::check_output(&cells, ICKB_LOGIC_HASH, &DepositLimits::V1);
+    assert!(matches!(res, Ok(42)));
+
+    let cells = MemoryCellSource {
+        inputs: vec![],
+        outputs: vec![deposit(amount), receipt(2, amount)],
+        cell_deps: vec![],
+    };
+    let res = ::ickb_logic::check_output(&cells, ICKB_LOGIC_HASH, &DepositLimits::V1);
+    assert!(matches!(
+        res,
+        Err(::ickb_logic::error::Error::ReceiptMismatch)
+    ));
+
+    let cells = MemoryCellSource {
+        inputs: vec![],
+        outputs: vec![deposit(10 * CKB), receipt(1, 10 * CKB)],
+        cell_deps: vec![],
+    };
+    let res = ::ickb_logic::check_output(&cells, ICKB_LOGIC_HASH, &DepositLimits::V1);
+    assert!(matches!(
+        res,
+        Err(::ickb_logic::error::Error::DepositTooSmall)
+    ));
+
+    let cells = MemoryCellSource {
+        inputs: vec![],
+        outputs: vec![receipt(0, amount)],
+        cell_deps: vec![],
+    };
+    let res = ::ickb_logic::check_output(&cells, ICKB_LOGIC_HASH, &DepositLimits::V1);
+    assert!(matches!(res, Err(::ickb_logic::error::Error::EmptyReceipt)));
+}
+
+#[test]
+fn test_ickb_logic_check_input() {
+    let amount = 10_000 * CKB;
+    let ar = ::ickb_rate::GENESIS_ACCUMULATED_RATE as u64;
+    let mut inputs = vec![deposit(amount), receipt(3, amount), udt(42)];
+    for cell in &mut inputs[..2] {
+        cell.header = Some(header(ar));
+    }
+    let cells = MemoryCellSource {
+        inputs,
+        outputs: vec![],
+        cell_deps: vec![],
+    };
+
+    let Ok((udt_ickb, receipts_ickb, deposits_ickb)) =
+        ::ickb_logic::check_input(&cells, ICKB_LOGIC_HASH, &DepositLimits::V1)
+    else {
+        panic!("valid input");
+    };
+    let ickb = ::ickb_rate::ickb_for_deposit(amount, ar).unwrap();
+    assert_eq!(udt_ickb, 42);
+    assert_eq!(receipts_ickb, 3 * ickb);
+    assert_eq!(deposits_ickb, ickb);
+
+    // Deposit without its header
+    let cells = MemoryCellSource {
+        inputs: vec![deposit(amount)],
+        outputs: vec![],
+        cell_deps: vec![],
+    };
+    let res = ::ickb_logic::check_input(&cells, ICKB_LOGIC_HASH, &DepositLimits::V1);
+    assert!(matches!(res, Err(::ickb_logic::error::Error::ItemMissing)));
+}
+
+const PARAMS_TYPE_ID_ARGS: [u8; 32] = [7u8; 32];
+
+// Fork limits: deposits from 10 to 500 CKB, discounted over 100 iCKB
+const FORK_LIMITS: DepositLimits = DepositLimits {
+    min_unoccupied_capacity: 10 * CKB,
+    max_unoccupied_capacity: 500 * CKB,
+    soft_cap: 100 * CKB as u128,
+};
+
+fn params(limits: &DepositLimits) -> MemoryCell {
+    let data = ickb_sdk::deposit::encode_deposit_limits(limits);
+    let type_hash = ::ickb_logic::params_type_hash(PARAMS_TYPE_ID_ARGS);
+    cell(::ickb_logic::PARAMS_LOCK_HASH, Some(type_hash), 0, &data)
+}
+
+#[test]
+fn test_ickb_logic_deposit_limits() {
+    use ::ickb_logic::{deposit_limits, error::Error, params_type_hash, PARAMS_LOCK_HASH};
+    use ckb_testtool::ckb_chain_spec::consensus::TYPE_ID_CODE_HASH;
+
+    // The parameter cell is the type id with the v2 args, locked by the SDK params lock
+    let type_id = Script::new_builder()
+        .code_hash(TYPE_ID_CODE_HASH.pack())
+        .hash_type(ScriptHashType::Type.into())
+        .args(PARAMS_TYPE_ID_ARGS.to_vec().pack())
+        .build();
+    let expected: [u8; 32] = type_id.calc_script_hash().unpack();
+    assert_eq!(params_type_hash(PARAMS_TYPE_ID_ARGS), expected);
+    let expected: [u8; 32] = ickb_sdk::Scripts::params_lock().calc_script_hash().unpack();
+    assert_eq!(PARAMS_LOCK_HASH, expected);
+
+    // v1 ignores cell deps, v2 reads them
+    let cells = MemoryCellSource {
+        inputs: vec![],
+        outputs: vec![],
+        cell_deps: vec![udt(1), params(&FORK_LIMITS)],
+    };
+    assert!(matches!(deposit_limits(&cells, &[]), Ok(DepositLimits::V1)));
+    assert!(matches!(
+        deposit_limits(&cells, &PARAMS_TYPE_ID_ARGS),
+        Ok(FORK_LIMITS)
+    ));
+    assert!(matches!(
+        deposit_limits(&cells, &[8u8; 32]),
+        Err(Error::ParamsMissing)
+    ));
+    assert!(matches!(
+        deposit_limits(&cells, &[7u8; 20]),
+        Err(Error::NotEmptyArgs)
+    ));
+
+    // Parameter cells with trailing data or unreasonable limits
+    let mut extended = params(&FORK_LIMITS);
+    extended.data.push(0);
+    let inverted = DepositLimits {
+        min_unoccupied_capacity: 500 * CKB,
+        max_unoccupied_capacity: 10 * CKB,
+        ..FORK_LIMITS
+    };
+    for params in [extended, params(&inverted)] {
+        let cells = MemoryCellSource {
+            inputs: vec![],
+            outputs: vec![],
+            cell_deps: vec![params],
+        };
+        assert!(matches!(
+            deposit_limits(&cells, &PARAMS_TYPE_ID_ARGS),
+            Err(Error::ParamsInvalid)
+        ));
+    }
+
+    // Parameter cells that can be consumed could have their limits updated
+    let cells = MemoryCellSource {
+        inputs: vec![],
+        outputs: vec![],
+        cell_deps: vec![MemoryCell {
+            lock_hash: USER_LOCK_HASH,
+            ..params(&FORK_LIMITS)
+        }],
+    };
+    assert!(matches!(
+        deposit_limits(&cells, &PARAMS_TYPE_ID_ARGS),
+        Err(Error::ParamsMutable)
+    ));
+}
+
+#[test]
+fn test_ickb_logic_v2_limits() {
+    use ::ickb_logic::{check_input, check_output, error::Error};
+
+    // Deposit sizes follow the parameter cell instead of the v1 constants
+    let check = |amount: u64| {
+        let cells = MemoryCellSource {
+            inputs: vec![],
+            outputs: vec![deposit(amount), receipt(1, amount)],
+            cell_deps: vec![],
+        };
+        check_output(&cells, ICKB_LOGIC_HASH, &FORK_LIMITS)
+    };
+    assert!(matches!(check(10 * CKB), Ok(0)));
+    assert!(matches!(check(500 * CKB), Ok(0)));
+    assert!(matches!(check(10 * CKB - 1), Err(Error::DepositTooSmall)));
+    assert!(matches!(check(1_000 * CKB), Err(Error::DepositTooBig)));
+
+    // Same soft cap discount, applied over the fork soft cap
+    let amount = 300 * CKB;
+    let ar = ::ickb_rate::GENESIS_ACCUMULATED_RATE as u64;
+    let mut inputs = vec![deposit(amount), receipt(2, amount)];
+    for cell in &mut inputs {
+        cell.header = Some(header(ar));
+    }
+    let cells = MemoryCellSource {
+        inputs,
+        outputs: vec![],
+        cell_deps: vec![],
+    };
+    let Ok((_, receipts_ickb, deposits_ickb)) = check_input(&cells, ICKB_LOGIC_HASH, &FORK_LIMITS)
+    else {
+        panic!("valid input");
+    };
+    let ickb = u128::from(100 * CKB + 200 * CKB / 10 * 9);
+    assert_eq!(deposits_ickb, ickb);
+    assert_eq!(receipts_ickb, 2 * ickb);
+    assert_eq!(FORK_LIMITS.ickb_for_deposit(amount, ar), Some(ickb));
+}
+
+fn withdrawal_request(unoccupied: u64) -> MemoryCell {
+    cell(
+        OWNED_OWNER_HASH,
+        Some(DAO_HASH),
+        unoccupied,
+        &1u64.to_le_bytes(),
+    )
+}
+
+fn owner(distance: i32) -> MemoryCell {
+    cell(
+        USER_LOCK_HASH,
+        Some(OWNED_OWNER_HASH),
+        0,
+        &distance.to_le_bytes(),
+    )
+}
+
+#[test]
+fn test_owned_owner_check() {
+    use ::owned_owner::{check_owned_owner, error::Error};
+
+    let pairs = vec![
+        withdrawal_request(1_000 * CKB),
+        owner(-1),
+        owner(1),
+        withdrawal_request(1_000 * CKB),
+    ];
+    let cells = MemoryCellSource {
+        inputs: with_out_points(pairs.clone()),
+        outputs: pairs,
+        cell_deps: vec![],
+    };
+    assert!(check_owned_owner(&cells, OWNED_OWNER_HASH).is_ok());
+
+    let cells = MemoryCellSource {
+        inputs: vec![],
+        outputs: vec![withdrawal_request(1_000 * CKB), owner(-1), owner(-2)],
+        cell_deps: vec![],
+    };
+    let res = check_owned_owner(&cells, OWNED_OWNER_HASH);
+    assert!(matches!(res, Err(Error::Mismatch)));
+
+    let cells = MemoryCellSource {
+        inputs: with_out_points(vec![owner(1), withdrawal_request(1_000 * CKB)]),
+        outputs: vec![],
+        cell_deps: vec![],
+    };
+    assert!(check_owned_owner(&cells, OWNED_OWNER_HASH).is_ok());
+
+    let not_request = cell(OWNED_OWNER_HASH, Some(DAO_HASH), 0, &DAO_DEPOSIT_DATA);
+    let cells = MemoryCellSource {
+        inputs: vec![],
+        outputs: vec![not_request, owner(-1)],
+        cell_deps: vec![],
+    };
+    let res = check_owned_owner(&cells, OWNED_OWNER_HASH);
+    assert!(matches!(res, Err(Error::NotWithdrawalRequest)));
+}
+
+// CellSource counting the loads issued by the validation logic, each one a syscall on-chain
+struct CountingCellSource<'a> {
+    cells: &'a MemoryCellSource,
+    count: std::cell::Cell<u64>,
+}
+
+impl CountingCellSource<'_> {
+    fn tick(&self) {
+        self.count.set(self.count.get() + 1);
+    }
+}
+
+impl CellSource for CountingCellSource<'_> {
+    fn load_cell_data(
+        &self,
+        buf: &mut [u8],
+        offset: usize,
+        index: usize,
+        source: Source,
+    ) -> Result<usize, SysError> {
+        self.tick();
+        self.cells.load_cell_data(buf, offset, index, source)
+    }
+
+    fn load_header(
+        &self,
+        buf: &mut [u8],
+        offset: usize,
+        index: usize,
+        source: Source,
+    ) -> Result<usize, SysError> {
+        self.tick();
+        self.cells.load_header(buf, offset, index, source)
+    }
+
+    fn load_cell_capacity(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        self.tick();
+        self.cells.load_cell_capacity(index, source)
+    }
+
+    fn load_cell_occupied_capacity(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        self.tick();
+        self.cells.load_cell_occupied_capacity(index, source)
+    }
+
+    fn load_cell_lock_hash(&self, index: usize, source: Source) -> Result<[u8; 32], SysError> {
+        self.tick();
+        self.cells.load_cell_lock_hash(index, source)
+    }
+
+    fn load_cell_type_hash(
+        &self,
+        index: usize,
+        source: Source,
+    ) -> Result<Option<[u8; 32]>, SysError> {
+        self.tick();
+        self.cells.load_cell_type_hash(index, source)
+    }
+
+    fn load_input_out_point(
+        &self,
+        index: usize,
+        source: Source,
+    ) -> Result<([u8; 32], u32), SysError> {
+        self.tick();
+        self.cells.load_input_out_point(index, source)
+    }
+
+    fn load_input_since(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        self.tick();
+        self.cells.load_input_since(index, source)
+    }
+}
+
+#[test]
+fn test_owned_owner_batch_loads() {
+    use ::owned_owner::check_owned_owner;
+
+    // Withdrawal of n pairs, with owner cells after all withdrawal requests
+    let loads = |n: usize| {
+        let mut inputs = vec![withdrawal_request(0); n];
+        inputs.extend((0..n).map(|_| owner(-(n as i32))));
+        let cells = MemoryCellSource {
+            inputs: with_out_points(inputs),
+            outputs: vec![cell(USER_LOCK_HASH, None, 0, &[])],
+            cell_deps: vec![],
+        };
+        let counter = CountingCellSource {
+            cells: &cells,
+            count: std::cell::Cell::new(0),
+        };
+        assert!(check_owned_owner(&counter, OWNED_OWNER_HASH).is_ok());
+        counter.count.get()
+    };
+
+    // Lock hash, type hash, data and out point of both cells, nothing loaded twice
+    let base = loads(0);
+    for n in [1, 16, 256] {
+        assert_eq!(loads(n) - base, 8 * n as u64);
+    }
+}
+
+const INFO: OrderInfo = OrderInfo {
+    ckb_to_udt: Ratio {
+        ckb_multiplier: 1,
+        udt_multiplier: 1,
+    },
+    udt_to_ckb: Ratio {
+        ckb_multiplier: 0,
+        udt_multiplier: 0,
+    },
+    ckb_min_match_log: 33,
+    expiry: None,
+    max_matcher_fee: None,
+};
+
+fn order(action: &OrderAction, info: &OrderInfo, unoccupied: u64, udt_amount: u128) -> MemoryCell {
+    let data = encode_order_data(udt_amount, &UdtLayout::Udt, action, info);
+    cell(LIMIT_ORDER_HASH, Some(UDT_HASH), unoccupied, &data)
+}
+
+fn master() -> MemoryCell {
+    cell(USER_LOCK_HASH, Some(LIMIT_ORDER_HASH), 0, &[])
+}
+
+#[test]
+fn test_limit_order_extract_order() {
+    use ::limit_order::{error::Error, extract_order};
+    let mint = OrderAction::Mint { master_distance: 1 };
+
+    let cells = MemoryCellSource {
+        inputs: vec![],
+        outputs: vec![order(&mint, &INFO, 2_000 * CKB, 7)],
+        cell_deps: vec![],
+    };
+    let Ok((metapoint, data)) = extract_order(&cells, 0, Source::Output) else {
+        panic!("valid order");
+    };
+    assert_eq!(metapoint.tx_hash, None);
+    assert_eq!(metapoint.index, 1);
+    assert!(data.ckb == utils::C256::from(OCCUPIED + 2_000 * CKB));
+    assert!(data.ckb_unoccupied == utils::C256::from(2_000 * CKB));
+    assert!(data.udt == utils::C256::from(7u64));
+    assert_eq!(data.info.udt_hash, UDT_HASH);
+    assert!(data.info.udt_to_ckb.is_none());
+
+    let master = OrderAction::Match {
+        master: OutPoint::new_builder()
+            .tx_hash(TX_HASH.pack())
+            .index(3u32.pack())
+            .build(),
+    };
+    let cells = MemoryCellSource {
+        inputs: vec![],
+        outputs: vec![order(&master, &INFO, 2_000 * CKB, 7)],
+        cell_deps: vec![],
+    };
+    let Ok((metapoint, _)) = extract_order(&cells, 0, Source::Output) else {
+        panic!("valid order");
+    };
+    assert_eq!(metapoint.tx_hash, Some(TX_HASH));
+    assert_eq!(metapoint.index, 3);
+
+    let concave = OrderInfo {
+        udt_to_ckb: Ratio {
+            ckb_multiplier: 2,
+            udt_multiplier: 1,
+        },
+        ..INFO
+    };
+    let cells = MemoryCellSource {
+        inputs: vec![],
+        outputs: vec![
+            order(&mint, &concave, 2_000 * CKB, 0),
+            order(&mint, &OrderInfo::default(), 2_000 * CKB, 0),
+            cell(
+                LIMIT_ORDER_HASH,
+                None,
+                0,
+                &encode_order_data(0, &UdtLayout::Udt, &mint, &INFO),
+            ),
+            cell(LIMIT_ORDER_HASH, Some(UDT_HASH), 0, &[0u8; 16]),
+        ],
+        cell_deps: vec![],
+    };
+    let res = extract_order(&cells, 0, Source::Output);
+    assert!(matches!(res, Err(Error::ConcaveRatio)));
+    let res = extract_order(&cells, 1, Source::Output);
+    assert!(matches!(res, Err(Error::BothRatioNull)));
+    let res = extract_order(&cells, 2, Source::Output);
+    assert!(matches!(res, Err(Error::MissingUdtType)));
+    let res = extract_order(&cells, 3, Source::Output);
+    assert!(matches!(res, Err(Error::Encoding)));
+}
+
+#[test]
+fn test_limit_order_check_orders() {
+    use ::limit_order::{check_orders, error::Error};
+    let mint = OrderAction::Mint { master_distance: 1 };
+
+    // Mint
+    let cells = MemoryCellSource {
+        inputs: vec![],
+        outputs: vec![order(&mint, &INFO, 2_000 * CKB, 0), master()],
+        cell_deps: vec![],
+    };
+    assert!(check_orders(&cells, LIMIT_ORDER_HASH).is_ok());
+
+    // Match, order was minted at index 0 and master at index 1 of TX_HASH
+    let matched = OrderAction::Match {
+        master: OutPoint::new_builder()
+            .tx_hash(TX_HASH.pack())
+            .index(1u32.pack())
+            .build(),
+    };
+    let inputs = with_out_points(vec![order(&mint, &INFO, 2_000 * CKB, 0)]);
+    let cells = MemoryCellSource {
+        inputs: inputs.clone(),
+        outputs: vec![order(&matched, &INFO, 1_000 * CKB, 1_000 * u128::from(CKB))],
+        cell_deps: vec![],
+    };
+    assert!(check_orders(&cells, LIMIT_ORDER_HASH).is_ok());
+
+    let cells = MemoryCellSource {
+        inputs: inputs.clone(),
+        outputs: vec![order(
+            &matched,
+            &INFO,
+            1_000 * CKB,
+            1_000 * u128::from(CKB) - 1,
+        )],
+        cell_deps: vec![],
+    };
+    let res = check_orders(&cells, LIMIT_ORDER_HASH);
+    assert!(matches!(res, Err(Error::DecreasingValue)));
+
+    // Melt
+    let cells = MemoryCellSource {
+        inputs: with_out_points(vec![order(&mint, &INFO, 2_000 * CKB, 0), master()]),
+        outputs: vec![],
+        cell_deps: vec![],
+    };
+    assert!(check_orders(&cells, LIMIT_ORDER_HASH).is_ok());
+
+    let cells = MemoryCellSource {
+        inputs,
+        outputs: vec![],
+        cell_deps: vec![],
+    };
+    let res = check_orders(&cells, LIMIT_ORDER_HASH);
+    assert!(matches!(res, Err(Error::InvalidConfiguration)));
+}
+
+// XudtData with a lock made of the given bytes
+fn xudt_data(lock: &[u8]) -> Bytes {
+    let lock = ickb_types::Bytes::new_builder()
+        .set(lock.iter().copied().map(Byte::new).collect())
+        .build();
+    ickb_types::XudtData::new_builder()
+        .lock(lock)
+        .build()
+        .as_bytes()
+}
+
+fn xudt_order(action: &OrderAction, xudt_data: &Bytes, unoccupied: u64, udt: u128) -> MemoryCell {
+    let layout = UdtLayout::XudtData(xudt_data.clone());
+    let data = encode_order_data(udt, &layout, action, &INFO);
+    cell(LIMIT_ORDER_HASH, Some(UDT_HASH), unoccupied, &data)
+}
+
+#[test]
+fn test_limit_order_xudt_data() {
+    use ::limit_order::{check_orders, error::Error, extract_order, UdtLayout as Layout};
+    let mint = OrderAction::Mint { master_distance: 1 };
+    let valid = xudt_order(&mint, &xudt_data(&[1]), 2_000 * CKB, 7);
+
+    let cells = MemoryCellSource {
+        inputs: vec![],
+        outputs: vec![valid.clone()],
+        cell_deps: vec![],
+    };
+    let Ok((_, data)) = extract_order(&cells, 0, Source::Output) else {
+        panic!("valid order");
+    };
+    assert!(data.udt == utils::C256::from(7u64));
+    let udt_data_size = 16 + xudt_data(&[1]).len() as u32;
+    assert!(data.info.udt_layout == Layout::XudtData { udt_data_size });
+
+    // Trailer is strategy followed by udt_data_size
+    let tampered = |f: &dyn Fn(&mut Vec<u8>)| {
+        let mut c = valid.clone();
+        f(&mut c.data);
+        c
+    };
+    let trailer = valid.data.len() - 5;
+    let cells = MemoryCellSource {
+        inputs: vec![],
+        outputs: vec![
+            tampered(&|d| d[trailer] = 1),
+            tampered(&|d| d[trailer + 1] += 1),
+            tampered(&|d| d[16..20].copy_from_slice(&[0xff; 4])),
+            tampered(&|d| d.truncate(20)),
+        ],
+        cell_deps: vec![],
+    };
+    let res = extract_order(&cells, 0, Source::Output);
+    assert!(matches!(res, Err(Error::InvalidUdtLayout)));
+    let res = extract_order(&cells, 1, Source::Output);
+    assert!(matches!(res, Err(Error::Encoding)));
+    let res = extract_order(&cells, 2, Source::Output);
+    assert!(matches!(res, Err(Error::InvalidUdtLayout)));
+    let res = extract_order(&cells, 3, Source::Output);
+    assert!(matches!(res, Err(Error::Encoding)));
+
+    // Match must preserve the xUDT data
+    let udt = 1_000 * u128::from(CKB) + 7;
+    let matched = OrderAction::Match {
+        master: OutPoint::new_builder()
+            .tx_hash(TX_HASH.pack())
+            .index(1u32.pack())
+            .build(),
+    };
+    let inputs = with_out_points(vec![valid]);
+    for (lock, ok) in [([1], true), ([2], false)] {
+        let cells = MemoryCellSource {
+            inputs: inputs.clone(),
+            outputs: vec![xudt_order(&matched, &xudt_data(&lock), 1_000 * CKB, udt)],
+            cell_deps: vec![],
+        };
+        let res = check_orders(&cells, LIMIT_ORDER_HASH);
+        assert_eq!(res.is_ok(), ok);
+        assert!(ok || matches!(res, Err(Error::UdtDataChanged)));
+    }
+
+    // A different layout is a different order info
+    let cells = MemoryCellSource {
+        inputs,
+        outputs: vec![xudt_order(&matched, &xudt_data(&[1, 2]), 1_000 * CKB, udt)],
+        cell_deps: vec![],
+    };
+    let res = check_orders(&cells, LIMIT_ORDER_HASH);
+    assert!(matches!(res, Err(Error::DifferentInfo)));
+}
+
+// Expiry at block 1_000, refunding to USER_LOCK_HASH, the lock of master()
+const EXPIRY: OrderExpiry = OrderExpiry {
+    since: 1_000,
+    master_lock_hash: USER_LOCK_HASH,
+};
+
+// Master of an expiring order, its data is the expiry since
+fn expiring_master(expiry: u64, since: u64) -> MemoryCell {
+    MemoryCell {
+        since,
+        ..cell(
+            USER_LOCK_HASH,
+            Some(LIMIT_ORDER_HASH),
+            0,
+            &expiry.to_le_bytes(),
+        )
+    }
+}
+
+fn expiring_order(action: &OrderAction, expiry: OrderExpiry, since: u64) -> MemoryCell {
+    let info = OrderInfo {
+        expiry: Some(expiry),
+        ..INFO
+    };
+    MemoryCell {
+        since,
+        ..order(action, &info, 2_000 * CKB, 7)
+    }
+}
+
+#[test]
+fn test_limit_order_expiry() {
+    use ::limit_order::{check_orders, error::Error, extract_order};
+    let mint = OrderAction::Mint { master_distance: 1 };
+
+    // Only absolute block numbers and epochs are valid expiries
+    let epoch = 0x2000_0100_0a00_0004;
+    let cells = MemoryCellSource {
+        inputs: vec![],
+        outputs: [
+            1_000,
+            epoch,
+            1 << 63 | 1_000,
+            0x4000_0000_6000_0000,
+            0x40ff_ffff_ffff_ffff,
+        ]
+        .map(|since| expiring_order(&mint, OrderExpiry { since, ..EXPIRY }, 0))
+        .to_vec(),
+        cell_deps: vec![],
+    };
+    let Ok((_, data)) = extract_order(&cells, 0, Source::Output) else {
+        panic!("valid order");
+    };
+    let expiry = data.info.expiry.expect("expiry");
+    assert_eq!(expiry.since.as_u64(), 1_000);
+    assert_eq!(expiry.master_lock_hash, USER_LOCK_HASH);
+    assert!(extract_order(&cells, 1, Source::Output).is_ok());
+    let res = extract_order(&cells, 2, Source::Output);
+    assert!(matches!(res, Err(Error::InvalidExpiry)));
+    let res = extract_order(&cells, 3, Source::Output);
+    assert!(matches!(res, Err(Error::InvalidExpiry)));
+    let res = extract_order(&cells, 4, Source::Output);
+    assert!(matches!(res, Err(Error::InvalidExpiry)));
+
+    // Mint, master_lock_hash must be the master lock and the master data the expiry since
+    let cells = MemoryCellSource {
+        inputs: vec![],
+        outputs: vec![expiring_order(&mint, EXPIRY, 0), expiring_master(1_000, 0)],
+        cell_deps: vec![],
+    };
+    assert!(check_orders(&cells, LIMIT_ORDER_HASH).is_ok());
+    let other_lock = OrderExpiry {
+        master_lock_hash: UDT_HASH,
+        ..EXPIRY
+    };
+    let cells = MemoryCellSource {
+        inputs: vec![],
+        outputs: vec![
+            expiring_order(&mint, other_lock, 0),
+            expiring_master(1_000, 0),
+        ],
+        cell_deps: vec![],
+    };
+    let res = check_orders(&cells, LIMIT_ORDER_HASH);
+    assert!(matches!(res, Err(Error::InvalidExpiry)));
+    for (order, master) in [
+        (expiring_order(&mint, EXPIRY, 0), master()),
+        (expiring_order(&mint, EXPIRY, 0), expiring_master(999, 0)),
+        (
+            order(&mint, &INFO, 2_000 * CKB, 7),
+            expiring_master(1_000, 0),
+        ),
+        (
+            order(&mint, &INFO, 2_000 * CKB, 7),
+            cell(USER_LOCK_HASH, Some(LIMIT_ORDER_HASH), 0, &[0]),
+        ),
+    ] {
+        let cells = MemoryCellSource {
+            inputs: vec![],
+            outputs: vec![order, master],
+            cell_deps: vec![],
+        };
+        let res = check_orders(&cells, LIMIT_ORDER_HASH);
+        assert!(matches!(res, Err(Error::InvalidMasterData)));
+    }
+
+    // Matches cannot drop the expiry
+    let matched = OrderAction::Match {
+        master: OutPoint::new_builder()
+            .tx_hash(TX_HASH.pack())
+            .index(1u32.pack())
+            .build(),
+    };
+    let inputs = with_out_points(vec![expiring_order(&mint, EXPIRY, 0)]);
+    let udt_amount = 1_000 * u128::from(CKB) + 7;
+    let cells = MemoryCellSource {
+        inputs: inputs.clone(),
+        outputs: vec![order(&matched, &INFO, 1_000 * CKB, udt_amount)],
+        cell_deps: vec![],
+    };
+    let res = check_orders(&cells, LIMIT_ORDER_HASH);
+    assert!(matches!(res, Err(Error::DifferentInfo)));
+
+    // Anyone can melt an expired order, as long as CKB and UDT go back to the master lock
+    let refund = || {
+        let udt_data = 7u128.to_le_bytes();
+        let mut refund = cell(USER_LOCK_HASH, Some(UDT_HASH), 0, &udt_data);
+        refund.capacity = inputs[0].capacity;
+        refund
+    };
+    for (since, outputs, ok) in [
+        (1_000, vec![refund()], true),
+        (999, vec![refund()], false),
+        (epoch, vec![refund()], false),
+        (0x40ff_ffff_ffff_ffff, vec![refund()], false),
+        (1_000, vec![], false),
+        (
+            1_000,
+            vec![MemoryCell {
+                data: 6u128.to_le_bytes().to_vec(),
+                ..refund()
+            }],
+            false,
+        ),
+        (
+            1_000,
+            vec![MemoryCell {
+                capacity: OCCUPIED,
+                ..refund()
+            }],
+            false,
+        ),
+    ] {
+        let cells = MemoryCellSource {
+            inputs: with_out_points(vec![expiring_order(&mint, EXPIRY, since)]),
+            outputs,
+            cell_deps: vec![],
+        };
+        let res = check_orders(&cells, LIMIT_ORDER_HASH);
+        match (since, ok) {
+            (_, true) => assert!(res.is_ok()),
+            (1_000, false) => assert!(matches!(res, Err(Error::InsufficientRefund))),
+            _ => assert!(matches!(res, Err(Error::NotExpired))),
+        }
+    }
+
+    // Orders without expiry still need their master
+    let cells = MemoryCellSource {
+        inputs: with_out_points(vec![MemoryCell {
+            since: 1_000,
+            ..order(&mint, &INFO, 2_000 * CKB, 7)
+        }]),
+        outputs: vec![refund()],
+        cell_deps: vec![],
+    };
+    let res = check_orders(&cells, LIMIT_ORDER_HASH);
+    assert!(matches!(res, Err(Error::InvalidConfiguration)));
+
+    // The master left behind can be burned alone by its owner, but only past the expiry it carries
+    for (master, ok) in [
+        (expiring_master(1_000, 1_000), true),
+        (expiring_master(1_000, 999), false),
+        (expiring_master(1_000, epoch), false),
+    ] {
+        let cells = MemoryCellSource {
+            inputs: with_out_points(vec![cell(USER_LOCK_HASH, None, 0, &[]), master]),
+            outputs: vec![],
+            cell_deps: vec![],
+        };
+        let res = check_orders(&cells, LIMIT_ORDER_HASH);
+        if ok {
+            assert!(res.is_ok());
+        } else {
+            assert!(matches!(res, Err(Error::NotExpired)));
+        }
+    }
+
+    // Masters of orders without expiry cannot be burned alone
+    let cells = MemoryCellSource {
+        inputs: with_out_points(vec![MemoryCell {
+            since: 1_000,
+            ..master()
+        }]),
+        outputs: vec![],
+        cell_deps: vec![],
+    };
+    let res = check_orders(&cells, LIMIT_ORDER_HASH);
+    assert!(matches!(res, Err(Error::InvalidConfiguration)));
+}
+
+#[test]
+fn test_limit_order_matcher_fee() {
+    use ::limit_order::{check_orders, error::Error, extract_order};
+    let mint = OrderAction::Mint { master_distance: 1 };
+    let matched = OrderAction::Match {
+        master: OutPoint::new_builder()
+            .tx_hash(TX_HASH.pack())
+            .index(1u32.pack())
+            .build(),
+    };
+
+    // Up to 0.1% of the value leaving the order can go to the matcher
+    let info = OrderInfo {
+        max_matcher_fee: Some(1_000),
+        ..INFO
+    };
+    let inputs = with_out_points(vec![order(&mint, &info, 2_000 * CKB, 7)]);
+    for (udt_given, ok) in [
+        (1_000 * CKB, true),
+        (999 * CKB, true),
+        (999 * CKB - 1, false),
+    ] {
+        let udt_amount = 7 + u128::from(udt_given);
+        let cells = MemoryCellSource {
+            inputs: inputs.clone(),
+            outputs: vec![order(&matched, &info, 1_000 * CKB, udt_amount)],
+            cell_deps: vec![],
+        };
+        let res = check_orders(&cells, LIMIT_ORDER_HASH);
+        if ok {
+            assert!(res.is_ok());
+        } else {
+            assert!(matches!(res, Err(Error::DecreasingValue)));
+        }
+    }
+
+    // Without the term the same match loses value
+    let cells = MemoryCellSource {
+        inputs: with_out_points(vec![order(&mint, &INFO, 2_000 * CKB, 7)]),
+        outputs: vec![order(
+            &matched,
+            &INFO,
+            1_000 * CKB,
+            7 + u128::from(999 * CKB),
+        )],
+        cell_deps: vec![],
+    };
+    let res = check_orders(&cells, LIMIT_ORDER_HASH);
+    assert!(matches!(res, Err(Error::DecreasingValue)));
+
+    // The fee must be lower than the whole value
+    let cells = MemoryCellSource {
+        inputs: vec![],
+        outputs: [999_999, MATCHER_FEE_DENOMINATOR]
+            .map(|fee| {
+                let info = OrderInfo {
+                    max_matcher_fee: Some(fee),
+                    ..INFO
+                };
+                order(&mint, &info, 0, 0)
+            })
+            .to_vec(),
+        cell_deps: vec![],
+    };
+    let Ok((_, data)) = extract_order(&cells, 0, Source::Output) else {
+        panic!("valid order");
+    };
+    assert!(data.info.max_matcher_fee == utils::C256::from(999_999u64));
+    let res = extract_order(&cells, 1, Source::Output);
+    assert!(matches!(res, Err(Error::InvalidMatcherFee)));
+}
@@ -0,0 +1,493 @@
+use super::*;
+use crate::fixtures::{deploy_ickb, Ickb, Tx, CKB, GENESIS_ACCUMULATED_RATE};
+use ::ickb_rate::DepositLimits;
+use ckb_testtool::ckb_types::core::ScriptHashType;
+
+// ickb_logic error numbers, see contracts/ickb_logic/src/error.rs
+// IndexOutOfBound and LengthNotEnough are never returned: extractors treat longer data as valid
+const ERROR_ITEM_MISSING: i8 = 2;
+const ERROR_ENCODING: i8 = 4;
+const ERROR_DEPOSIT_TOO_SMALL: i8 = 7;
+const ERROR_DEPOSIT_TOO_BIG: i8 = 8;
+const ERROR_EMPTY_RECEIPT: i8 = 9;
+const ERROR_RECEIPT_MISMATCH: i8 = 10;
+const ERROR_AMOUNT_MISMATCH: i8 = 11;
+const ERROR_AMOUNT_UNREASONABLY_BIG: i8 = 12;
+const ERROR_PARAMS_MISSING: i8 = 13;
+const ERROR_PARAMS_INVALID: i8 = 14;
+const ERROR_PARAMS_MUTABLE: i8 = 15;
+const ERROR_INVALID_ACCUMULATED_RATE: i8 = 16;
+
+// Accumulated rate 25% higher than genesis, so each CKB is worth 0.8 iCKB
+const ACCUMULATED_RATE: u64 = GENESIS_ACCUMULATED_RATE / 4 * 5;
+
+// Phase 1
+
+#[test]
+fn test_deposit_phase_1() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB;
+    let ar = GENESIS_ACCUMULATED_RATE;
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.deposit_cell(amount, ar))
+        .output(ickb.deposit_cell(amount, ar))
+        .output(ickb.deposit_cell(2 * amount, ar))
+        .output(ickb.receipt_cell(2, amount))
+        .output(ickb.receipt_cell(1, 2 * amount));
+    let cycles = ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+    println!("consume cycles: {cycles}");
+}
+
+#[test]
+fn test_deposit_phase_1_split_receipts() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB;
+    let ar = GENESIS_ACCUMULATED_RATE;
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.deposit_cell(amount, ar))
+        .output(ickb.deposit_cell(amount, ar))
+        .output(ickb.deposit_cell(amount, ar))
+        .output(ickb.receipt_cell(1, amount))
+        .output(ickb.receipt_cell(2, amount));
+    ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_deposit_bounds() {
+    let mut ickb = deploy_ickb();
+    let ar = GENESIS_ACCUMULATED_RATE;
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(2_000_000 * CKB))
+        .output(ickb.deposit_cell(1_000 * CKB, ar))
+        .output(ickb.deposit_cell(1_000_000 * CKB, ar))
+        .output(ickb.receipt_cell(1, 1_000 * CKB))
+        .output(ickb.receipt_cell(1, 1_000_000 * CKB));
+    ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_deposit_too_small() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB - 1;
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.deposit_cell(amount, GENESIS_ACCUMULATED_RATE))
+        .output(ickb.receipt_cell(1, amount));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_DEPOSIT_TOO_SMALL);
+}
+
+#[test]
+fn test_deposit_too_big() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000_000 * CKB + 1;
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(2_000_000 * CKB))
+        .output(ickb.deposit_cell(amount, GENESIS_ACCUMULATED_RATE))
+        .output(ickb.receipt_cell(1, amount));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_DEPOSIT_TOO_BIG);
+}
+
+#[test]
+fn test_empty_receipt() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB;
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.deposit_cell(amount, GENESIS_ACCUMULATED_RATE))
+        .output(ickb.receipt_cell(1, amount))
+        .output(ickb.receipt_cell(0, amount));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_EMPTY_RECEIPT);
+}
+
+#[test]
+fn test_receipt_short_data() {
+    let mut ickb = deploy_ickb();
+    let receipt = ickb
+        .receipt_cell(1, 0)
+        .with_data(Bytes::from(vec![1u8, 0, 0, 0]));
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(receipt);
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_ENCODING);
+}
+
+#[test]
+fn test_receipt_quantity_mismatch() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB;
+    let ar = GENESIS_ACCUMULATED_RATE;
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.deposit_cell(amount, ar))
+        .output(ickb.deposit_cell(amount, ar))
+        .output(ickb.receipt_cell(1, amount));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_RECEIPT_MISMATCH);
+}
+
+#[test]
+fn test_receipt_amount_mismatch() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB;
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.deposit_cell(amount, GENESIS_ACCUMULATED_RATE))
+        .output(ickb.receipt_cell(1, amount + 1));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_RECEIPT_MISMATCH);
+}
+
+#[test]
+fn test_receipt_without_deposit() {
+    let mut ickb = deploy_ickb();
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.receipt_cell(1, 1_000 * CKB));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_RECEIPT_MISMATCH);
+}
+
+#[test]
+fn test_output_lock_misuse() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB;
+    let misused = ickb.capacity_cell(amount).with_lock(&ickb.ickb_logic);
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.deposit_cell(amount, GENESIS_ACCUMULATED_RATE))
+        .output(ickb.receipt_cell(1, amount))
+        .output(misused);
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_SCRIPT_MISUSE);
+}
+
+#[test]
+fn test_output_udt_lock_misuse() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB;
+    let misused = ickb.capacity_cell(amount).with_lock(&ickb.ickb_udt);
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.deposit_cell(amount, GENESIS_ACCUMULATED_RATE))
+        .output(ickb.receipt_cell(1, amount))
+        .output(misused);
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_SCRIPT_MISUSE);
+}
+
+// Phase 2
+
+#[test]
+fn test_deposit_phase_2() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB;
+    let receipt = ickb
+        .receipt_cell(3, amount)
+        .in_block(ickb.header(GENESIS_ACCUMULATED_RATE));
+
+    let tx = Tx::default()
+        .input_cell(receipt)
+        .output(ickb.udt_cell(3 * u128::from(amount)));
+    let cycles = ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+    println!("consume cycles: {cycles}");
+}
+
+#[test]
+fn test_deposit_phase_2_accumulated_rate() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB;
+    let receipt = ickb
+        .receipt_cell(2, amount)
+        .in_block(ickb.header(ACCUMULATED_RATE));
+
+    let tx = Tx::default()
+        .input_cell(receipt)
+        .output(ickb.udt_cell(u128::from(amount) * 8 / 10))
+        .output(ickb.udt_cell(u128::from(amount) * 8 / 10));
+    ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_deposit_phase_2_soft_cap() {
+    let mut ickb = deploy_ickb();
+    let receipt = ickb
+        .receipt_cell(1, 200_000 * CKB)
+        .in_block(ickb.header(GENESIS_ACCUMULATED_RATE));
+
+    // 10% discount on the 100_000 iCKB exceeding the soft cap
+    let tx = Tx::default()
+        .input_cell(receipt)
+        .output(ickb.udt_cell(u128::from(190_000 * CKB)));
+    ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_deposit_phase_2_amount_mismatch() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB;
+    let receipt = ickb
+        .receipt_cell(1, amount)
+        .in_block(ickb.header(GENESIS_ACCUMULATED_RATE));
+
+    let tx = Tx::default()
+        .input_cell(receipt)
+        .output(ickb.udt_cell(u128::from(amount) + 1));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_AMOUNT_MISMATCH);
+}
+
+#[test]
+fn test_deposit_phase_2_soft_cap_mismatch() {
+    let mut ickb = deploy_ickb();
+    let amount = 200_000 * CKB;
+    let receipt = ickb
+        .receipt_cell(1, amount)
+        .in_block(ickb.header(GENESIS_ACCUMULATED_RATE));
+
+    let tx = Tx::default()
+        .input_cell(receipt)
+        .output(ickb.udt_cell(u128::from(amount)));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_AMOUNT_MISMATCH);
+}
+
+#[test]
+fn test_deposit_phase_2_missing_header() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB;
+    let receipt = ickb
+        .receipt_cell(1, amount)
+        .in_block(ickb.header(GENESIS_ACCUMULATED_RATE));
+
+    let tx = Tx::default()
+        .input_cell(receipt)
+        .output(ickb.udt_cell(u128::from(amount)))
+        .without_header_deps();
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_ITEM_MISSING);
+}
+
+#[test]
+fn test_deposit_phase_2_zero_accumulated_rate() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB;
+    // No valid header has a zero accumulated rate, so the receipt has no iCKB value
+    let receipt = ickb.receipt_cell(1, amount).in_block(ickb.header(0));
+
+    let tx = Tx::default()
+        .input_cell(receipt)
+        .output(ickb.udt_cell(u128::from(amount)));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_INVALID_ACCUMULATED_RATE);
+}
+
+#[test]
+fn test_amount_unreasonably_big() {
+    let mut ickb = deploy_ickb();
+    let receipt = ickb
+        .receipt_cell(1, 1_000 * CKB)
+        .in_block(ickb.header(GENESIS_ACCUMULATED_RATE));
+
+    let tx = Tx::default()
+        .input_cell(receipt)
+        .output(ickb.udt_cell(u128::from(u64::MAX) + 1));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_AMOUNT_UNREASONABLY_BIG);
+}
+
+// Withdrawal
+
+#[test]
+fn test_deposit_to_udt_withdrawal() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB;
+
+    let tx = Tx::default()
+        .input_cell(ickb.deposit_cell(amount, ACCUMULATED_RATE))
+        .input_cell(ickb.udt_cell(u128::from(amount)))
+        .output(ickb.withdrawal_request(amount, 1))
+        .output(ickb.owner_cell(-1))
+        .output(ickb.udt_cell(u128::from(amount) * 2 / 10));
+    ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_deposit_to_udt_withdrawal_amount_mismatch() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB;
+
+    let tx = Tx::default()
+        .input_cell(ickb.deposit_cell(amount, GENESIS_ACCUMULATED_RATE))
+        .input_cell(ickb.udt_cell(u128::from(amount) - 1))
+        .output(ickb.withdrawal_request(amount, 1))
+        .output(ickb.owner_cell(-1));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_AMOUNT_MISMATCH);
+}
+
+#[test]
+fn test_receipt_to_deposit_swap() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB;
+    let receipt = ickb
+        .receipt_cell(1, amount)
+        .in_block(ickb.header(GENESIS_ACCUMULATED_RATE));
+
+    // Receipt value covers a deposit withdrawal without ever minting UDT
+    let tx = Tx::default()
+        .input_cell(receipt)
+        .input_cell(ickb.deposit_cell(amount, GENESIS_ACCUMULATED_RATE))
+        .output(ickb.withdrawal_request(amount, 1))
+        .output(ickb.owner_cell(-1));
+    ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+}
+
+// V2 parameters
+
+// Limits far below the v1 ones, so v1 would reject every deposit of these tests
+const V2_LIMITS: DepositLimits = DepositLimits {
+    min_unoccupied_capacity: 100 * CKB,
+    max_unoccupied_capacity: 500 * CKB,
+    soft_cap: 200 * CKB as u128,
+};
+
+// Script { code_hash: [0; 32], hash_type: Data, args: [] }, whose hash is PARAMS_LOCK_HASH
+fn params_lock() -> Script {
+    Script::new_builder()
+        .hash_type(ScriptHashType::Data.into())
+        .build()
+}
+
+fn deploy_v2(limits: &DepositLimits) -> Ickb {
+    let mut ickb = deploy_ickb();
+    ickb.deploy_params(limits, &params_lock());
+    ickb
+}
+
+#[test]
+fn test_v2_deposit_bounds() {
+    let mut ickb = deploy_v2(&V2_LIMITS);
+    let ar = GENESIS_ACCUMULATED_RATE;
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.deposit_cell(100 * CKB, ar))
+        .output(ickb.deposit_cell(500 * CKB, ar))
+        .output(ickb.receipt_cell(1, 100 * CKB))
+        .output(ickb.receipt_cell(1, 500 * CKB));
+    let cycles = ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+    println!("consume cycles: {cycles}");
+
+    for (amount, err_code) in [
+        (100 * CKB - 1, ERROR_DEPOSIT_TOO_SMALL),
+        (500 * CKB + 1, ERROR_DEPOSIT_TOO_BIG),
+    ] {
+        let tx = Tx::default()
+            .input_cell(ickb.capacity_cell(10_000 * CKB))
+            .output(ickb.deposit_cell(amount, ar))
+            .output(ickb.receipt_cell(1, amount));
+        let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+        assert_script_error(err, err_code);
+    }
+}
+
+#[test]
+fn test_v2_deposit_phase_2_soft_cap() {
+    let mut ickb = deploy_v2(&V2_LIMITS);
+    let receipt = ickb
+        .receipt_cell(1, 300 * CKB)
+        .in_block(ickb.header(GENESIS_ACCUMULATED_RATE));
+
+    // 10% discount on the 100 iCKB exceeding the v2 soft cap
+    let tx = Tx::default()
+        .input_cell(receipt)
+        .output(ickb.udt_cell(u128::from(290 * CKB)));
+    ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_v2_params_missing() {
+    let mut ickb = deploy_v2(&V2_LIMITS);
+    ickb.extra_cell_deps.clear();
+    let amount = 100 * CKB;
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.deposit_cell(amount, GENESIS_ACCUMULATED_RATE))
+        .output(ickb.receipt_cell(1, amount));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_PARAMS_MISSING);
+}
+
+#[test]
+fn test_v2_params_invalid() {
+    let limits = DepositLimits {
+        min_unoccupied_capacity: 600 * CKB,
+        ..V2_LIMITS
+    };
+    let mut ickb = deploy_v2(&limits);
+    let amount = 600 * CKB;
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.deposit_cell(amount, GENESIS_ACCUMULATED_RATE))
+        .output(ickb.receipt_cell(1, amount));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_PARAMS_INVALID);
+}
+
+#[test]
+fn test_v2_params_mutable() {
+    let mut ickb = deploy_ickb();
+    let lock = ickb.always_success.clone();
+    ickb.deploy_params(&V2_LIMITS, &lock);
+    let amount = 100 * CKB;
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.deposit_cell(amount, GENESIS_ACCUMULATED_RATE))
+        .output(ickb.receipt_cell(1, amount));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_PARAMS_MUTABLE);
+}
+
+#[test]
+fn test_v2_params_cell_unspendable() {
+    let mut ickb = deploy_v2(&V2_LIMITS);
+    let params = ickb.extra_cell_deps[0].out_point();
+
+    // Empty data hashes to zero, so an empty cell dep matches the params lock code hash,
+    // but it holds no code to execute
+    assert_eq!(CellOutput::calc_data_hash(&[]), Byte32::zero());
+    let empty = ickb.create(ickb.capacity_cell(0));
+    let tx = TransactionBuilder::default()
+        .input(CellInput::new_builder().previous_output(params).build())
+        .output(ickb.capacity_cell(0).output)
+        .output_data(Bytes::new().pack())
+        .cell_dep(CellDep::new_builder().out_point(empty).build())
+        .build();
+    let err = ickb.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    let err = err.to_string();
+    assert!(
+        err.contains("Inputs[0].Lock") && err.contains("ElfParseError"),
+        "{err}"
+    );
+}
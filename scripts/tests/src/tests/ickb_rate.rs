@@ -0,0 +1,131 @@
+use super::*;
+use crate::fixtures::{deploy_ickb, Tx, CKB, GENESIS_ACCUMULATED_RATE};
+use ::ickb_rate::{
+    ckb_for_ickb, ickb_for_deposit, ickb_for_receipt, max_deposit_under_soft_cap, DepositLimits,
+    CKB_MAXIMUM_UNOCCUPIED_CAPACITY_PER_DEPOSIT, ICKB_SOFT_CAP_PER_DEPOSIT,
+};
+
+// error numbers, see contracts/ickb_logic/src/error.rs
+const ERROR_AMOUNT_MISMATCH: i8 = 11;
+
+// A rate that doesn't divide evenly, so rounding matters
+const ACCUMULATED_RATE: u64 = GENESIS_ACCUMULATED_RATE / 7 * 9 + 1;
+
+#[test]
+fn test_ickb_rate_quotes() {
+    assert_eq!(
+        ickb_for_deposit(1_000 * CKB, GENESIS_ACCUMULATED_RATE),
+        Some(1_000 * 100_000_000)
+    );
+    assert_eq!(
+        ickb_for_deposit(200_000 * CKB, GENESIS_ACCUMULATED_RATE),
+        Some(190_000 * 100_000_000)
+    );
+    assert_eq!(
+        ickb_for_receipt(3, 1_000 * CKB, GENESIS_ACCUMULATED_RATE),
+        ickb_for_deposit(1_000 * CKB, GENESIS_ACCUMULATED_RATE).map(|ickb| 3 * ickb)
+    );
+
+    // Converting back loses at most the rounding of both conversions
+    let amount = u128::from(1_000 * CKB);
+    let ckb = ckb_for_ickb(
+        ickb_for_deposit(1_000 * CKB, ACCUMULATED_RATE).unwrap(),
+        ACCUMULATED_RATE,
+    )
+    .unwrap();
+    assert!(amount - 2 <= ckb && ckb <= amount);
+    assert_eq!(ckb_for_ickb(u128::MAX, ACCUMULATED_RATE), None);
+
+    // A zero accumulated rate, which no valid header has, has no quote
+    assert_eq!(ickb_for_deposit(1_000 * CKB, 0), None);
+    assert_eq!(ickb_for_receipt(3, 1_000 * CKB, 0), None);
+    assert_eq!(ickb_for_receipt(u32::MAX, 1_000_000 * CKB, 1), None);
+    assert_eq!(max_deposit_under_soft_cap(0), 0);
+}
+
+#[test]
+fn test_ickb_rate_max_deposit_under_soft_cap() {
+    for accumulated_rate in [GENESIS_ACCUMULATED_RATE, ACCUMULATED_RATE] {
+        let max = max_deposit_under_soft_cap(accumulated_rate);
+        assert!(ickb_for_deposit(max, accumulated_rate) <= Some(ICKB_SOFT_CAP_PER_DEPOSIT));
+        // One more shannon would exceed the soft cap
+        let genesis = u128::from(GENESIS_ACCUMULATED_RATE);
+        let over = u128::from(max + 1) * genesis / u128::from(accumulated_rate);
+        assert!(over > ICKB_SOFT_CAP_PER_DEPOSIT);
+    }
+
+    // Capped by the maximum deposit size
+    assert_eq!(
+        max_deposit_under_soft_cap(u64::MAX),
+        CKB_MAXIMUM_UNOCCUPIED_CAPACITY_PER_DEPOSIT
+    );
+}
+
+#[test]
+fn test_ickb_rate_deposit_limits() {
+    let limits = DepositLimits {
+        min_unoccupied_capacity: 10 * CKB,
+        max_unoccupied_capacity: 5_000 * CKB,
+        soft_cap: u128::from(100 * CKB),
+    };
+    assert!(limits.is_valid() && DepositLimits::V1.is_valid());
+    assert_eq!(DepositLimits::default(), DepositLimits::V1);
+
+    let max = limits.max_deposit_under_soft_cap(ACCUMULATED_RATE);
+    assert!(limits.ickb_for_deposit(max, ACCUMULATED_RATE) <= Some(limits.soft_cap));
+    assert!(limits.ickb_for_deposit(max + 1, ACCUMULATED_RATE) > Some(limits.soft_cap));
+    assert_eq!(
+        limits.ickb_for_deposit(200 * CKB, GENESIS_ACCUMULATED_RATE),
+        Some(u128::from(190 * CKB))
+    );
+
+    // Never overflows, even with an unreasonable soft cap
+    let uncapped = DepositLimits {
+        soft_cap: u128::MAX,
+        ..limits
+    };
+    assert_eq!(
+        uncapped.max_deposit_under_soft_cap(u64::MAX),
+        limits.max_unoccupied_capacity
+    );
+
+    for invalid in [
+        DepositLimits {
+            min_unoccupied_capacity: 0,
+            ..limits
+        },
+        DepositLimits {
+            max_unoccupied_capacity: CKB,
+            ..limits
+        },
+        DepositLimits {
+            soft_cap: 0,
+            ..limits
+        },
+    ] {
+        assert!(!invalid.is_valid());
+    }
+}
+
+// The quote is exactly what ickb_logic accepts
+#[test]
+fn test_ickb_rate_matches_ickb_logic() {
+    for amount in [1_000 * CKB, 150_000 * CKB + 7, 1_000_000 * CKB] {
+        let quote = ickb_for_receipt(2, amount, ACCUMULATED_RATE).unwrap();
+
+        let mut ickb = deploy_ickb();
+        let receipt = ickb
+            .receipt_cell(2, amount)
+            .in_block(ickb.header(ACCUMULATED_RATE));
+        let tx = Tx::default()
+            .input_cell(receipt.clone())
+            .output(ickb.udt_cell(quote));
+        ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+
+        let tx = Tx::default()
+            .input_cell(receipt)
+            .output(ickb.udt_cell(quote + 1));
+        let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+        assert_script_error(err, ERROR_AMOUNT_MISMATCH);
+    }
+}
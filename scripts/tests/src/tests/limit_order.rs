@@ -0,0 +1,516 @@
+use super::*;
+use crate::fixtures::{deploy_ickb, Cell, Ickb, OrderAction, OrderInfo, Ratio, Tx, CKB};
+use ickb_sdk::order::{encode_order_data, OrderExpiry, UdtLayout};
+
+// limit_order error numbers, see contracts/limit_order/src/error.rs
+const ERROR_INVALID_CKB_MIN_MATCH_LOG: i8 = 10;
+const ERROR_CONCAVE_RATIO: i8 = 11;
+const ERROR_BOTH_RATIO_NULL: i8 = 12;
+const ERROR_DIFFERENT_INFO: i8 = 16;
+const ERROR_DECREASING_VALUE: i8 = 18;
+const ERROR_INSUFFICIENT_MATCH: i8 = 20;
+const ERROR_INVALID_CONFIGURATION: i8 = 21;
+const ERROR_INVALID_EXPIRY: i8 = 24;
+const ERROR_NOT_EXPIRED: i8 = 25;
+const ERROR_INSUFFICIENT_REFUND: i8 = 26;
+const ERROR_INVALID_MATCHER_FEE: i8 = 27;
+const ERROR_INVALID_MASTER_DATA: i8 = 28;
+
+// xUDT ERROR_AMOUNT, outputs hold more UDT than inputs
+const ERROR_XUDT_AMOUNT: i8 = -52;
+
+// Sell CKB for UDT at 1:1, ckb_min_match is 2^33 shannons, so about 86 CKB
+const INFO: OrderInfo = OrderInfo {
+    ckb_to_udt: Ratio {
+        ckb_multiplier: 1,
+        udt_multiplier: 1,
+    },
+    udt_to_ckb: Ratio {
+        ckb_multiplier: 0,
+        udt_multiplier: 0,
+    },
+    ckb_min_match_log: 33,
+    expiry: None,
+    max_matcher_fee: None,
+};
+
+const MINT: OrderAction = OrderAction::Mint { master_distance: 1 };
+
+// Absolute block number since of the expiring orders
+const EXPIRY: u64 = 1_000;
+
+// Epoch since flags, a different metric than EXPIRY
+const EPOCH_SINCE_FLAGS: u64 = 0x20 << 56;
+
+// Create a minted order with its master, returns order and master out points
+fn mint(ickb: &mut Ickb, unoccupied: u64) -> (OutPoint, OutPoint) {
+    let master = ickb.master_cell();
+    mint_info(ickb, &INFO, master, unoccupied)
+}
+
+fn mint_info(
+    ickb: &mut Ickb,
+    info: &OrderInfo,
+    master: Cell,
+    unoccupied: u64,
+) -> (OutPoint, OutPoint) {
+    let order = ickb.limit_order_cell(info, &MINT, unoccupied, 0);
+    let out_points = ickb.create_outputs(vec![order, master]);
+    (out_points[0].clone(), out_points[1].clone())
+}
+
+// INFO expiring at EXPIRY, refunds go to the always success lock of the master
+fn expiring_info(ickb: &Ickb) -> OrderInfo {
+    OrderInfo {
+        expiry: Some(OrderExpiry {
+            since: EXPIRY,
+            master_lock_hash: ickb.always_success.calc_script_hash().unpack(),
+        }),
+        ..INFO
+    }
+}
+
+fn mint_expiring(ickb: &mut Ickb, unoccupied: u64) -> (OutPoint, OutPoint) {
+    let info = expiring_info(ickb);
+    let master = ickb.expiring_master_cell(EXPIRY);
+    mint_info(ickb, &info, master, unoccupied)
+}
+
+// Cell locked by the master lock holding exactly capacity shannons
+fn refund_cell(ickb: &Ickb, capacity: u64) -> Cell {
+    let cell = ickb.capacity_cell(0);
+    Cell {
+        output: cell.output.as_builder().capacity(capacity.pack()).build(),
+        ..cell
+    }
+}
+
+fn capacity(ickb: &Ickb, out_point: &OutPoint) -> u64 {
+    let (output, _) = ickb.context.get_cell(out_point).expect("live cell");
+    output.capacity().unpack()
+}
+
+#[test]
+fn test_limit_order_mint() {
+    let mut ickb = deploy_ickb();
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.limit_order_cell(&INFO, &MINT, 2_000 * CKB, 0))
+        .output(ickb.master_cell());
+    ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_limit_order_mint_without_master() {
+    let mut ickb = deploy_ickb();
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.limit_order_cell(&INFO, &MINT, 2_000 * CKB, 0))
+        .output(ickb.capacity_cell(0))
+        .output(ickb.master_cell());
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_INVALID_CONFIGURATION);
+}
+
+#[test]
+fn test_limit_order_invalid_info() {
+    let cases = [
+        (OrderInfo::default(), ERROR_BOTH_RATIO_NULL),
+        (
+            OrderInfo {
+                ckb_min_match_log: 65,
+                ..INFO
+            },
+            ERROR_INVALID_CKB_MIN_MATCH_LOG,
+        ),
+        (
+            OrderInfo {
+                udt_to_ckb: Ratio {
+                    ckb_multiplier: 2,
+                    udt_multiplier: 1,
+                },
+                ..INFO
+            },
+            ERROR_CONCAVE_RATIO,
+        ),
+    ];
+
+    for (info, err_code) in cases {
+        let mut ickb = deploy_ickb();
+        let tx = Tx::default()
+            .input_cell(ickb.capacity_cell(10_000 * CKB))
+            .output(ickb.limit_order_cell(&info, &MINT, 2_000 * CKB, 0))
+            .output(ickb.master_cell());
+        let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+        assert_script_error(err, err_code);
+    }
+}
+
+#[test]
+fn test_limit_order_match() {
+    let mut ickb = deploy_ickb();
+    let (order, master) = mint(&mut ickb, 2_000 * CKB);
+    let matched = u128::from(1_000 * CKB);
+    let action = OrderAction::Match { master };
+
+    let tx = Tx::default()
+        .input(&order)
+        .input_cell(ickb.udt_cell(matched))
+        .output(ickb.limit_order_cell(&INFO, &action, 1_000 * CKB, matched))
+        .output(ickb.capacity_cell(1_000 * CKB));
+    let cycles = ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+    println!("consume cycles: {cycles}");
+}
+
+#[test]
+fn test_limit_order_match_decreasing_value() {
+    let mut ickb = deploy_ickb();
+    let (order, master) = mint(&mut ickb, 2_000 * CKB);
+    let matched = u128::from(1_000 * CKB) - 1;
+    let action = OrderAction::Match { master };
+
+    let tx = Tx::default()
+        .input(&order)
+        .input_cell(ickb.udt_cell(matched))
+        .output(ickb.limit_order_cell(&INFO, &action, 1_000 * CKB, matched))
+        .output(ickb.capacity_cell(1_000 * CKB));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_DECREASING_VALUE);
+}
+
+#[test]
+fn test_limit_order_match_insufficient() {
+    let mut ickb = deploy_ickb();
+    let (order, master) = mint(&mut ickb, 2_000 * CKB);
+    let matched = u128::from(10 * CKB);
+    let action = OrderAction::Match { master };
+
+    let tx = Tx::default()
+        .input(&order)
+        .input_cell(ickb.udt_cell(matched))
+        .output(ickb.limit_order_cell(&INFO, &action, 1_990 * CKB, matched))
+        .output(ickb.capacity_cell(10 * CKB));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_INSUFFICIENT_MATCH);
+}
+
+#[test]
+fn test_limit_order_match_different_info() {
+    let mut ickb = deploy_ickb();
+    let (order, master) = mint(&mut ickb, 2_000 * CKB);
+    let matched = u128::from(1_000 * CKB);
+    let action = OrderAction::Match { master };
+    let info = OrderInfo {
+        ckb_min_match_log: 0,
+        ..INFO
+    };
+
+    let tx = Tx::default()
+        .input(&order)
+        .input_cell(ickb.udt_cell(matched))
+        .output(ickb.limit_order_cell(&info, &action, 1_000 * CKB, matched))
+        .output(ickb.capacity_cell(1_000 * CKB));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_DIFFERENT_INFO);
+}
+
+#[test]
+fn test_limit_order_melt() {
+    let mut ickb = deploy_ickb();
+    let (order, master) = mint(&mut ickb, 2_000 * CKB);
+
+    let tx = Tx::default()
+        .input(&order)
+        .input(&master)
+        .output(ickb.capacity_cell(2_000 * CKB));
+    ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_limit_order_melt_without_master() {
+    let mut ickb = deploy_ickb();
+    let (order, _) = mint(&mut ickb, 2_000 * CKB);
+
+    let tx = Tx::default()
+        .input(&order)
+        .output(ickb.capacity_cell(2_000 * CKB));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_INVALID_CONFIGURATION);
+}
+
+#[test]
+fn test_limit_order_mint_expiring() {
+    let mut ickb = deploy_ickb();
+    let info = expiring_info(&ickb);
+    let order = ickb.limit_order_cell(&info, &MINT, 2_000 * CKB, 0);
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(order.clone())
+        .output(ickb.expiring_master_cell(EXPIRY));
+    let cycles = ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+    println!("consume cycles: {cycles}");
+
+    let other_lock = ickb.owned_owner.clone();
+    let cases = [
+        (ickb.master_cell(), ERROR_INVALID_MASTER_DATA),
+        (
+            ickb.expiring_master_cell(EXPIRY + 1),
+            ERROR_INVALID_MASTER_DATA,
+        ),
+        (
+            ickb.expiring_master_cell(EXPIRY).with_lock(&other_lock),
+            ERROR_INVALID_EXPIRY,
+        ),
+    ];
+    for (master, err_code) in cases {
+        let tx = Tx::default()
+            .input_cell(ickb.capacity_cell(10_000 * CKB))
+            .output(order.clone())
+            .output(master);
+        let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+        assert_script_error(err, err_code);
+    }
+
+    // Masters of orders without expiry carry no data
+    let order = ickb.limit_order_cell(&INFO, &MINT, 2_000 * CKB, 0);
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(order)
+        .output(ickb.expiring_master_cell(EXPIRY));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_INVALID_MASTER_DATA);
+}
+
+#[test]
+fn test_limit_order_melt_expired() {
+    let mut ickb = deploy_ickb();
+    let (order, _) = mint_expiring(&mut ickb, 2_000 * CKB);
+    let refund = capacity(&ickb, &order);
+
+    // Anyone can melt it, as long as the master lock gets the order CKB back
+    let tx = Tx::default()
+        .input_with_since(&order, EXPIRY)
+        .output(refund_cell(&ickb, refund));
+    let cycles = ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+    println!("consume cycles: {cycles}");
+}
+
+// Known gap: past the expiry a match is still valid, no script can bound the commit time from above
+#[test]
+fn test_limit_order_match_expired() {
+    let mut ickb = deploy_ickb();
+    let (order, master) = mint_expiring(&mut ickb, 2_000 * CKB);
+    let info = expiring_info(&ickb);
+    let matched = u128::from(1_000 * CKB);
+    let action = OrderAction::Match { master };
+
+    let tx = Tx::default()
+        .input_with_since(&order, EXPIRY)
+        .input_cell(ickb.udt_cell(matched))
+        .output(ickb.limit_order_cell(&info, &action, 1_000 * CKB, matched))
+        .output(ickb.capacity_cell(1_000 * CKB));
+    ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_limit_order_melt_not_expired() {
+    let mut ickb = deploy_ickb();
+    let (order, _) = mint_expiring(&mut ickb, 2_000 * CKB);
+    let refund = capacity(&ickb, &order);
+
+    for since in [0, EXPIRY - 1, EPOCH_SINCE_FLAGS | EXPIRY] {
+        let tx = Tx::default()
+            .input_with_since(&order, since)
+            .output(refund_cell(&ickb, refund));
+        let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+        assert_script_error(err, ERROR_NOT_EXPIRED);
+    }
+}
+
+#[test]
+fn test_limit_order_melt_insufficient_refund() {
+    let mut ickb = deploy_ickb();
+    let (order, _) = mint_expiring(&mut ickb, 2_000 * CKB);
+    let refund = capacity(&ickb, &order);
+
+    let tx = Tx::default()
+        .input_with_since(&order, EXPIRY)
+        .output(refund_cell(&ickb, refund - 1));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_INSUFFICIENT_REFUND);
+
+    // Refunds must be locked by the master lock
+    let other_lock = ickb.owned_owner.clone();
+    let tx = Tx::default()
+        .input_with_since(&order, EXPIRY)
+        .output(refund_cell(&ickb, refund).with_lock(&other_lock));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_INSUFFICIENT_REFUND);
+}
+
+#[test]
+fn test_limit_order_burn_master() {
+    let mut ickb = deploy_ickb();
+
+    // The master left behind by an expired order melted without it
+    let (_, master) = mint_expiring(&mut ickb, 2_000 * CKB);
+    let tx = Tx::default()
+        .input_with_since(&master, EXPIRY)
+        .output(ickb.capacity_cell(0));
+    ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+
+    let (_, master) = mint_expiring(&mut ickb, 2_000 * CKB);
+    let tx = Tx::default()
+        .input_with_since(&master, EXPIRY - 1)
+        .output(ickb.capacity_cell(0));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_NOT_EXPIRED);
+
+    // Without expiry, burning the master would lock its order forever
+    let (_, master) = mint(&mut ickb, 2_000 * CKB);
+    let tx = Tx::default().input(&master).output(ickb.capacity_cell(0));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_INVALID_CONFIGURATION);
+}
+
+#[test]
+fn test_limit_order_match_fee() {
+    let mut ickb = deploy_ickb();
+    // The matcher keeps up to 0.1% of the value leaving the order
+    let info = OrderInfo {
+        max_matcher_fee: Some(1_000),
+        ..INFO
+    };
+    let taken = 1_000 * CKB;
+    let fee = u128::from(taken / 1_000);
+
+    for (matched, result) in [
+        (u128::from(taken) - fee, Ok(())),
+        (u128::from(taken) - fee - 1, Err(ERROR_DECREASING_VALUE)),
+    ] {
+        let master = ickb.master_cell();
+        let (order, master) = mint_info(&mut ickb, &info, master, 2_000 * CKB);
+        let action = OrderAction::Match { master };
+        let tx = Tx::default()
+            .input(&order)
+            .input_cell(ickb.udt_cell(matched))
+            .output(ickb.limit_order_cell(&info, &action, 1_000 * CKB, matched))
+            .output(ickb.capacity_cell(taken));
+        match result {
+            Ok(()) => {
+                ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+            }
+            Err(err_code) => {
+                let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+                assert_script_error(err, err_code);
+            }
+        }
+    }
+
+    // The fee is a fraction of the taken value
+    let info = OrderInfo {
+        max_matcher_fee: Some(1_000_000),
+        ..INFO
+    };
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.limit_order_cell(&info, &MINT, 2_000 * CKB, 0))
+        .output(ickb.master_cell());
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_INVALID_MATCHER_FEE);
+}
+
+// xUDT with an empty extension script vector in its args, flag 0x1 of RFC 0052, so its cells carry
+// XudtData after the UDT amount
+fn xudt_with_extension(ickb: &Ickb) -> Script {
+    let args = [
+        [7u8; 32].as_slice(),
+        &1u32.to_le_bytes(),
+        // Empty molecule ScriptVec, a dynvec holding only its total size
+        &4u32.to_le_bytes(),
+    ]
+    .concat();
+    ickb.ickb_udt
+        .clone()
+        .as_builder()
+        .args(Bytes::from(args).pack())
+        .build()
+}
+
+fn xudt_data() -> Bytes {
+    let lock = ickb_types::Bytes::new_builder()
+        .set([1u8, 2, 3].into_iter().map(Byte::new).collect())
+        .build();
+    ickb_types::XudtData::new_builder()
+        .lock(lock)
+        .build()
+        .as_bytes()
+}
+
+// UdtData followed by XudtData and PartialOrderData with the UdtLayout trailer
+fn xudt_order_cell(
+    ickb: &Ickb,
+    xudt: &Script,
+    info: &OrderInfo,
+    action: &OrderAction,
+    unoccupied: u64,
+    udt_amount: u128,
+) -> Cell {
+    let layout = UdtLayout::XudtData(xudt_data());
+    let data = encode_order_data(udt_amount, &layout, action, info);
+    Cell::new(&ickb.limit_order, Some(xudt), data, unoccupied)
+}
+
+fn xudt_cell(ickb: &Ickb, xudt: &Script, udt_amount: u128) -> Cell {
+    let data = [udt_amount.to_le_bytes().as_slice(), &xudt_data()].concat();
+    Cell::new(&ickb.always_success, Some(xudt), Bytes::from(data), 0)
+}
+
+#[test]
+fn test_limit_order_xudt_extension_data() {
+    let mut ickb = deploy_ickb();
+    let xudt = xudt_with_extension(&ickb);
+    // Sell UDT for CKB at 1:1
+    let info = OrderInfo {
+        ckb_to_udt: Ratio::default(),
+        udt_to_ckb: INFO.ckb_to_udt,
+        ..INFO
+    };
+    let amount = u128::from(1_000 * CKB);
+
+    // The real xUDT binary accepts the order trailer after its XudtData
+    let order = xudt_order_cell(&ickb, &xudt, &info, &MINT, 0, amount);
+    let out_points = ickb.create_outputs(vec![order.clone(), ickb.master_cell()]);
+    let tx = Tx::default()
+        .input_cell(xudt_cell(&ickb, &xudt, amount))
+        .input_cell(ickb.capacity_cell(1_000 * CKB))
+        .output(order)
+        .output(ickb.master_cell());
+    let cycles = ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+    println!("consume cycles: {cycles}");
+
+    // Partial match, the XudtData of the order is left untouched
+    let action = OrderAction::Match {
+        master: out_points[1].clone(),
+    };
+    let half = amount / 2;
+    let matched = xudt_order_cell(&ickb, &xudt, &info, &action, 500 * CKB, half);
+    let tx = Tx::default()
+        .input(&out_points[0])
+        .input_cell(ickb.capacity_cell(500 * CKB))
+        .output(matched)
+        .output(xudt_cell(&ickb, &xudt, half));
+    let cycles = ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+    println!("consume cycles: {cycles}");
+
+    // xUDT reads the amount of the order cell: more UDT out than in
+    let tx = Tx::default()
+        .input_cell(xudt_cell(&ickb, &xudt, amount))
+        .output(xudt_order_cell(&ickb, &xudt, &info, &MINT, 0, amount + 1))
+        .output(ickb.master_cell());
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_XUDT_AMOUNT);
+}
@@ -0,0 +1,267 @@
+use ickb_types::MATCHER_FEE_DENOMINATOR;
+use limit_order::{error::Error, validate, Data, Info, Ratio, UdtLayout};
+use primitive_types::U256;
+use proptest::prelude::*;
+use std::panic::{catch_unwind, UnwindSafe};
+use utils::C256;
+
+// Host side properties of limit_order::validate, random orders are generated the same way
+// limit_order::entry::extract_order accepts them
+
+#[derive(Clone, Copy, Debug)]
+struct RawRatio {
+    ckb_mul: u64,
+    udt_mul: u64,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct RawInfo {
+    ckb_to_udt: Option<RawRatio>,
+    udt_to_ckb: Option<RawRatio>,
+    ckb_min_match_log: u32,
+    max_matcher_fee: u32,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct RawData {
+    ckb: u64,
+    udt: u128,
+    ckb_unoccupied: u64,
+}
+
+fn ratio() -> impl Strategy<Value = Option<RawRatio>> {
+    prop_oneof![
+        Just(None),
+        (1..=u64::MAX, 1..=u64::MAX)
+            .prop_map(|(ckb_mul, udt_mul)| Some(RawRatio { ckb_mul, udt_mul })),
+        // Small multipliers, so ratios are often close to each other
+        (1..=10u64, 1..=10u64).prop_map(|(ckb_mul, udt_mul)| Some(RawRatio { ckb_mul, udt_mul })),
+    ]
+}
+
+// Fees below MATCHER_FEE_DENOMINATOR, as extract_order accepts them
+fn matcher_fee() -> impl Strategy<Value = u32> {
+    prop_oneof![
+        Just(0),
+        1..MATCHER_FEE_DENOMINATOR,
+        Just(MATCHER_FEE_DENOMINATOR - 1),
+    ]
+}
+
+// Same ratio checks as extract_order
+fn info() -> impl Strategy<Value = RawInfo> {
+    (ratio(), ratio(), 0..=64u32, matcher_fee())
+        .prop_map(
+            |(ckb_to_udt, udt_to_ckb, ckb_min_match_log, max_matcher_fee)| RawInfo {
+                ckb_to_udt,
+                udt_to_ckb,
+                ckb_min_match_log,
+                max_matcher_fee,
+            },
+        )
+        .prop_filter("valid info", |info| {
+            match (info.ckb_to_udt, info.udt_to_ckb) {
+                (Some(c2u), Some(u2c)) => {
+                    U256::from(c2u.ckb_mul) * U256::from(u2c.udt_mul)
+                        >= U256::from(c2u.udt_mul) * U256::from(u2c.ckb_mul)
+                }
+                (None, None) => false,
+                _ => true,
+            }
+        })
+}
+
+fn div_ceil(a: U256, b: U256) -> U256 {
+    (a + b - 1) / b
+}
+
+// Part of amount given to the order after the matcher keeps its fee, rounded up
+fn after_fee(amount: U256, fee: u32) -> U256 {
+    let denominator = U256::from(MATCHER_FEE_DENOMINATOR);
+    div_ceil(amount * (denominator - U256::from(fee)), denominator)
+}
+
+// An order and a match attempt close to the limit price after the matcher fee,
+// so both accepted and rejected matches are common
+fn match_case() -> impl Strategy<Value = (RawInfo, RawData, RawData)> {
+    (
+        info(),
+        0..=u64::MAX / 4,
+        prop_oneof![Just(0), 0..=u64::MAX / 4],
+        prop_oneof![Just(0), 0..=u128::from(u64::MAX)],
+        any::<bool>(),
+        any::<u64>(),
+        -2i64..=2,
+    )
+        .prop_map(
+            |(info, occupied, unoccupied, udt, ckb_to_udt, fraction, noise)| {
+                let i = RawData {
+                    ckb: occupied + unoccupied,
+                    udt,
+                    ckb_unoccupied: unoccupied,
+                };
+
+                let fraction = |amount: U256| amount * U256::from(fraction) / U256::from(u64::MAX);
+                let noisy = |amount: U256| {
+                    let amount = amount.as_u128() as i128 + i128::from(noise);
+                    u128::try_from(amount).unwrap_or(0)
+                };
+
+                let o = match (ckb_to_udt, info.ckb_to_udt, info.udt_to_ckb) {
+                    (true, Some(r), _) => {
+                        let ckb_taken = fraction(U256::from(unoccupied));
+                        let udt_given =
+                            div_ceil(ckb_taken * U256::from(r.ckb_mul), U256::from(r.udt_mul));
+                        let udt_given = after_fee(udt_given, info.max_matcher_fee);
+                        let ckb_taken = ckb_taken.as_u64();
+                        RawData {
+                            ckb: i.ckb - ckb_taken,
+                            udt: i.udt.saturating_add(noisy(udt_given)),
+                            ckb_unoccupied: unoccupied - ckb_taken,
+                        }
+                    }
+                    (false, _, Some(r)) => {
+                        let udt_taken = fraction(U256::from(udt));
+                        let ckb_given =
+                            div_ceil(udt_taken * U256::from(r.udt_mul), U256::from(r.ckb_mul));
+                        let ckb_given = after_fee(ckb_given, info.max_matcher_fee);
+                        let ckb_given = noisy(ckb_given).min(u128::from(u64::MAX / 4)) as u64;
+                        RawData {
+                            ckb: i.ckb + ckb_given,
+                            udt: i.udt - udt_taken.as_u128(),
+                            ckb_unoccupied: unoccupied + ckb_given,
+                        }
+                    }
+                    _ => i,
+                };
+
+                (info, i, o)
+            },
+        )
+}
+
+fn to_info(info: RawInfo) -> Info {
+    let ratio = |r: Option<RawRatio>| {
+        r.map(|r| Ratio {
+            ckb_mul: C256::from(r.ckb_mul),
+            udt_mul: C256::from(r.udt_mul),
+        })
+    };
+    Info {
+        udt_hash: [42u8; 32],
+        udt_layout: UdtLayout::Udt,
+        ckb_to_udt: ratio(info.ckb_to_udt),
+        udt_to_ckb: ratio(info.udt_to_ckb),
+        ckb_min_match: C256::from(1u128 << info.ckb_min_match_log),
+        expiry: None,
+        max_matcher_fee: C256::from(u64::from(info.max_matcher_fee)),
+    }
+}
+
+fn to_data(data: RawData, info: RawInfo) -> Data {
+    Data {
+        ckb: C256::from(data.ckb),
+        udt: C256::from(data.udt),
+        ckb_unoccupied: C256::from(data.ckb_unoccupied),
+        info: to_info(info),
+    }
+}
+
+fn value(data: &RawData, r: RawRatio) -> U256 {
+    U256::from(data.ckb) * U256::from(r.ckb_mul) + U256::from(data.udt) * U256::from(r.udt_mul)
+}
+
+fn is_true_overflow<T>(f: impl FnOnce() -> T + UnwindSafe, expected: Option<U256>) -> bool {
+    catch_unwind(f).is_err() == expected.is_none()
+}
+
+proptest! {
+    #[test]
+    fn accepted_match_preserves_value((info, i, o) in match_case()) {
+        if info.max_matcher_fee == 0 && validate(to_data(i, info), to_data(o, info)).is_ok() {
+            // Non concave ratios imply that value is preserved at both ratios
+            for r in [info.ckb_to_udt, info.udt_to_ckb].into_iter().flatten() {
+                prop_assert!(value(&i, r) <= value(&o, r));
+            }
+        }
+    }
+
+    #[test]
+    fn accepted_match_bounds_matcher_fee((info, i, o) in match_case()) {
+        if validate(to_data(i, info), to_data(o, info)).is_ok() {
+            let (r, taken_value) = if i.ckb > o.ckb {
+                let r = info.ckb_to_udt.unwrap();
+                (r, U256::from(i.ckb - o.ckb) * U256::from(r.ckb_mul))
+            } else {
+                let r = info.udt_to_ckb.unwrap();
+                (r, U256::from(i.udt - o.udt) * U256::from(r.udt_mul))
+            };
+            let (i_value, o_value) = (value(&i, r), value(&o, r));
+            if i_value > o_value {
+                prop_assert!(
+                    (i_value - o_value) * U256::from(MATCHER_FEE_DENOMINATOR)
+                        <= taken_value * U256::from(info.max_matcher_fee)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn accepted_match_honors_min_match((info, i, o) in match_case()) {
+        if validate(to_data(i, info), to_data(o, info)).is_ok() {
+            let ckb_min_match = U256::from(1u128 << info.ckb_min_match_log);
+            if i.ckb > o.ckb {
+                prop_assert!(i.ckb_unoccupied > 0);
+                if o.ckb_unoccupied > 0 {
+                    prop_assert!(U256::from(i.ckb - o.ckb) >= ckb_min_match);
+                }
+            } else {
+                prop_assert!(i.udt > o.udt);
+                let r = info.udt_to_ckb.unwrap();
+                if o.udt > 0 {
+                    prop_assert!(
+                        U256::from(i.udt - o.udt) * U256::from(r.udt_mul)
+                            >= ckb_min_match * U256::from(r.ckb_mul)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fulfilled_order_is_never_modified((info, i, o) in match_case()) {
+        let c2u_fulfilled = info.ckb_to_udt.is_none() || i.ckb_unoccupied == 0;
+        let u2c_fulfilled = info.udt_to_ckb.is_none() || i.udt == 0;
+        if c2u_fulfilled && u2c_fulfilled {
+            let result = validate(to_data(i, info), to_data(o, info));
+            prop_assert!(matches!(
+                result,
+                Err(Error::InvalidMatch | Error::AttemptToChangeFulfilled)
+            ));
+        }
+    }
+
+    #[test]
+    fn c256_panics_only_on_overflow(a: u128, b: u128, c: u128) {
+        let (ua, ub, uc) = (U256::from(a), U256::from(b), U256::from(c));
+        let (ca, cb, cc) = (C256::from(a), C256::from(b), C256::from(c));
+
+        let expected = ua.checked_mul(ub).and_then(|x| x.checked_mul(uc));
+        prop_assert!(is_true_overflow(|| ca * cb * cc, expected));
+
+        let expected = ua.checked_mul(ub).and_then(|x| x.checked_add(ua.checked_mul(ub)?));
+        prop_assert!(is_true_overflow(|| ca * cb + ca * cb, expected));
+
+        let expected = ua.checked_sub(ub);
+        prop_assert!(is_true_overflow(|| ca - cb, expected));
+    }
+}
+
+// Overflow is reachable only with products of more than two factors
+#[test]
+fn test_c256_overflow() {
+    let max = C256::from(u128::MAX);
+    assert!(catch_unwind(|| max * max).is_ok());
+    assert!(catch_unwind(|| max * max * C256::from(2u64)).is_err());
+    assert!(catch_unwind(|| C256::from(0u64) - C256::from(1u64)).is_err());
+}
@@ -0,0 +1,123 @@
+use super::*;
+use crate::fixtures::{Ickb, OrderAction, OrderInfo, Ratio, Tx, CKB};
+use ickb_tools::{
+    deployment::find_dir,
+    registry::Deployment,
+    scripts::{network_scripts, ICKB_DEP_GROUP},
+};
+
+const NETWORKS: [&str; 2] = ["mainnet", "testnet"];
+const GENESIS_AR: u64 = crate::fixtures::GENESIS_ACCUMULATED_RATE;
+const AMOUNT: u64 = 1_000 * CKB;
+
+fn deploy_network(name: &str) -> Ickb {
+    let deployment = Deployment::load(&find_dir("deployment"), name).unwrap();
+    Ickb::deploy_network(&deployment, ICKB_DEP_GROUP)
+}
+
+#[test]
+fn test_network_scripts() {
+    for name in NETWORKS {
+        let ickb = deploy_network(name);
+        let deployment = Deployment::load(&find_dir("deployment"), name).unwrap();
+        let scripts = network_scripts(&deployment).unwrap();
+        let expected = ickb.scripts();
+        assert_eq!(expected.dao, scripts.dao, "{name}");
+        assert_eq!(expected.ickb_logic, scripts.ickb_logic, "{name}");
+        assert_eq!(expected.ickb_udt, scripts.ickb_udt, "{name}");
+        assert_eq!(expected.owned_owner, scripts.owned_owner, "{name}");
+        assert_eq!(expected.limit_order, scripts.limit_order, "{name}");
+        assert_eq!(expected.cell_deps, scripts.cell_deps, "{name}");
+    }
+}
+
+#[test]
+fn test_network_deposit_and_withdrawal() {
+    for name in NETWORKS {
+        let mut ickb = deploy_network(name);
+
+        // Transactions only carry the iCKB dep group, every script resolves through it
+        let tx = Tx::default()
+            .input_cell(ickb.capacity_cell(2 * AMOUNT))
+            .output(ickb.deposit_cell(AMOUNT, GENESIS_AR))
+            .output(ickb.receipt_cell(1, AMOUNT));
+        let tx = ickb.build(tx);
+        assert_eq!(
+            tx.cell_deps().into_iter().collect::<Vec<_>>(),
+            ickb.cell_deps
+        );
+        ickb.verify_tx(tx, MAX_CYCLES)
+            .unwrap_or_else(|err| panic!("{name} deposit phase 1: {err}"));
+
+        // Receipt without deposit
+        let tx = Tx::default()
+            .input_cell(ickb.capacity_cell(2 * AMOUNT))
+            .output(ickb.receipt_cell(1, AMOUNT));
+        assert!(ickb.verify(tx, MAX_CYCLES).is_err(), "{name}");
+
+        let header = ickb.header(GENESIS_AR);
+        let tx = Tx::default()
+            .input_cell(ickb.receipt_cell(1, AMOUNT).in_block(header))
+            .output(ickb.udt_cell(u128::from(AMOUNT)));
+        ickb.verify(tx, MAX_CYCLES)
+            .unwrap_or_else(|err| panic!("{name} deposit phase 2: {err}"));
+
+        let tx = Tx::default()
+            .input_cell(ickb.deposit_cell(AMOUNT, GENESIS_AR))
+            .input_cell(ickb.udt_cell(u128::from(AMOUNT)))
+            .output(ickb.withdrawal_request(AMOUNT, 1))
+            .output(ickb.owner_cell(-1));
+        ickb.verify(tx, MAX_CYCLES)
+            .unwrap_or_else(|err| panic!("{name} withdrawal request: {err}"));
+
+        let out_points = ickb.create_outputs(vec![
+            ickb.withdrawal_request(AMOUNT, 1),
+            ickb.owner_cell(-1),
+        ]);
+        let tx = Tx::default()
+            .input(&out_points[0])
+            .input(&out_points[1])
+            .output(ickb.capacity_cell(AMOUNT));
+        ickb.verify(tx, MAX_CYCLES)
+            .unwrap_or_else(|err| panic!("{name} withdrawal: {err}"));
+    }
+}
+
+#[test]
+fn test_network_limit_order() {
+    let info = OrderInfo {
+        ckb_to_udt: Ratio {
+            ckb_multiplier: 1,
+            udt_multiplier: 1,
+        },
+        udt_to_ckb: Ratio {
+            ckb_multiplier: 0,
+            udt_multiplier: 0,
+        },
+        ckb_min_match_log: 33,
+        expiry: None,
+        max_matcher_fee: None,
+    };
+    let mint = OrderAction::Mint { master_distance: 1 };
+    for name in NETWORKS {
+        let mut ickb = deploy_network(name);
+
+        let tx = Tx::default()
+            .input_cell(ickb.capacity_cell(3 * AMOUNT))
+            .output(ickb.limit_order_cell(&info, &mint, 2 * AMOUNT, 0))
+            .output(ickb.master_cell());
+        ickb.verify(tx, MAX_CYCLES)
+            .unwrap_or_else(|err| panic!("{name} mint: {err}"));
+
+        let out_points = ickb.create_outputs(vec![
+            ickb.limit_order_cell(&info, &mint, 2 * AMOUNT, 0),
+            ickb.master_cell(),
+        ]);
+        let tx = Tx::default()
+            .input(&out_points[0])
+            .input(&out_points[1])
+            .output(ickb.capacity_cell(2 * AMOUNT));
+        ickb.verify(tx, MAX_CYCLES)
+            .unwrap_or_else(|err| panic!("{name} melt: {err}"));
+    }
+}
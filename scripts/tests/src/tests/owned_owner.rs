@@ -0,0 +1,121 @@
+use super::*;
+use crate::fixtures::{deploy_ickb, Tx, CKB};
+
+// owned_owner error numbers, see contracts/owned_owner/src/error.rs
+const ERROR_NOT_WITHDRAWAL_REQUEST: i8 = 6;
+const ERROR_SCRIPT_MISUSE: i8 = 7;
+const ERROR_MISMATCH: i8 = 8;
+
+#[test]
+fn test_owned_owner_pairs() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB;
+
+    // Owner cells can point both backward and forward
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.withdrawal_request(amount, 1))
+        .output(ickb.owner_cell(-1))
+        .output(ickb.owner_cell(1))
+        .output(ickb.withdrawal_request(amount, 1));
+    ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_owned_owner_wrong_distance() {
+    let mut ickb = deploy_ickb();
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.withdrawal_request(1_000 * CKB, 1))
+        .output(ickb.owner_cell(1));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_MISMATCH);
+}
+
+#[test]
+fn test_owned_owner_double_owner() {
+    let mut ickb = deploy_ickb();
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(ickb.withdrawal_request(1_000 * CKB, 1))
+        .output(ickb.owner_cell(-1))
+        .output(ickb.owner_cell(-2));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_MISMATCH);
+}
+
+#[test]
+fn test_owned_owner_not_withdrawal_request() {
+    let mut ickb = deploy_ickb();
+    let owned = ickb.capacity_cell(1_000 * CKB).with_lock(&ickb.owned_owner);
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(owned)
+        .output(ickb.owner_cell(-1));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_NOT_WITHDRAWAL_REQUEST);
+}
+
+#[test]
+fn test_owned_owner_script_misuse() {
+    let mut ickb = deploy_ickb();
+    let misused = ickb.owner_cell(0).with_lock(&ickb.owned_owner);
+
+    let tx = Tx::default()
+        .input_cell(ickb.capacity_cell(10_000 * CKB))
+        .output(misused);
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_SCRIPT_MISUSE);
+}
+
+#[test]
+fn test_owned_owner_withdrawal() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB;
+    let out_points = ickb.create_outputs(vec![
+        ickb.withdrawal_request(amount, 1),
+        ickb.owner_cell(-1),
+    ]);
+
+    let tx = Tx::default()
+        .input(&out_points[1])
+        .input(&out_points[0])
+        .output(ickb.capacity_cell(amount));
+    ickb.verify(tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_owned_owner_withdrawal_foreign_owner() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB;
+    let owned = ickb.create_outputs(vec![ickb.withdrawal_request(amount, 1)]);
+    let owner = ickb.create_outputs(vec![ickb.capacity_cell(0), ickb.owner_cell(-1)]);
+
+    let tx = Tx::default()
+        .input(&owned[0])
+        .input(&owner[1])
+        .output(ickb.capacity_cell(amount));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_MISMATCH);
+}
+
+#[test]
+fn test_owned_owner_withdrawal_output_mismatch() {
+    let mut ickb = deploy_ickb();
+    let amount = 1_000 * CKB;
+    let out_points = ickb.create_outputs(vec![
+        ickb.withdrawal_request(amount, 1),
+        ickb.owner_cell(-1),
+    ]);
+
+    // The type group skips validation as the lock group runs, which still checks the outputs
+    let tx = Tx::default()
+        .input(&out_points[0])
+        .input(&out_points[1])
+        .output(ickb.owner_cell(1));
+    let err = ickb.verify(tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_MISMATCH);
+}
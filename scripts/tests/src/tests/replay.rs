@@ -0,0 +1,300 @@
+use super::*;
+use crate::fixtures::{genesis_type_id, Ickb, Tx, CKB};
+use ckb_mock_tx_types::{MockTransaction, ReprMockTransaction};
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_hash::blake2b_256;
+use ckb_testtool::ckb_types::core::{Cycle, ScriptHashType, TransactionView};
+use ickb_tools::{
+    deployment::{find_dir, parse_hash, CellLocation},
+    registry::{embedded_migrations, Deployment},
+    scripts::{ICKB_DEP_GROUP, ICKB_LOGIC},
+};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+use utils::XUDT_CODE_HASH;
+
+// Transaction replay regression tests: recorded transactions, in the ckb-debugger mock format with
+// their resolved inputs, cell deps and header deps, are verified again against the current binaries,
+// failing when a change rejects a previously valid transaction.
+// Fixtures are stored in replay/<network>/, see replay/Readme.md on how to record them.
+// Replay another directory with: REPLAY_FIXTURES_DIR=path cargo test replay -- --include-ignored
+
+const FIXTURES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/replay");
+const NETWORKS: [&str; 2] = ["mainnet", "testnet"];
+
+// CKB mainnet max block cycles
+const MAX_BLOCK_CYCLES: u64 = 3_500_000_000;
+
+// iCKB code cell deployed by a migration
+struct CodeCell {
+    name: String,
+    file: String,
+    data_hash: [u8; 32],
+}
+
+// Packed out points are not hashable keys
+fn key(out_point: &OutPoint) -> ([u8; 32], u32) {
+    (out_point.tx_hash().unpack(), out_point.index().unpack())
+}
+
+// iCKB code cells of every migration of a network, so older generations are replayed too
+fn code_cells(network: &str) -> HashMap<([u8; 32], u32), CodeCell> {
+    let config = Deployment::embedded(network).unwrap().config;
+    let mut cells = HashMap::new();
+    for (_, migration) in embedded_migrations(network).unwrap() {
+        for recipe in migration.cell_recipes {
+            let Some(CellLocation::File { file }) = config
+                .cells
+                .iter()
+                .find(|c| c.name == recipe.name)
+                .map(|c| &c.location)
+            else {
+                continue;
+            };
+            let out_point = (parse_hash(&recipe.tx_hash).unwrap(), recipe.index);
+            let cell = CodeCell {
+                name: recipe.name.clone(),
+                file: file.rsplit('/').next().unwrap().to_string(),
+                data_hash: parse_hash(&recipe.data_hash).unwrap(),
+            };
+            cells.insert(out_point, cell);
+        }
+    }
+    cells
+}
+
+// Binaries by file name
+fn load_binaries(loader: &Loader) -> HashMap<String, Bytes> {
+    ["ickb_logic", "limit_order", "owned_owner"]
+        .into_iter()
+        .map(|name| (name.to_string(), loader.load_binary(name)))
+        .collect()
+}
+
+fn data1_hash(data_hash: [u8; 32]) -> [u8; 32] {
+    Script::new_builder()
+        .code_hash(data_hash.pack())
+        .hash_type(ScriptHashType::Data1.into())
+        .build()
+        .calc_script_hash()
+        .unpack()
+}
+
+// Rewrites of the scripts referencing a recorded binary that differs from the current one
+#[derive(Default)]
+struct Rewrites {
+    // Data1 code hashes
+    code_hashes: HashMap<[u8; 32], [u8; 32]>,
+    // ickb_logic script hashes, the first 32 bytes of the iCKB xUDT args
+    owner_hashes: HashMap<[u8; 32], [u8; 32]>,
+}
+
+impl Rewrites {
+    fn is_empty(&self) -> bool {
+        self.code_hashes.is_empty()
+    }
+
+    fn script(&self, script: Script) -> Script {
+        let code_hash: [u8; 32] = script.code_hash().unpack();
+        let is_data1 = script.hash_type() == ScriptHashType::Data1.into();
+        if let Some(new) = self.code_hashes.get(&code_hash).filter(|_| is_data1) {
+            return script.as_builder().code_hash(new.pack()).build();
+        }
+        let args = script.args().raw_data();
+        if code_hash == XUDT_CODE_HASH && args.len() >= 32 {
+            let owner: [u8; 32] = args[..32].try_into().unwrap();
+            if let Some(new) = self.owner_hashes.get(&owner) {
+                let args = [new.as_slice(), &args[32..]].concat();
+                return script.as_builder().args(Bytes::from(args).pack()).build();
+            }
+        }
+        script
+    }
+
+    fn output(&self, output: CellOutput) -> CellOutput {
+        let lock = self.script(output.lock());
+        let type_ = output.type_().to_opt().map(|t| self.script(t));
+        output.as_builder().lock(lock).type_(type_.pack()).build()
+    }
+}
+
+// Genesis output indexes of the secp256k1_blake160 sighash all and multisig locks, the signature locks
+// of the recorded inputs, referenced by type
+const SIGNATURE_LOCK_INDEXES: [u32; 2] = [1, 4];
+
+fn signature_locks() -> HashSet<[u8; 32]> {
+    SIGNATURE_LOCK_INDEXES
+        .into_iter()
+        .map(|index| genesis_type_id(index).calc_script_hash().unpack())
+        .collect()
+}
+
+// Verify a recorded transaction with the iCKB code cells replaced by binaries. If a binary differs
+// from the recorded one, scripts referencing it are rewritten and, when this changes the transaction
+// hash, the genesis signature locks of its inputs are bound to always success, as their signatures
+// no longer match. Any other lock is verified as recorded, so a rewrite breaking it fails the replay.
+fn replay(
+    network: &str,
+    mock: MockTransaction,
+    binaries: &HashMap<String, Bytes>,
+) -> Result<Cycle, Error> {
+    let code_cells = code_cells(network);
+    let mut rewrites = Rewrites::default();
+    for dep in &mock.mock_info.cell_deps {
+        if let Some(cell) = code_cells.get(&key(&dep.cell_dep.out_point())) {
+            let data_hash = blake2b_256(&binaries[&cell.file]);
+            if data_hash != cell.data_hash {
+                rewrites.code_hashes.insert(cell.data_hash, data_hash);
+                if cell.name == ICKB_LOGIC {
+                    rewrites
+                        .owner_hashes
+                        .insert(data1_hash(cell.data_hash), data1_hash(data_hash));
+                }
+            }
+        }
+    }
+
+    let recorded = mock.tx.into_view();
+    let tx = if rewrites.is_empty() {
+        recorded.clone()
+    } else {
+        let outputs: Vec<CellOutput> = recorded
+            .outputs()
+            .into_iter()
+            .map(|o| rewrites.output(o))
+            .collect();
+        recorded.as_advanced_builder().set_outputs(outputs).build()
+    };
+    let resigned = tx.hash() != recorded.hash();
+
+    let mut context = Context::default();
+    for header in mock.mock_info.header_deps {
+        context.insert_header(header);
+    }
+    let signature_locks = signature_locks();
+    let mut signed = HashSet::new();
+    for input in mock.mock_info.inputs {
+        let out_point = input.input.previous_output();
+        let output = rewrites.output(input.output);
+        let lock = output.lock();
+        let code_hash: [u8; 32] = lock.code_hash().unpack();
+        if lock.hash_type() == ScriptHashType::Type.into() && signature_locks.contains(&code_hash) {
+            signed.insert(code_hash);
+        }
+        context.create_cell_with_out_point(out_point.clone(), output, input.data);
+        if let Some(block_hash) = input.header {
+            if context.headers.contains_key(&block_hash) {
+                context.link_cell_with_block(out_point, block_hash, 0);
+            }
+        }
+    }
+    for dep in mock.mock_info.cell_deps {
+        let out_point = dep.cell_dep.out_point();
+        let type_hash: Option<[u8; 32]> = dep
+            .output
+            .type_()
+            .to_opt()
+            .map(|t| t.calc_script_hash().unpack());
+        let data = match code_cells.get(&key(&out_point)) {
+            Some(cell) => binaries[&cell.file].clone(),
+            None if resigned && type_hash.is_some_and(|h| signed.contains(&h)) => {
+                ALWAYS_SUCCESS.clone()
+            }
+            None => dep.data,
+        };
+        context.create_cell_with_out_point(out_point, dep.output, data);
+    }
+
+    context.verify_tx(&tx, MAX_BLOCK_CYCLES)
+}
+
+fn load_fixture(path: &Path) -> MockTransaction {
+    let content = fs::read_to_string(path).unwrap();
+    let repr: ReprMockTransaction =
+        serde_json::from_str(&content).unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+    repr.into()
+}
+
+fn fixture_paths(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .map(|e| e.unwrap().path())
+        .filter(|p| p.extension().is_some_and(|e| e == "json"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+// Ignored until the recorded mainnet transactions listed in replay/Readme.md are committed
+#[test]
+#[ignore = "no recorded mainnet transactions in replay/ yet"]
+fn test_replay_fixtures() {
+    let dir =
+        env::var("REPLAY_FIXTURES_DIR").map_or_else(|_| PathBuf::from(FIXTURES_DIR), PathBuf::from);
+    let binaries = load_binaries(&Loader::default());
+    let mut replayed = 0;
+    for network in NETWORKS {
+        for path in fixture_paths(&dir.join(network)) {
+            let cycles = replay(network, load_fixture(&path), &binaries)
+                .unwrap_or_else(|err| panic!("{} is rejected: {err}", path.display()));
+            println!("{}: {cycles} cycles", path.display());
+            replayed += 1;
+        }
+    }
+    // An empty or misplaced fixture directory would otherwise pass without replaying anything
+    assert!(replayed > 0, "no fixtures in {}", dir.display());
+}
+
+// Record a deposit into a fixture, as a node export would
+fn recorded_deposit() -> MockTransaction {
+    let deployment = Deployment::load(&find_dir("deployment"), "mainnet").unwrap();
+    let mut ickb = Ickb::deploy_network(&deployment, ICKB_DEP_GROUP);
+    let amount = 1_000 * CKB;
+    let receipt = ickb
+        .receipt_cell(1, amount)
+        .in_block(ickb.header(crate::fixtures::GENESIS_ACCUMULATED_RATE));
+    let tx: TransactionView = ickb.build(
+        Tx::default()
+            .input_cell(receipt)
+            .output(ickb.udt_cell(u128::from(amount))),
+    );
+    let json = serde_json::to_string(&ickb.context.dump_tx(&tx).unwrap()).unwrap();
+    serde_json::from_str::<ReprMockTransaction>(&json)
+        .unwrap()
+        .into()
+}
+
+#[test]
+fn test_replay_recorded() {
+    let binaries = load_binaries(&Loader::default());
+    replay("mainnet", recorded_deposit(), &binaries).unwrap();
+
+    // A binary with a different data hash, but the same behavior: scripts are rewritten
+    let mut upgraded = binaries.clone();
+    let ickb_logic = [binaries["ickb_logic"].as_ref(), &[0]].concat();
+    upgraded.insert("ickb_logic".to_string(), Bytes::from(ickb_logic));
+    replay("mainnet", recorded_deposit(), &upgraded).unwrap();
+
+    // Signature locks are bound to always success only once a rewrite changes the transaction hash:
+    // the stand-in sighash code is replaced by empty data, which cannot be executed
+    let sighash = genesis_type_id(SIGNATURE_LOCK_INDEXES[0]).calc_script_hash();
+    let mut rejecting = recorded_deposit();
+    for dep in &mut rejecting.mock_info.cell_deps {
+        let type_hash = dep.output.type_().to_opt().map(|t| t.calc_script_hash());
+        if type_hash.is_some_and(|h| h == sighash) {
+            dep.data = Bytes::new();
+        }
+    }
+    let recorded = load_binaries(&Loader::release());
+    let err = replay("mainnet", rejecting.clone(), &recorded).unwrap_err();
+    assert!(err.to_string().contains("Inputs[0].Lock"), "{err}");
+    replay("mainnet", rejecting, &upgraded).unwrap();
+
+    // A binary rejecting the recorded transaction
+    let mut broken = binaries.clone();
+    broken.insert("ickb_logic".to_string(), binaries["owned_owner"].clone());
+    assert!(replay("mainnet", recorded_deposit(), &broken).is_err());
+}
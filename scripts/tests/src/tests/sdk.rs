@@ -0,0 +1,6 @@
+use super::*;
+
+mod deposit;
+mod matcher;
+mod order;
+mod withdrawal;
@@ -0,0 +1,142 @@
+use super::*;
+use crate::fixtures::{deploy_ickb, CKB, GENESIS_ACCUMULATED_RATE};
+use ickb_sdk::{
+    deposit::{check_output, deposit_phase_one, deposit_phase_two, DepositRequest, Receipt},
+    Error,
+};
+
+const ACCUMULATED_RATE: u64 = GENESIS_ACCUMULATED_RATE / 4 * 5;
+
+#[test]
+fn test_sdk_deposit_phase_one() {
+    let mut ickb = deploy_ickb();
+    let scripts = ickb.scripts();
+    let input = ickb.create(ickb.capacity_cell(1_000_000 * CKB));
+
+    let request = DepositRequest::new(ickb.always_success.clone())
+        .deposit(1_000 * CKB, 2)
+        .and_then(|r| r.deposit(2_000 * CKB, 1))
+        .and_then(|r| r.deposit(1_000 * CKB, 1))
+        .unwrap();
+    let tx = deposit_phase_one(&scripts, &[input], &request).expect("valid request");
+
+    // Three deposits of 1_000 CKB and one of 2_000 CKB, followed by two receipts
+    assert_eq!(tx.outputs().len(), 6);
+    ickb.verify_tx(tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_sdk_deposit_phase_one_invalid_request() {
+    let ickb = deploy_ickb();
+    let scripts = ickb.scripts();
+    let lock = ickb.always_success.clone();
+
+    let cases = [
+        (
+            DepositRequest::new(lock.clone()).deposit(1_000 * CKB - 1, 1),
+            Error::DepositTooSmall,
+        ),
+        (
+            DepositRequest::new(lock.clone()).deposit(1_000_000 * CKB + 1, 1),
+            Error::DepositTooBig,
+        ),
+        (
+            DepositRequest::new(lock).deposit(1_000 * CKB, 0),
+            Error::EmptyReceipt,
+        ),
+    ];
+    for (request, err) in cases {
+        let request = request.unwrap();
+        assert_eq!(deposit_phase_one(&scripts, &[], &request).unwrap_err(), err);
+    }
+}
+
+#[test]
+fn test_sdk_deposit_request_overflow() {
+    let ickb = deploy_ickb();
+    let request = DepositRequest::new(ickb.always_success.clone())
+        .deposit(1_000 * CKB, u32::MAX)
+        .unwrap();
+
+    // Deposits of another capacity go to their own receipt
+    let request = request.deposit(2_000 * CKB, 1).unwrap();
+    let err = request.deposit(1_000 * CKB, 1).unwrap_err();
+    assert_eq!(err, Error::ReceiptMismatch);
+}
+
+#[test]
+fn test_sdk_deposit_phase_two() {
+    let mut ickb = deploy_ickb();
+    let scripts = ickb.scripts();
+
+    let mut receipts = Vec::new();
+    for (quantity, amount, accumulated_rate) in [
+        (2, 1_000 * CKB, ACCUMULATED_RATE),
+        (1, 200_000 * CKB, GENESIS_ACCUMULATED_RATE),
+    ] {
+        let header = ickb.header(accumulated_rate);
+        let cell = ickb.receipt_cell(quantity, amount).in_block(header.clone());
+        let data = cell.data.clone();
+        let out_point = ickb.create(cell);
+        receipts.push(Receipt::new(out_point, &data, header).expect("valid receipt"));
+    }
+
+    let lock = ickb.always_success.clone();
+    let tx = deposit_phase_two(&scripts, &receipts, &lock).expect("valid receipts");
+    // 2 * 800 iCKB plus 190_000 iCKB after the soft cap discount
+    assert_eq!(
+        check_output(&scripts, &tx),
+        Ok(u128::from(1_600 * CKB + 190_000 * CKB))
+    );
+
+    // Receipts capacity is not enough for the xUDT cell, so fund it
+    let funding = ickb.create(ickb.capacity_cell(1_000 * CKB));
+    let tx = tx
+        .as_advanced_builder()
+        .input(CellInput::new_builder().previous_output(funding).build())
+        .build();
+    ickb.verify_tx(tx, MAX_CYCLES).expect("pass verification");
+
+    // No iCKB value at a zero accumulated rate
+    let header = ickb.header(0);
+    let data = ickb.receipt_cell(1, 1_000 * CKB).data;
+    let receipt = Receipt::new(receipts[0].out_point.clone(), &data, header).unwrap();
+    assert_eq!(receipt.ickb_amount(&scripts.deposit_limits), None);
+    let err = deposit_phase_two(&scripts, &[receipt], &lock).unwrap_err();
+    assert_eq!(err, Error::InvalidAccumulatedRate);
+}
+
+#[test]
+fn test_sdk_check_output_receipt_mismatch() {
+    let ickb = deploy_ickb();
+    let scripts = ickb.scripts();
+
+    // A hand-built phase 1 with a receipt for the wrong quantity
+    let request = DepositRequest::new(ickb.always_success.clone())
+        .deposit(1_000 * CKB, 2)
+        .unwrap();
+    let tx = deposit_phase_one(&scripts, &[], &request).unwrap();
+    let receipt = ickb.receipt_cell(1, 1_000 * CKB);
+    let outputs: Vec<_> = tx.outputs().into_iter().take(2).collect();
+    let outputs_data: Vec<_> = tx.outputs_data().into_iter().take(2).collect();
+    let tx = tx
+        .as_advanced_builder()
+        .set_outputs(outputs)
+        .set_outputs_data(outputs_data)
+        .output(receipt.output)
+        .output_data(receipt.data.pack())
+        .build();
+    assert_eq!(check_output(&scripts, &tx), Err(Error::ReceiptMismatch));
+}
+
+#[test]
+fn test_sdk_receipt_short_data() {
+    let mut ickb = deploy_ickb();
+    let header = ickb.header(ACCUMULATED_RATE);
+    let out_point = OutPoint::new(Default::default(), 0);
+    let data = ickb.receipt_cell(1, 1_000 * CKB).data;
+    assert_eq!(
+        Receipt::new(out_point, &data[..11], header).unwrap_err(),
+        Error::Encoding
+    );
+}
@@ -0,0 +1,216 @@
+use super::*;
+use crate::fixtures::{deploy_ickb, Ickb, OrderAction, OrderInfo, Ratio, CKB};
+use ckb_testtool::ckb_types::core::{EpochNumberWithFraction, HeaderBuilder, HeaderView};
+use ickb_sdk::{
+    matcher::{batch_transaction, order_books, Batch},
+    order::{Match, Order, OrderExpiry},
+    Error,
+};
+
+const MINT: OrderAction = OrderAction::Mint { master_distance: 1 };
+
+// UDT valued at 1 CKB
+const REFERENCE: Ratio = Ratio {
+    ckb_multiplier: 1,
+    udt_multiplier: 1,
+};
+
+// Sell UDT asking ckb_multiplier CKB every udt_multiplier UDT, ckb_min_match is about 86 CKB
+fn ask(price: Ratio) -> OrderInfo {
+    OrderInfo {
+        ckb_to_udt: Ratio::default(),
+        udt_to_ckb: Ratio {
+            ckb_multiplier: price.udt_multiplier,
+            udt_multiplier: price.ckb_multiplier,
+        },
+        ckb_min_match_log: 33,
+        expiry: None,
+        max_matcher_fee: None,
+    }
+}
+
+// Buy UDT paying ckb_multiplier CKB every udt_multiplier UDT
+fn bid(price: Ratio) -> OrderInfo {
+    OrderInfo {
+        ckb_to_udt: Ratio {
+            ckb_multiplier: price.udt_multiplier,
+            udt_multiplier: price.ckb_multiplier,
+        },
+        udt_to_ckb: Ratio::default(),
+        ckb_min_match_log: 33,
+        expiry: None,
+        max_matcher_fee: None,
+    }
+}
+
+fn price(ckb: u64, udt: u64) -> Ratio {
+    Ratio {
+        ckb_multiplier: ckb,
+        udt_multiplier: udt,
+    }
+}
+
+fn tip(number: u64) -> HeaderView {
+    HeaderBuilder::default()
+        .number(number.pack())
+        .epoch(EpochNumberWithFraction::new(0, 0, 1).full_value().pack())
+        .build()
+}
+
+// Create a minted order with its master
+fn order(ickb: &mut Ickb, info: &OrderInfo, unoccupied: u64, udt_amount: u128) -> Order {
+    let cell = ickb.limit_order_cell(info, &MINT, unoccupied, udt_amount);
+    let (output, data) = (cell.output.clone(), cell.data.clone());
+    let out_points = ickb.create_outputs(vec![cell, ickb.master_cell()]);
+    Order::new(out_points[0].clone(), output, &data).expect("valid order")
+}
+
+#[test]
+fn test_sdk_order_books() {
+    let mut ickb = deploy_ickb();
+    let other_udt = ickb.always_success.clone();
+    let cheap = order(&mut ickb, &ask(price(9, 10)), 0, u128::from(500 * CKB));
+    let fulfilled = order(&mut ickb, &ask(price(9, 10)), 0, 0);
+    let other = Order {
+        output: cheap
+            .output
+            .clone()
+            .as_builder()
+            .type_(Some(other_udt).pack())
+            .build(),
+        ..cheap.clone()
+    };
+
+    let expiring = Order {
+        info: OrderInfo {
+            expiry: Some(OrderExpiry::at_block_number(1_000, &ickb.always_success)),
+            ..cheap.info
+        },
+        ..cheap.clone()
+    };
+
+    let orders = [cheap.clone(), fulfilled, other.clone(), expiring];
+    let books = order_books(&orders, &tip(999));
+    assert_eq!(books.len(), 2);
+    let sizes: Vec<_> = books.values().map(|book| book.orders.len()).collect();
+    assert_eq!(sizes.iter().sum::<usize>(), 3);
+    for (udt_hash, book) in books {
+        let expected: [u8; 32] = book.udt.calc_script_hash().unpack();
+        assert_eq!(udt_hash, expected);
+    }
+
+    // Expired orders can still be matched on-chain, so matchers skip them
+    let books = order_books(&orders, &tip(1_000));
+    for (_, book) in books {
+        assert_eq!(book.orders.len(), 1);
+    }
+}
+
+#[test]
+fn test_sdk_best_batch() {
+    let mut ickb = deploy_ickb();
+    let scripts = ickb.scripts();
+    let orders = [
+        order(&mut ickb, &ask(price(9, 10)), 0, u128::from(500 * CKB)),
+        order(&mut ickb, &ask(price(12, 10)), 0, u128::from(500 * CKB)),
+        order(&mut ickb, &bid(price(11, 10)), 1_000 * CKB, 0),
+    ];
+
+    let books = order_books(&orders, &tip(0));
+    let book = books.values().next().unwrap();
+
+    // Without UDT, the bid is matched only with the UDT bought from the cheap ask
+    let batch = book.best_batch(1_000 * CKB, 0, &REFERENCE);
+    assert_eq!(batch.matches.len(), 2);
+    assert_eq!(batch.matches[0].0.out_point, orders[0].out_point);
+    assert_eq!(batch.matches[1].0.out_point, orders[2].out_point);
+    assert_eq!(batch.ckb_delta, i128::from(100 * CKB));
+    assert_eq!(batch.udt_delta, 0);
+    assert_eq!(batch.profit, i128::from(100 * CKB));
+
+    let tx = batch_transaction(&scripts, &[batch]).unwrap();
+    let funding = ickb.create(ickb.capacity_cell(1_000 * CKB));
+    let tx = tx
+        .as_advanced_builder()
+        .input(CellInput::new_builder().previous_output(funding).build())
+        .build();
+    ickb.verify_tx(tx, MAX_CYCLES).expect("pass verification");
+
+    // Without CKB nothing can be bought, nor sold without UDT
+    let batch = book.best_batch(0, 0, &REFERENCE);
+    assert!(batch.matches.is_empty());
+}
+
+#[test]
+fn test_sdk_best_batch_optimal() {
+    let mut ickb = deploy_ickb();
+    let orders = [
+        order(&mut ickb, &ask(price(9, 10)), 0, u128::from(1_000 * CKB)),
+        order(&mut ickb, &ask(price(85, 100)), 0, u128::from(500 * CKB)),
+        order(&mut ickb, &ask(price(85, 100)), 0, u128::from(500 * CKB)),
+    ];
+    let books = order_books(&orders, &tip(0));
+    let book = books.values().next().unwrap();
+
+    // The most profitable match alone, buying all the UDT of the first ask, would use up 900 of the
+    // 1000 CKB, the two cheaper asks together make more and leave 150 CKB for a partial match
+    let batch = book.best_batch(1_000 * CKB, 0, &REFERENCE);
+    let matched: Vec<_> = batch.matches.iter().map(|(o, _)| &o.out_point).collect();
+    assert_eq!(
+        matched,
+        orders.iter().map(|o| &o.out_point).collect::<Vec<_>>()
+    );
+    assert_eq!(batch.matches[0].1.udt_amount, 83_333_333_334);
+    assert_eq!(batch.matches[1].1.udt_amount, 0);
+    assert_eq!(batch.matches[2].1.udt_amount, 0);
+    assert_eq!(batch.ckb_delta, -i128::from(1_000 * CKB));
+    // 150 CKB from the cheaper asks and 16.67 CKB from the partial match of the first one,
+    // while the greedy pick of the first ask would make 100 CKB plus 17.65 CKB
+    assert_eq!(batch.profit, 16_666_666_666);
+
+    let tx = batch_transaction(&ickb.scripts(), &[batch]).unwrap();
+    let funding = ickb.create(ickb.capacity_cell(1_000 * CKB));
+    let tx = tx
+        .as_advanced_builder()
+        .input(CellInput::new_builder().previous_output(funding).build())
+        .build();
+    ickb.verify_tx(tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_sdk_best_batch_min_match() {
+    let mut ickb = deploy_ickb();
+    let orders = [order(&mut ickb, &bid(price(11, 10)), 1_000 * CKB, 0)];
+    let books = order_books(&orders, &tip(0));
+    let book = books.values().next().unwrap();
+
+    // Selling 50 UDT would be a partial match below ckb_min_match
+    let batch = book.best_batch(0, u128::from(50 * CKB), &REFERENCE);
+    assert!(batch.matches.is_empty());
+
+    let batch = book.best_batch(0, u128::from(100 * CKB), &REFERENCE);
+    assert_eq!(batch.matches.len(), 1);
+    assert_eq!(batch.udt_delta, -i128::from(100 * CKB));
+    assert_eq!(batch.ckb_delta, i128::from(110 * CKB));
+}
+
+#[test]
+fn test_sdk_batch_transaction_validates_matches() {
+    let mut ickb = deploy_ickb();
+    let scripts = ickb.scripts();
+    let ask = order(&mut ickb, &ask(price(9, 10)), 0, u128::from(500 * CKB));
+
+    // Taking all the UDT without paying for it
+    let batch = Batch {
+        matches: vec![(
+            ask.clone(),
+            Match {
+                ckb: ask.ckb(),
+                udt_amount: 0,
+            },
+        )],
+        ..Batch::default()
+    };
+    let err = batch_transaction(&scripts, &[batch]).unwrap_err();
+    assert_eq!(err, Error::DecreasingValue);
+}
@@ -0,0 +1,350 @@
+use super::*;
+use crate::fixtures::{deploy_ickb, Ickb, OrderAction, OrderInfo, Ratio, CKB};
+use ckb_testtool::ckb_types::core::TransactionView;
+use ickb_sdk::{
+    order::{
+        burn_expired_master, check_match, match_order, melt_expired_order, melt_orders, mint_order,
+        Match, Order, OrderExpiry, OrderUdt, UdtLayout, ORDER_SIZE,
+    },
+    Error,
+};
+
+// Sell CKB for UDT at 1:1, ckb_min_match is 2^33 shannons, so about 86 CKB
+const CKB_TO_UDT: OrderInfo = OrderInfo {
+    ckb_to_udt: Ratio {
+        ckb_multiplier: 1,
+        udt_multiplier: 1,
+    },
+    udt_to_ckb: Ratio {
+        ckb_multiplier: 0,
+        udt_multiplier: 0,
+    },
+    ckb_min_match_log: 33,
+    expiry: None,
+    max_matcher_fee: None,
+};
+
+// Sell UDT for CKB, asking 7 CKB every 3 UDT
+const UDT_TO_CKB: OrderInfo = OrderInfo {
+    ckb_to_udt: Ratio {
+        ckb_multiplier: 0,
+        udt_multiplier: 0,
+    },
+    udt_to_ckb: Ratio {
+        ckb_multiplier: 3,
+        udt_multiplier: 7,
+    },
+    ckb_min_match_log: 33,
+    expiry: None,
+    max_matcher_fee: None,
+};
+
+// Create a minted order with its master
+fn order(ickb: &mut Ickb, info: &OrderInfo, unoccupied: u64, udt_amount: u128) -> Order {
+    let action = OrderAction::Mint { master_distance: 1 };
+    let cell = ickb.limit_order_cell(info, &action, unoccupied, udt_amount);
+    let (output, data) = (cell.output.clone(), cell.data.clone());
+    let out_points = ickb.create_outputs(vec![cell, ickb.master_cell()]);
+    Order::new(out_points[0].clone(), output, &data).expect("valid order")
+}
+
+// Add the matcher side of the trade
+fn fill(ickb: &mut Ickb, tx: TransactionView, udt_in: u128, ckb_in: u64) -> TransactionView {
+    let mut builder = tx.as_advanced_builder();
+    if udt_in > 0 {
+        let out_point = ickb.create(ickb.udt_cell(udt_in));
+        builder = builder.input(CellInput::new_builder().previous_output(out_point).build());
+    }
+    let out_point = ickb.create(ickb.capacity_cell(ckb_in));
+    builder
+        .input(CellInput::new_builder().previous_output(out_point).build())
+        .build()
+}
+
+#[test]
+fn test_sdk_mint_order() {
+    let mut ickb = deploy_ickb();
+    let scripts = ickb.scripts();
+    let input = ickb.create(ickb.capacity_cell(10_000 * CKB));
+
+    let (udt, lock) = (OrderUdt::from(&ickb.ickb_udt), ickb.always_success.clone());
+    let tx = mint_order(&scripts, &[input], &udt, &CKB_TO_UDT, 2_000 * CKB, 0, &lock).unwrap();
+
+    let (output, data) = tx.output_with_data(0).unwrap();
+    assert_eq!(data.len(), ORDER_SIZE);
+    let order = Order::new(OutPoint::new(tx.hash(), 0), output, &data).unwrap();
+    assert_eq!(order.master, OutPoint::new(tx.hash(), 1));
+    assert_eq!(order.ckb_unoccupied(), 2_000 * CKB);
+    ickb.verify_tx(tx, MAX_CYCLES).expect("pass verification");
+
+    let invalid = OrderInfo::default();
+    let err = mint_order(&scripts, &[], &udt, &invalid, 2_000 * CKB, 0, &lock).unwrap_err();
+    assert_eq!(err, Error::BothRatioNull);
+}
+
+#[test]
+fn test_sdk_ckb_to_udt_match() {
+    let mut ickb = deploy_ickb();
+    let scripts = ickb.scripts();
+
+    // Partial match limited by the available UDT, then full match
+    for (udt_available, ckb_taken) in [(500 * CKB, 500 * CKB), (5_000 * CKB, 2_000 * CKB)] {
+        let order = order(&mut ickb, &CKB_TO_UDT, 2_000 * CKB, 0);
+        let m = order
+            .max_ckb_to_udt_match(u128::from(udt_available))
+            .unwrap();
+        assert_eq!(m.ckb, order.ckb() - ckb_taken);
+        assert_eq!(m.udt_amount, u128::from(ckb_taken));
+
+        let tx = match_order(&scripts, &order, &m).unwrap();
+        let tx = fill(&mut ickb, tx, m.udt_amount, 0);
+        ickb.verify_tx(tx, MAX_CYCLES).expect("pass verification");
+    }
+
+    let order = order(&mut ickb, &CKB_TO_UDT, 2_000 * CKB, 0);
+    let err = order
+        .max_ckb_to_udt_match(u128::from(10 * CKB))
+        .unwrap_err();
+    assert_eq!(err, Error::InsufficientMatch);
+    let err = order.max_udt_to_ckb_match(10 * CKB).unwrap_err();
+    assert_eq!(err, Error::InvalidMatch);
+}
+
+#[test]
+fn test_sdk_udt_to_ckb_match() {
+    let mut ickb = deploy_ickb();
+    let scripts = ickb.scripts();
+    let udt_amount = u128::from(1_000 * CKB) + 1;
+    let order = order(&mut ickb, &UDT_TO_CKB, 0, udt_amount);
+
+    // Rounding always favors the order
+    let ckb_available = 700 * CKB + 5;
+    let m = order.max_udt_to_ckb_match(ckb_available).unwrap();
+    let udt_taken = udt_amount - m.udt_amount;
+    let ckb_given = m.ckb - order.ckb();
+    assert_eq!(udt_taken, u128::from(ckb_available) * 3 / 7);
+    assert!(ckb_given <= ckb_available);
+    assert!(u128::from(ckb_given) * 3 >= udt_taken * 7);
+
+    let tx = match_order(&scripts, &order, &m).unwrap();
+    let tx = fill(&mut ickb, tx, 0, ckb_given);
+    ickb.verify_tx(tx, MAX_CYCLES).expect("pass verification");
+
+    // The fulfilled order cannot be matched anymore
+    let (output, data) = order.apply(&Match {
+        ckb: order.ckb(),
+        udt_amount: 0,
+    });
+    let fulfilled = Order::new(order.out_point.clone(), output, &data).unwrap();
+    assert!(fulfilled.is_fulfilled());
+    let err = fulfilled.max_udt_to_ckb_match(ckb_available).unwrap_err();
+    assert_eq!(err, Error::AttemptToChangeFulfilled);
+}
+
+#[test]
+fn test_sdk_check_match_decreasing_value() {
+    let mut ickb = deploy_ickb();
+    let order = order(&mut ickb, &CKB_TO_UDT, 2_000 * CKB, 0);
+    let m = order.max_ckb_to_udt_match(u128::from(1_000 * CKB)).unwrap();
+
+    let (output, data) = order.apply(&Match {
+        udt_amount: m.udt_amount - 1,
+        ..m
+    });
+    let matched = Order::new(order.out_point.clone(), output, &data).unwrap();
+    assert_eq!(check_match(&order, &matched), Err(Error::DecreasingValue));
+}
+
+#[test]
+fn test_sdk_melt_orders() {
+    let mut ickb = deploy_ickb();
+    let scripts = ickb.scripts();
+    let orders = [
+        order(&mut ickb, &CKB_TO_UDT, 2_000 * CKB, 0),
+        order(&mut ickb, &UDT_TO_CKB, 0, u128::from(300 * CKB)),
+        order(&mut ickb, &UDT_TO_CKB, 0, u128::from(200 * CKB)),
+    ];
+
+    let lock = ickb.always_success.clone();
+    let tx = melt_orders(&scripts, &orders, &lock);
+    assert_eq!(tx.inputs().len(), 6);
+    assert_eq!(tx.outputs().len(), 1);
+    assert_eq!(
+        tx.outputs_data().get(0).unwrap().raw_data(),
+        ickb.udt_cell(u128::from(500 * CKB)).data
+    );
+    ickb.verify_tx(tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_sdk_xudt_data_order() {
+    use ickb_types::XudtData;
+    let ickb = deploy_ickb();
+    let scripts = ickb.scripts();
+    let xudt_data = |lock: u8| {
+        let lock = ickb_types::Bytes::new_builder()
+            .push(Byte::new(lock))
+            .build();
+        XudtData::new_builder().lock(lock).build().as_bytes()
+    };
+    let udt = OrderUdt {
+        script: ickb.ickb_udt.clone(),
+        layout: UdtLayout::XudtData(xudt_data(1)),
+    };
+    let lock = ickb.always_success.clone();
+    let tx = mint_order(&scripts, &[], &udt, &CKB_TO_UDT, 2_000 * CKB, 0, &lock).unwrap();
+
+    // UDT data comes first, as expected by xUDT
+    let (output, data) = tx.output_with_data(0).unwrap();
+    assert_eq!(data.len(), udt.layout.order_size());
+    assert_eq!(&data[16..16 + xudt_data(1).len()], xudt_data(1).as_ref());
+    let order = Order::new(OutPoint::new(tx.hash(), 0), output, &data).unwrap();
+    assert_eq!(order.udt_layout, udt.layout);
+    assert_eq!(order.ckb_unoccupied(), 2_000 * CKB);
+
+    // Matches preserve the xUDT data
+    let m = order.max_ckb_to_udt_match(u128::from(1_000 * CKB)).unwrap();
+    let (output, data) = order.apply(&m);
+    let matched = Order::new(order.out_point.clone(), output, &data).unwrap();
+    assert_eq!(check_match(&order, &matched), Ok(()));
+    let changed = Order {
+        udt_layout: UdtLayout::XudtData(xudt_data(2)),
+        ..matched
+    };
+    assert_eq!(check_match(&order, &changed), Err(Error::UdtDataChanged));
+
+    let invalid = OrderUdt {
+        layout: UdtLayout::XudtData(Bytes::from(vec![0u8; 20])),
+        ..udt
+    };
+    let err = mint_order(&scripts, &[], &invalid, &CKB_TO_UDT, 2_000 * CKB, 0, &lock).unwrap_err();
+    assert_eq!(err, Error::InvalidUdtLayout);
+}
+
+#[test]
+fn test_sdk_order_expiry() {
+    use ckb_testtool::ckb_types::core::{EpochNumberWithFraction, HeaderBuilder};
+    let ickb = deploy_ickb();
+    let scripts = ickb.scripts();
+    let (udt, lock) = (OrderUdt::from(&ickb.ickb_udt), ickb.always_success.clone());
+    let info = OrderInfo {
+        expiry: Some(OrderExpiry::at_block_number(1_000, &lock)),
+        ..CKB_TO_UDT
+    };
+    let tx = mint_order(&scripts, &[], &udt, &info, 2_000 * CKB, 0, &lock).unwrap();
+    let (_, master_data) = tx.output_with_data(1).unwrap();
+    assert_eq!(master_data, Bytes::from(1_000u64.to_le_bytes().to_vec()));
+
+    let (output, data) = tx.output_with_data(0).unwrap();
+    let order = Order::new(OutPoint::new(tx.hash(), 0), output, &data).unwrap();
+    assert_eq!(order.info, info);
+    assert_eq!(order.data_size(), data.len());
+    assert_eq!(order.ckb_unoccupied(), 2_000 * CKB);
+    let epoch = EpochNumberWithFraction::new(10, 1, 4);
+    let tip = |number: u64, epoch: EpochNumberWithFraction| {
+        HeaderBuilder::default()
+            .number(number.pack())
+            .epoch(epoch.full_value().pack())
+            .build()
+    };
+    assert!(!order.is_expired(&tip(999, epoch)));
+    assert!(order.is_expired(&tip(1_000, epoch)));
+
+    // Matches keep the expiry
+    let m = order.max_ckb_to_udt_match(u128::from(1_000 * CKB)).unwrap();
+    let (output, data) = order.apply(&m);
+    let matched = Order::new(order.out_point.clone(), output, &data).unwrap();
+    assert_eq!(check_match(&order, &matched), Ok(()));
+
+    // Refund to the master lock
+    let tx = melt_expired_order(&scripts, &order, &lock).unwrap();
+    let since: u64 = tx.inputs().get(0).unwrap().since().unpack();
+    assert_eq!(since, 1_000);
+    let (refund, data) = tx.output_with_data(0).unwrap();
+    assert_eq!(refund.lock(), lock);
+    let capacity: u64 = refund.capacity().unpack();
+    assert_eq!(capacity, order.ckb());
+    assert_eq!(data, ickb.udt_cell(0).data);
+    let err = melt_expired_order(&scripts, &order, &ickb.ickb_logic).unwrap_err();
+    assert_eq!(err, Error::InsufficientRefund);
+
+    // Then the master can be burned alone
+    let master = OutPoint::new(order.out_point.tx_hash(), 1);
+    let tx = burn_expired_master(&scripts, &master, &info.expiry.unwrap()).unwrap();
+    let input = tx.inputs().get(0).unwrap();
+    assert_eq!(input.previous_output(), master);
+    let since: u64 = input.since().unpack();
+    assert_eq!(since, 1_000);
+
+    // Epoch expiry and invalid expiries
+    let expiry = OrderExpiry::at_epoch(epoch, &lock);
+    let before = EpochNumberWithFraction::new(10, 0, 4);
+    assert!(!expiry.is_expired(&tip(1_000, before)));
+    assert!(expiry.is_expired(&tip(0, EpochNumberWithFraction::new(10, 2, 8))));
+    let other_lock = OrderInfo {
+        expiry: Some(OrderExpiry::at_block_number(1_000, &ickb.ickb_logic)),
+        ..info
+    };
+    let err = mint_order(&scripts, &[], &udt, &other_lock, 2_000 * CKB, 0, &lock).unwrap_err();
+    assert_eq!(err, Error::InvalidExpiry);
+    let relative = OrderInfo {
+        expiry: Some(OrderExpiry {
+            since: 1 << 63 | 1_000,
+            ..expiry
+        }),
+        ..info
+    };
+    let err = mint_order(&scripts, &[], &udt, &relative, 2_000 * CKB, 0, &lock).unwrap_err();
+    assert_eq!(err, Error::InvalidExpiry);
+}
+
+#[test]
+fn test_sdk_order_matcher_fee() {
+    let mut ickb = deploy_ickb();
+    let scripts = ickb.scripts();
+    let info = OrderInfo {
+        max_matcher_fee: Some(1_000),
+        ..CKB_TO_UDT
+    };
+    let order = order(&mut ickb, &info, 2_000 * CKB, 0);
+    assert_eq!(order.info, info);
+
+    // The matcher keeps up to 0.1% of the CKB taken
+    let m = order.max_ckb_to_udt_match(u128::from(999 * CKB)).unwrap();
+    assert_eq!(m.ckb, order.ckb() - 1_000 * CKB);
+    assert_eq!(m.udt_amount, u128::from(999 * CKB));
+    let (output, data) = order.apply(&m);
+    let matched = Order::new(order.out_point.clone(), output, &data).unwrap();
+    assert_eq!(check_match(&order, &matched), Ok(()));
+    let (output, data) = order.apply(&Match {
+        udt_amount: m.udt_amount - 1,
+        ..m
+    });
+    let greedy = Order::new(order.out_point.clone(), output, &data).unwrap();
+    assert_eq!(check_match(&order, &greedy), Err(Error::DecreasingValue));
+
+    // Same on the UDT -> CKB side, rounding still favors the order
+    let info = OrderInfo {
+        max_matcher_fee: Some(2_500),
+        ..UDT_TO_CKB
+    };
+    let udt_amount = u128::from(1_000 * CKB) + 1;
+    let order = self::order(&mut ickb, &info, 0, udt_amount);
+    let ckb_available = 700 * CKB + 5;
+    let m = order.max_udt_to_ckb_match(ckb_available).unwrap();
+    let ckb_given = m.ckb - order.ckb();
+    assert!(ckb_given <= ckb_available);
+    assert!(udt_amount - m.udt_amount > u128::from(ckb_available) * 3 / 7);
+    let (output, data) = order.apply(&m);
+    let matched = Order::new(order.out_point.clone(), output, &data).unwrap();
+    assert_eq!(check_match(&order, &matched), Ok(()));
+
+    // The fee must be lower than the whole value
+    let (udt, lock) = (OrderUdt::from(&ickb.ickb_udt), ickb.always_success.clone());
+    let invalid = OrderInfo {
+        max_matcher_fee: Some(1_000_000),
+        ..info
+    };
+    let err = mint_order(&scripts, &[], &udt, &invalid, 0, 1, &lock).unwrap_err();
+    assert_eq!(err, Error::InvalidMatcherFee);
+}
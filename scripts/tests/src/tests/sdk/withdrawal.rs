@@ -0,0 +1,169 @@
+use super::*;
+use crate::fixtures::{deploy_ickb, Ickb, CKB, GENESIS_ACCUMULATED_RATE};
+use ::ickb_rate::DepositLimits;
+use ckb_testtool::ckb_types::core::{EpochNumberWithFraction, HeaderView};
+use ickb_sdk::{
+    withdrawal::{
+        check_owned_owner, select_deposits, withdrawal, withdrawal_request, Deposit,
+        WithdrawalRequest,
+    },
+    Error, UdtCell,
+};
+
+const ACCUMULATED_RATE: u64 = GENESIS_ACCUMULATED_RATE / 4 * 5;
+
+// Create a live iCKB deposit
+fn deposit(ickb: &mut Ickb, amount: u64, accumulated_rate: u64) -> Deposit {
+    let cell = ickb.deposit_cell(amount, accumulated_rate);
+    let output = cell.output.clone();
+    let header = cell.header.clone().unwrap();
+    let out_point = ickb.create(cell);
+    Deposit {
+        out_point,
+        output,
+        header,
+    }
+}
+
+// Create a live iCKB xUDT cell
+fn udt(ickb: &mut Ickb, amount: u128) -> UdtCell {
+    let out_point = ickb.create(ickb.udt_cell(amount));
+    UdtCell { out_point, amount }
+}
+
+fn with_epoch(header: HeaderView, number: u64, index: u64) -> HeaderView {
+    let epoch = EpochNumberWithFraction::new(number, index, 1000);
+    header
+        .as_advanced_builder()
+        .epoch(epoch.full_value().pack())
+        .build()
+}
+
+#[test]
+fn test_sdk_select_deposits() {
+    let mut ickb = deploy_ickb();
+    let deposits = [
+        deposit(&mut ickb, 1_000 * CKB, GENESIS_ACCUMULATED_RATE),
+        deposit(&mut ickb, 3_000 * CKB, GENESIS_ACCUMULATED_RATE),
+        deposit(&mut ickb, 2_000 * CKB, GENESIS_ACCUMULATED_RATE),
+    ];
+
+    let selected = select_deposits(&deposits, u128::from(4_500 * CKB), &DepositLimits::V1);
+    let amounts: Vec<_> = selected.iter().map(Deposit::unoccupied).collect();
+    assert_eq!(amounts, [3_000 * CKB, 1_000 * CKB]);
+
+    assert!(select_deposits(&deposits, u128::from(999 * CKB), &DepositLimits::V1).is_empty());
+}
+
+#[test]
+fn test_sdk_withdrawal_request() {
+    let mut ickb = deploy_ickb();
+    let scripts = ickb.scripts();
+    let deposits = [
+        deposit(&mut ickb, 1_000 * CKB, ACCUMULATED_RATE),
+        deposit(&mut ickb, 2_000 * CKB, GENESIS_ACCUMULATED_RATE),
+    ];
+    let udt_cells = [udt(&mut ickb, u128::from(3_000 * CKB))];
+
+    let lock = ickb.always_success.clone();
+    let tx = withdrawal_request(&scripts, &deposits, &udt_cells, &lock).expect("enough iCKB");
+
+    // Two withdrawal requests, two owners and the change of 3_000 - 800 - 2_000 iCKB
+    assert_eq!(tx.outputs().len(), 5);
+    let change = UdtCell::new(
+        OutPoint::default(),
+        &tx.outputs_data().get(4).unwrap().raw_data(),
+    );
+    assert_eq!(change.unwrap().amount, u128::from(200 * CKB));
+    ickb.verify_tx(tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_sdk_withdrawal_request_not_enough_ickb() {
+    let mut ickb = deploy_ickb();
+    let scripts = ickb.scripts();
+    let deposits = [deposit(&mut ickb, 1_000 * CKB, GENESIS_ACCUMULATED_RATE)];
+    let udt_cells = [udt(&mut ickb, u128::from(1_000 * CKB) - 1)];
+
+    let lock = ickb.always_success.clone();
+    let err = withdrawal_request(&scripts, &deposits, &udt_cells, &lock).unwrap_err();
+    assert_eq!(err, Error::AmountMismatch);
+}
+
+#[test]
+fn test_sdk_withdrawal() {
+    let mut ickb = deploy_ickb();
+    let scripts = ickb.scripts();
+    let deposit = deposit(&mut ickb, 1_000 * CKB, GENESIS_ACCUMULATED_RATE);
+    let deposit_header = with_epoch(deposit.header.clone(), 10, 500);
+    let deposit = Deposit {
+        header: deposit_header.clone(),
+        ..deposit
+    };
+    let udt_cells = [udt(&mut ickb, u128::from(1_000 * CKB))];
+
+    let lock = ickb.always_success.clone();
+    let tx = withdrawal_request(&scripts, &[deposit], &udt_cells, &lock).unwrap();
+    let header = with_epoch(ickb.header(ACCUMULATED_RATE), 200, 600);
+    ickb.commit(&tx, &header);
+
+    let requests =
+        WithdrawalRequest::from_transaction(&scripts, &tx, &header, &[deposit_header]).unwrap();
+    assert_eq!(requests.len(), 1);
+    let request = &requests[0];
+    assert_eq!(request.owned, OutPoint::new(tx.hash(), 0));
+    assert_eq!(request.owner, OutPoint::new(tx.hash(), 1));
+
+    // Accumulated rate grew by 25%, so does the unoccupied capacity
+    let capacity: u64 = request.owned_output.capacity().unpack();
+    assert_eq!(request.maximum_withdraw(), capacity + 250 * CKB);
+
+    // 190 epochs and a fraction have passed, so the deposit is locked for two periods
+    let unlock = EpochNumberWithFraction::new(370, 500, 1000);
+    assert_eq!(request.since(), 0x2000_0000_0000_0000 | unlock.full_value());
+
+    let tx = withdrawal(&scripts, &requests, &lock);
+    ickb.verify_tx(tx, MAX_CYCLES).expect("pass verification");
+}
+
+#[test]
+fn test_sdk_withdrawal_missing_deposit_header() {
+    let mut ickb = deploy_ickb();
+    let scripts = ickb.scripts();
+    let deposits = [deposit(&mut ickb, 1_000 * CKB, GENESIS_ACCUMULATED_RATE)];
+    let udt_cells = [udt(&mut ickb, u128::from(1_000 * CKB))];
+
+    let lock = ickb.always_success.clone();
+    let tx = withdrawal_request(&scripts, &deposits, &udt_cells, &lock).unwrap();
+    let header = ickb.header(ACCUMULATED_RATE);
+    let err = WithdrawalRequest::from_transaction(&scripts, &tx, &header, &[]).unwrap_err();
+    assert_eq!(err, Error::ItemMissing);
+}
+
+#[test]
+fn test_sdk_check_owned_owner_mismatch() {
+    let mut ickb = deploy_ickb();
+    let scripts = ickb.scripts();
+    let deposits = [
+        deposit(&mut ickb, 1_000 * CKB, GENESIS_ACCUMULATED_RATE),
+        deposit(&mut ickb, 1_000 * CKB, GENESIS_ACCUMULATED_RATE),
+    ];
+    let udt_cells = [udt(&mut ickb, u128::from(2_000 * CKB))];
+
+    let lock = ickb.always_success.clone();
+    let tx = withdrawal_request(&scripts, &deposits, &udt_cells, &lock).unwrap();
+
+    // Both owners pointing at the first withdrawal request
+    let owner = ickb.owner_cell(-3);
+    let outputs_data: Vec<_> = tx
+        .outputs_data()
+        .into_iter()
+        .enumerate()
+        .map(|(index, data)| if index == 3 { owner.data.pack() } else { data })
+        .collect();
+    let tx = tx
+        .as_advanced_builder()
+        .set_outputs_data(outputs_data)
+        .build();
+    assert_eq!(check_owned_owner(&scripts, &tx), Err(Error::Mismatch));
+}
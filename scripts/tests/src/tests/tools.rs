@@ -0,0 +1,391 @@
+use super::*;
+use crate::fixtures::CKB;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_types::core::{DepType, ScriptHashType};
+use ickb_sdk::order::{mint_order, OrderInfo, OrderUdt, Ratio};
+use ickb_tools::{
+    deployment::{
+        data_hash, find_dir, migration_paths, parse_hash, to_hex, DeploymentConfig, Migration,
+        Network,
+    },
+    devnet::{devnet_deployment, load_template},
+    history::{history_report, load_migrations, migration_history, HISTORY_SCRIPTS},
+    registry::{embedded_migrations, Deployment},
+    scripts::{network_scripts, script_ids, ICKB_DEP_GROUP},
+    verify::{report, verify_migration, verify_release},
+};
+use std::{env, fs, path::PathBuf};
+use utils::{DAO_HASH, XUDT_CODE_HASH};
+
+const NETWORKS: [&str; 2] = ["testnet", "mainnet"];
+
+#[test]
+fn test_release_matches_deployments() {
+    let checks = verify_release(
+        &find_dir("deployment"),
+        &find_dir("build/release"),
+        &NETWORKS,
+        &[("xudt", XUDT_CODE_HASH)],
+    )
+    .expect("valid deployment records");
+
+    let names: Vec<&str> = checks.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["ICKB_LOGIC", "LIMIT_ORDER", "OWNED_OWNER", "XUDT"]);
+    for check in checks {
+        assert!(check.agrees(), "{check:?}");
+    }
+}
+
+#[test]
+fn test_verify_release_mismatch() {
+    // Copy of the deployment records with a tampered mainnet migration
+    let dir = env::temp_dir().join(format!("ickb_verify_release_{}", std::process::id()));
+    for network in NETWORKS {
+        let source = find_dir("deployment").join(network);
+        let target = dir.join(network);
+        fs::create_dir_all(target.join("migrations")).unwrap();
+        fs::copy(
+            source.join("deployment.toml"),
+            target.join("deployment.toml"),
+        )
+        .unwrap();
+        let (path, mut migration) = Migration::latest(&source.join("migrations")).unwrap();
+        if network == "mainnet" {
+            migration.cell_recipes[1].data_hash = to_hex(&[0u8; 32]);
+        }
+        fs::write(
+            target.join("migrations").join(path.file_name().unwrap()),
+            serde_json::to_string(&migration).unwrap(),
+        )
+        .unwrap();
+    }
+
+    let checks = verify_release(&dir, &find_dir("build/release"), &NETWORKS, &[]).unwrap();
+    let agrees: Vec<(&str, bool)> = checks
+        .iter()
+        .map(|c| (c.name.as_str(), c.agrees()))
+        .collect();
+    assert_eq!(
+        agrees,
+        [
+            ("ICKB_LOGIC", true),
+            ("LIMIT_ORDER", false),
+            ("OWNED_OWNER", true)
+        ]
+    );
+
+    // Missing binaries never agree
+    let checks = verify_release(&dir, &PathBuf::from("missing"), &NETWORKS, &[]).unwrap();
+    assert!(checks.iter().all(|c| c.local.is_none() && !c.agrees()));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_hex_round_trip() {
+    let hash = data_hash(b"iCKB");
+    assert_eq!(parse_hash(&to_hex(&hash)).unwrap(), hash);
+    assert!(parse_hash("0x00").is_err());
+    assert!(parse_hash(&to_hex(&hash)[2..]).is_err());
+}
+
+#[test]
+fn test_script_ids() {
+    let deployment_dir = find_dir("deployment");
+    let ids =
+        NETWORKS.map(|name| script_ids(&Deployment::load(&deployment_dir, name).unwrap()).unwrap());
+
+    for ids in &ids {
+        let script_hash = |name: &str| ids.scripts[name].script_hash.clone();
+        assert_eq!(script_hash("dao"), to_hex(&DAO_HASH));
+        assert_eq!(ids.scripts["ickb_udt"].code_hash, to_hex(&XUDT_CODE_HASH));
+        assert_eq!(
+            ids.scripts["ickb_udt"].args,
+            format!("{}00000080", script_hash("ickb_logic"))
+        );
+        assert_eq!(ids.cell_deps.len(), 1);
+    }
+
+    // Same binaries on both networks, so same scripts but different dep groups
+    assert_eq!(ids[0].scripts, ids[1].scripts);
+    assert_ne!(ids[0].cell_deps, ids[1].cell_deps);
+
+    // Values used by the frontends
+    let scripts = &ids[1].scripts;
+    assert_eq!(
+        scripts["ickb_logic"].script_hash,
+        "0xb73b6ab39d79390c6de90a09c96b290c331baf1798ed6f97aed02590929734e8"
+    );
+    assert_eq!(
+        scripts["ickb_udt"].script_hash,
+        "0xd485c2271949c232e3f5d46128336c716f90bcbf3cb278696083689fbbcd407a"
+    );
+}
+
+#[test]
+fn test_devnet_deployment() {
+    let genesis_tx_hash = [7u8; 32];
+    let template = find_dir("deployment").join("devnet").join("template.toml");
+    let config = load_template(&template, genesis_tx_hash).unwrap();
+    let devnet = devnet_deployment(&config, &find_dir("build/release"), None).unwrap();
+    let registry = devnet.deployment().unwrap();
+    assert_eq!(
+        registry.cell("SECP256K1_DATA").unwrap().out_point,
+        OutPoint::new(genesis_tx_hash.pack(), 3)
+    );
+
+    // Deterministic and with the same code hashes as the public networks
+    let again = devnet_deployment(&config, &find_dir("build/release"), None).unwrap();
+    assert_eq!(devnet.migration, again.migration);
+    let mainnet = Network::load(&find_dir("deployment"), "mainnet").unwrap();
+    for name in ["ICKB_LOGIC", "LIMIT_ORDER", "OWNED_OWNER"] {
+        let recipe = devnet.migration.cell_recipe(name).unwrap();
+        let mainnet_recipe = mainnet.migration.cell_recipe(name).unwrap();
+        assert_eq!(recipe.data_hash, mainnet_recipe.data_hash);
+        assert_eq!(recipe.occupied_capacity, mainnet_recipe.occupied_capacity);
+    }
+
+    // Context snapshot: deployment outputs plus stand-ins for the genesis cells
+    let mut context = Context::default();
+    for tx in &devnet.transactions {
+        for (index, (output, data)) in tx.outputs_with_data_iter().enumerate() {
+            context.create_cell_with_out_point(
+                OutPoint::new(tx.hash(), index as u32),
+                output,
+                data,
+            );
+        }
+    }
+    for index in 1..=4u32 {
+        let out_point = OutPoint::new(genesis_tx_hash.pack(), index);
+        context.create_cell_with_out_point(out_point, CellOutput::default(), Bytes::new());
+    }
+
+    // The dep group alone resolves the iCKB scripts
+    let scripts = network_scripts(&registry).unwrap();
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let lock = context
+        .build_script_with_hash_type(
+            &always_success_out_point,
+            ScriptHashType::Data1,
+            Bytes::new(),
+        )
+        .unwrap();
+    let input = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((10_000 * CKB).pack())
+            .lock(lock.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let info = OrderInfo {
+        ckb_to_udt: Ratio {
+            ckb_multiplier: 1,
+            udt_multiplier: 1,
+        },
+        udt_to_ckb: Ratio::default(),
+        ckb_min_match_log: 33,
+        expiry: None,
+        max_matcher_fee: None,
+    };
+    let tx = mint_order(
+        &scripts,
+        &[input],
+        &OrderUdt::from(&scripts.ickb_udt),
+        &info,
+        2_000 * CKB,
+        0,
+        &lock,
+    )
+    .unwrap()
+    .as_advanced_builder()
+    .cell_dep(
+        CellDep::new_builder()
+            .out_point(always_success_out_point)
+            .build(),
+    )
+    .build();
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+}
+
+#[test]
+fn test_registry_embeds_every_migration() {
+    for network in NETWORKS {
+        let dir = find_dir("deployment").join(network).join("migrations");
+        let files: Vec<String> = migration_paths(&dir)
+            .unwrap()
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        let embedded = embedded_migrations(network).unwrap();
+        let embedded_files: Vec<&str> = embedded.iter().map(|(f, _)| f.as_str()).collect();
+        assert_eq!(embedded_files, files);
+        for (file, migration) in embedded {
+            assert_eq!(migration, Migration::load(&dir.join(file)).unwrap());
+        }
+
+        let deployment = Deployment::embedded(network).unwrap();
+        assert_eq!(
+            deployment,
+            Deployment::load(&find_dir("deployment"), network).unwrap()
+        );
+    }
+    assert!(Deployment::embedded("devnet").is_err());
+}
+
+#[test]
+fn test_migration_history() {
+    let dir = find_dir("deployment").join("testnet").join("migrations");
+    let migrations = load_migrations(&dir).unwrap();
+    assert_eq!(migrations, embedded_migrations("testnet").unwrap());
+    let steps = migration_history("testnet", &migrations).unwrap();
+
+    let changed: Vec<Vec<&str>> = steps
+        .iter()
+        .map(|s| s.changed.iter().map(String::as_str).collect())
+        .collect();
+    assert_eq!(
+        changed,
+        [
+            HISTORY_SCRIPTS.to_vec(),
+            vec!["LIMIT_ORDER"],
+            vec!["OWNED_OWNER", "LIMIT_ORDER"],
+            vec!["ICKB_LOGIC"],
+            HISTORY_SCRIPTS.to_vec(),
+        ]
+    );
+    assert!(steps[0].stranded.is_empty());
+
+    // Only ickb_logic changes mint a new iCKB xUDT
+    let udt_hashes: Vec<[u8; 32]> = steps.iter().map(|s| s.generation.ickb_udt_hash).collect();
+    assert_eq!(udt_hashes[0], udt_hashes[2]);
+    assert_ne!(udt_hashes[2], udt_hashes[3]);
+    assert_ne!(udt_hashes[3], udt_hashes[4]);
+
+    // Deposits, receipts and iCKB of the previous ickb_logic are stranded
+    let step = &steps[3];
+    let previous = &steps[2].generation;
+    let stranded: Vec<[u8; 32]> = step.stranded.iter().map(|s| s.script_hash).collect();
+    assert_eq!(
+        stranded,
+        [
+            previous.ickb_logic_hash,
+            previous.ickb_logic_hash,
+            previous.ickb_udt_hash,
+        ]
+    );
+    assert!(step
+        .stranded
+        .iter()
+        .all(|s| s.code_cell == previous.code_cells["ICKB_LOGIC"].out_point));
+
+    // The latest generation is the one in use
+    let latest = &steps[4].generation;
+    let ids = script_ids(&Deployment::load(&find_dir("deployment"), "testnet").unwrap()).unwrap();
+    assert_eq!(
+        ids.scripts["ickb_logic"].script_hash,
+        to_hex(&latest.ickb_logic_hash)
+    );
+    assert_eq!(
+        ids.scripts["ickb_udt"].script_hash,
+        to_hex(&latest.ickb_udt_hash)
+    );
+    assert_eq!(steps[4].stranded.len(), 7);
+
+    let report = history_report(&steps);
+    assert!(report.contains(&to_hex(&latest.ickb_udt_hash)));
+    let stranded: usize = steps.iter().map(|s| s.stranded.len()).sum();
+    assert_eq!(report.matches("stranded").count(), stranded);
+}
+
+#[test]
+fn test_registry_mainnet() {
+    let mainnet = Deployment::mainnet().unwrap();
+    let ickb_logic = mainnet.cell("ICKB_LOGIC").unwrap();
+    assert_eq!(
+        ickb_logic.out_point,
+        OutPoint::new(
+            parse_hash("0xd7309191381f5a8a2904b8a79958a9be2752dbba6871fa193dab6aeb29dc8f44")
+                .unwrap()
+                .pack(),
+            0
+        )
+    );
+    assert_eq!(
+        ickb_logic.data_hash.map(|h| to_hex(&h)),
+        Some("0x2a8100ab5990fa055ab1b50891702e1e895c7bd1df6322cd725c1a6115873bd3".to_string())
+    );
+    assert_eq!(ickb_logic.cell_dep().dep_type(), DepType::Code.into());
+
+    // Cells already live have no recipe
+    let dao = mainnet.cell("DAO").unwrap();
+    assert_eq!(dao.data_hash, None);
+    let index: u32 = dao.out_point.index().unpack();
+    assert_eq!(index, 2);
+
+    // Same cell dep as the scripts of the network
+    let dep_group = mainnet.dep_group(ICKB_DEP_GROUP).unwrap();
+    assert_eq!(dep_group.cells.len(), 8);
+    assert_eq!(
+        mainnet.cell_dep(ICKB_DEP_GROUP),
+        Some(network_scripts(&mainnet).unwrap().cell_deps[0].clone())
+    );
+    assert_eq!(
+        mainnet.cell_dep("XUDT"),
+        Some(mainnet.cell("XUDT").unwrap().cell_dep())
+    );
+    assert_eq!(mainnet.cell_dep("MISSING"), None);
+
+    // Every dep group cell must be declared, every file cell must have a recipe
+    let mut config = mainnet.config.clone();
+    config.dep_groups[0].cells.push("MISSING".to_string());
+    assert!(Deployment::new("mainnet", config, mainnet.migration.clone()).is_err());
+    let mut migration = mainnet.migration.clone();
+    migration.cell_recipes.pop();
+    assert!(Deployment::new("mainnet", mainnet.config.clone(), migration).is_err());
+}
+
+#[test]
+fn test_verify_migration() {
+    let mainnet = find_dir("deployment").join("mainnet");
+    let config = mainnet.join("deployment.toml");
+    let (path, migration) = Migration::latest(&mainnet.join("migrations")).unwrap();
+    let release_dir = find_dir("build/release");
+
+    let (dep_groups, binaries) = verify_migration(&config, &path, &release_dir).unwrap();
+    assert_eq!(dep_groups.len(), 1);
+    assert_eq!(dep_groups[0].cells.len(), 8);
+    assert!(dep_groups.iter().all(|c| c.agrees()));
+    let names: Vec<&str> = binaries.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["ICKB_LOGIC", "LIMIT_ORDER", "OWNED_OWNER"]);
+    assert!(binaries.iter().all(|c| c.agrees()));
+
+    // Hashes stay aligned behind the migration file stem
+    let columns: Vec<usize> = report(&binaries)
+        .lines()
+        .filter_map(|line| line.find("0x"))
+        .collect();
+    assert_eq!(columns.len(), 2 * binaries.len());
+    assert!(columns.iter().all(|&c| c == columns[0]));
+
+    // Misordered dep group cells and a partial migration
+    let dir = env::temp_dir().join(format!("ickb_verify_migration_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let mut swapped = DeploymentConfig::load(&config).unwrap();
+    swapped.dep_groups[0].cells.swap(0, 1);
+    let swapped_path = dir.join("deployment.toml");
+    fs::write(&swapped_path, toml::to_string(&swapped).unwrap()).unwrap();
+    let (dep_groups, binaries) = verify_migration(&swapped_path, &path, &release_dir).unwrap();
+    assert!(!dep_groups[0].agrees());
+    assert!(binaries.iter().all(|c| c.agrees()));
+
+    let mut partial = migration;
+    partial.cell_recipes.remove(1);
+    let partial_path = dir.join("partial.json");
+    fs::write(&partial_path, serde_json::to_string(&partial).unwrap()).unwrap();
+    assert!(verify_migration(&config, &partial_path, &release_dir).is_err());
+
+    fs::remove_dir_all(dir).unwrap();
+}
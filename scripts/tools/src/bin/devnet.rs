@@ -0,0 +1,133 @@
+//! Generate the deterministic iCKB deployment of a dev chain from deployment/devnet/template.toml
+//! and the build/release binaries: same genesis and binaries always produce the same cell deps.
+//!
+//! Usage: devnet [--genesis-tx-hash hash] [--funding tx_hash:index:capacity]
+//!               [--funding-lock-args args] [--funding-cell-dep tx_hash:index]
+//!               [--devnet-dir dir] [--release-dir dir]
+//!
+//! Writes deployment.toml and migrations/latest.json in the devnet directory. With funding, also
+//! writes deploy-tx-0.json and deploy-tx-1.json in the `ckb-cli tx` format, ready to be signed and
+//! sent in order. The funding lock is the deployment lock with the funding lock args, while the
+//! funding cell dep is the dep group of its lock.
+
+use std::{env, fs, path::PathBuf, process::ExitCode};
+
+use ckb_types::{
+    bytes::Bytes,
+    core::DepType,
+    packed::{CellDep, OutPoint, Script},
+    prelude::*,
+};
+use ickb_tools::{
+    deployment::{find_dir, parse_hash, to_hex},
+    devnet::{devnet_deployment, load_template, parse_bytes, parse_hash_type, Funding},
+    Error,
+};
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let mut genesis_tx_hash = [0u8; 32];
+    let mut funding = None;
+    let mut funding_lock_args = None;
+    let mut funding_cell_dep = None;
+    let mut devnet_dir = find_dir("deployment").join("devnet");
+    let mut release_dir = find_dir("build/release");
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let value = args.next().ok_or_else(|| format!("{arg} needs a value"))?;
+        match arg.as_str() {
+            "--genesis-tx-hash" => genesis_tx_hash = parse_hash(&value)?,
+            "--funding" => funding = Some(value),
+            "--funding-lock-args" => funding_lock_args = Some(parse_bytes(&value)?),
+            "--funding-cell-dep" => funding_cell_dep = Some(value),
+            "--devnet-dir" => devnet_dir = PathBuf::from(value),
+            "--release-dir" => release_dir = PathBuf::from(value),
+            _ => return Err(format!("unknown argument {arg}").into()),
+        }
+    }
+
+    let config = load_template(&devnet_dir.join("template.toml"), genesis_tx_hash)?;
+    let funding = match funding {
+        None => None,
+        Some(funding) => {
+            let [tx_hash, index, capacity] = split(&funding)?;
+            let lock = Script::new_builder()
+                .code_hash(parse_hash(&config.lock.code_hash)?.pack())
+                .hash_type(parse_hash_type(&config.lock.hash_type)?.into())
+                .args(Bytes::from(funding_lock_args.ok_or("missing --funding-lock-args")?).pack())
+                .build();
+            let cell_deps = match funding_cell_dep {
+                None => Vec::new(),
+                Some(cell_dep) => {
+                    let [tx_hash, index] = split(&cell_dep)?;
+                    vec![CellDep::new_builder()
+                        .out_point(out_point(tx_hash, index)?)
+                        .dep_type(DepType::DepGroup.into())
+                        .build()]
+                }
+            };
+            Some(Funding {
+                out_point: out_point(tx_hash, index)?,
+                capacity: capacity.parse()?,
+                lock,
+                cell_deps,
+            })
+        }
+    };
+
+    let devnet = devnet_deployment(&config, &release_dir, funding.as_ref())?;
+
+    let write = |name: &str, content: String| {
+        let path = devnet_dir.join(name);
+        fs::write(&path, content).map_err(|err| Error::Io(path, err))
+    };
+    write("deployment.toml", toml::to_string(&devnet.config)?)?;
+    fs::create_dir_all(devnet_dir.join("migrations"))?;
+    write(
+        "migrations/latest.json",
+        serde_json::to_string_pretty(&devnet.migration)?,
+    )?;
+    if funding.is_some() {
+        for (i, tx) in devnet.transactions.iter().enumerate() {
+            let tx_file = serde_json::json!({
+                "transaction": ckb_jsonrpc_types::Transaction::from(tx.data()),
+                "multisig_configs": {},
+                "signatures": {},
+            });
+            write(
+                &format!("deploy-tx-{i}.json"),
+                serde_json::to_string_pretty(&tx_file)?,
+            )?;
+        }
+    }
+
+    for tx in &devnet.transactions {
+        println!("{}", to_hex(tx.hash().as_slice()));
+    }
+    Ok(())
+}
+
+fn split<const N: usize>(value: &str) -> Result<[&str; N], String> {
+    value
+        .split(':')
+        .collect::<Vec<_>>()
+        .try_into()
+        .map_err(|_| format!("expected {N} values separated by colons: {value}"))
+}
+
+fn out_point(tx_hash: &str, index: &str) -> Result<OutPoint, Box<dyn std::error::Error>> {
+    Ok(OutPoint::new_builder()
+        .tx_hash(parse_hash(tx_hash)?.pack())
+        .index(index.parse::<u32>()?.pack())
+        .build())
+}
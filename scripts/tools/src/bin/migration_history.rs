@@ -0,0 +1,43 @@
+//! Walk the migrations of a network from the oldest to the latest and report, for each one, which
+//! scripts changed, the resulting ickb_logic script hash and iCKB xUDT type hash, and which cells
+//! of the previous generation the new deployment strands.
+//!
+//! Usage: migration_history [network] [--deployment-dir dir]
+//!
+//! Network defaults to testnet.
+
+use std::{env, path::PathBuf, process::ExitCode};
+
+use ickb_tools::{
+    deployment::find_dir,
+    history::{history_report, load_migrations, migration_history},
+};
+
+fn main() -> ExitCode {
+    let mut deployment_dir = find_dir("deployment");
+    let mut network = "testnet".to_string();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--deployment-dir" {
+            let Some(dir) = args.next() else {
+                eprintln!("--deployment-dir needs a value");
+                return ExitCode::FAILURE;
+            };
+            deployment_dir = PathBuf::from(dir);
+        } else {
+            network = arg;
+        }
+    }
+
+    let dir = deployment_dir.join(&network).join("migrations");
+    match load_migrations(&dir).and_then(|m| migration_history(&network, &m)) {
+        Ok(steps) => {
+            print!("{}", history_report(&steps));
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
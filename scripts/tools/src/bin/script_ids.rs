@@ -0,0 +1,46 @@
+//! Print as JSON every identifier derived from the deployment records of a network: code hashes,
+//! script hashes of ickb_logic, owned_owner and limit_order, the iCKB xUDT type script and the
+//! iCKB dep group.
+//!
+//! Usage: script_ids [network...] [--deployment-dir dir]
+//!
+//! Networks default to testnet and mainnet.
+
+use std::{env, path::PathBuf, process::ExitCode};
+
+use ickb_tools::{deployment::find_dir, registry::Deployment, scripts::script_ids};
+
+fn main() -> ExitCode {
+    let mut deployment_dir = find_dir("deployment");
+    let mut networks = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--deployment-dir" {
+            let Some(dir) = args.next() else {
+                eprintln!("--deployment-dir needs a value");
+                return ExitCode::FAILURE;
+            };
+            deployment_dir = PathBuf::from(dir);
+        } else {
+            networks.push(arg);
+        }
+    }
+    if networks.is_empty() {
+        networks = vec!["testnet".to_string(), "mainnet".to_string()];
+    }
+
+    let ids = networks
+        .iter()
+        .map(|name| script_ids(&Deployment::load(&deployment_dir, name)?))
+        .collect::<Result<Vec<_>, _>>();
+    match ids {
+        Ok(ids) => {
+            println!("{}", serde_json::to_string_pretty(&ids).unwrap());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! Check a migration against its deployment configuration before shipping it: every dep group
+//! cell data is rebuilt from the migration out points and compared with its recorded data hash,
+//! then every cell recipe data hash is compared with its build/release binary.
+//!
+//! Usage: verify_migration <deployment.toml> <migration.json> [release_dir]
+//!
+//! Exits with a non-zero code if any dep group or binary disagrees with the migration.
+
+use std::{env, path::PathBuf, process::ExitCode};
+
+use ickb_tools::{
+    deployment::find_dir,
+    verify::{dep_groups_report, report, verify_migration},
+};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (config, migration) = match args.as_slice() {
+        [config, migration, ..] => (PathBuf::from(config), PathBuf::from(migration)),
+        _ => {
+            eprintln!("Usage: verify_migration <deployment.toml> <migration.json> [release_dir]");
+            return ExitCode::FAILURE;
+        }
+    };
+    let release_dir = args
+        .get(2)
+        .map_or_else(|| find_dir("build/release"), PathBuf::from);
+
+    let (dep_groups, binaries) = match verify_migration(&config, &migration, &release_dir) {
+        Ok(checks) => checks,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    print!("{}{}", dep_groups_report(&dep_groups), report(&binaries));
+    if dep_groups.iter().all(|c| c.agrees()) && binaries.iter().all(|c| c.agrees()) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,43 @@
+//! Check that build/release binaries match the testnet and mainnet deployment records.
+//!
+//! Usage: verify_release [deployment_dir] [release_dir]
+//!
+//! Exits with a non-zero code if any binary disagrees with a record.
+
+use std::{env, path::PathBuf, process::ExitCode};
+
+use ickb_tools::{
+    deployment::find_dir,
+    verify::{report, verify_release},
+};
+use utils::XUDT_CODE_HASH;
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+    let deployment_dir = args
+        .next()
+        .map_or_else(|| find_dir("deployment"), PathBuf::from);
+    let release_dir = args
+        .next()
+        .map_or_else(|| find_dir("build/release"), PathBuf::from);
+
+    let checks = match verify_release(
+        &deployment_dir,
+        &release_dir,
+        &["testnet", "mainnet"],
+        &[("xudt", XUDT_CODE_HASH)],
+    ) {
+        Ok(checks) => checks,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    print!("{}", report(&checks));
+    if checks.iter().all(|c| c.agrees()) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
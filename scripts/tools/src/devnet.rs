@@ -0,0 +1,246 @@
+use std::{fs, path::Path};
+
+use ckb_types::{
+    bytes::Bytes,
+    core::{Capacity, ScriptHashType, TransactionBuilder, TransactionView},
+    packed::{
+        Bytes as PackedBytes, CellDep, CellInput, CellOutput, OutPoint, OutPointVec, Script,
+        WitnessArgs,
+    },
+    prelude::*,
+};
+
+use crate::{
+    deployment::{
+        data_hash, parse_hash, read, to_hex, CellLocation, CellRecipe, DepGroupRecipe,
+        DeploymentConfig, Migration,
+    },
+    registry::Deployment,
+    Error,
+};
+
+// Template placeholder, replaced with the genesis transaction hash of the dev chain
+pub const GENESIS_TX_HASH_PLACEHOLDER: &str = "${genesis_tx_hash}";
+
+// Fee paid by each deployment transaction when funded, in shannons
+pub const DEPLOYMENT_FEE: u64 = 100_000_000;
+
+// Live cell paying for the deployment, the change goes back to its lock
+#[derive(Debug, Clone)]
+pub struct Funding {
+    pub out_point: OutPoint,
+    pub capacity: u64,
+    pub lock: Script,
+    // Cell deps needed by lock, such as the genesis secp256k1 dep group
+    pub cell_deps: Vec<CellDep>,
+}
+
+// Deterministic deployment of a dev chain: same inputs always produce the same transactions,
+// so a devnet and a ckb_testtool::Context snapshot share the same cell deps
+#[derive(Debug, Clone)]
+pub struct Devnet {
+    pub config: DeploymentConfig,
+    pub migration: Migration,
+    // Code cells transaction followed by the dep groups transaction
+    pub transactions: Vec<TransactionView>,
+}
+
+impl Devnet {
+    pub fn deployment(&self) -> Result<Deployment, Error> {
+        Deployment::new("devnet", self.config.clone(), self.migration.clone())
+    }
+}
+
+// Fill the template with the genesis transaction hash of the dev chain
+pub fn load_template(path: &Path, genesis_tx_hash: [u8; 32]) -> Result<DeploymentConfig, Error> {
+    let content = read(path)?.replace(GENESIS_TX_HASH_PLACEHOLDER, &to_hex(&genesis_tx_hash));
+    toml::from_str(&content).map_err(|err| Error::Parse(path.to_path_buf(), err.to_string()))
+}
+
+// Build the deployment transactions, cells deployed from a file are read from release_dir.
+// Without funding the transactions have no inputs, genesis-style: they cannot be sent to a node,
+// but can be committed as is to a ckb_testtool::Context.
+// With funding they are chained through their change output and only need to be signed.
+pub fn devnet_deployment(
+    config: &DeploymentConfig,
+    release_dir: &Path,
+    funding: Option<&Funding>,
+) -> Result<Devnet, Error> {
+    let lock = Script::new_builder()
+        .code_hash(parse_hash(&config.lock.code_hash)?.pack())
+        .hash_type(parse_hash_type(&config.lock.hash_type)?.into())
+        .args(Bytes::from(parse_bytes(&config.lock.args)?).pack())
+        .build();
+
+    // Code cells
+    let mut cells = Vec::new();
+    for cell in &config.cells {
+        if let CellLocation::File { file } = &cell.location {
+            if cell.enable_type_id {
+                return Err(Error::Unsupported(format!("{}: type id", cell.name)));
+            }
+            let file_name = Path::new(file).file_name().unwrap_or_default();
+            let path = release_dir.join(file_name);
+            let data = fs::read(&path).map_err(|err| Error::Io(path, err))?;
+            cells.push((cell.name.clone(), Bytes::from(data)));
+        }
+    }
+    let cells_tx = deployment_tx(
+        &lock,
+        cells.iter().map(|(_, data)| data.clone()).collect(),
+        funding.map(|f| (f.out_point.clone(), f.capacity, f)),
+    )?;
+
+    let mut cell_recipes = Vec::new();
+    for (index, (name, data)) in cells.iter().enumerate() {
+        let output = cells_tx.output(index).unwrap();
+        cell_recipes.push(CellRecipe {
+            name: name.clone(),
+            tx_hash: to_hex(cells_tx.hash().as_slice()),
+            index: index as u32,
+            occupied_capacity: output.capacity().unpack(),
+            data_hash: to_hex(&data_hash(data)),
+            type_id: None,
+        });
+    }
+
+    // Dep groups, cells are either just deployed or already live
+    let out_point = |name: &str| -> Result<OutPoint, Error> {
+        if let Some(recipe) = cell_recipes.iter().find(|r| r.name == name) {
+            return Ok(OutPoint::new(cells_tx.hash(), recipe.index));
+        }
+        match config
+            .cells
+            .iter()
+            .find(|c| c.name == name)
+            .map(|c| &c.location)
+        {
+            Some(CellLocation::OutPoint { tx_hash, index }) => Ok(OutPoint::new_builder()
+                .tx_hash(parse_hash(tx_hash)?.pack())
+                .index(index.pack())
+                .build()),
+            _ => Err(Error::Unsupported(format!(
+                "{name}: unknown dep group cell"
+            ))),
+        }
+    };
+    let mut dep_groups = Vec::new();
+    for dep_group in &config.dep_groups {
+        let out_points = dep_group
+            .cells
+            .iter()
+            .map(|name| out_point(name))
+            .collect::<Result<Vec<_>, _>>()?;
+        let data = OutPointVec::new_builder()
+            .set(out_points)
+            .build()
+            .as_bytes();
+        dep_groups.push((dep_group.name.clone(), data));
+    }
+    // Change of the code cells transaction is its last output
+    let change = funding.map(|f| {
+        let index = cells_tx.outputs().len() - 1;
+        let capacity: u64 = cells_tx.output(index).unwrap().capacity().unpack();
+        (OutPoint::new(cells_tx.hash(), index as u32), capacity, f)
+    });
+    let dep_groups_tx = deployment_tx(
+        &lock,
+        dep_groups.iter().map(|(_, data)| data.clone()).collect(),
+        change,
+    )?;
+
+    let dep_group_recipes = dep_groups
+        .iter()
+        .enumerate()
+        .map(|(index, (name, data))| DepGroupRecipe {
+            name: name.clone(),
+            tx_hash: to_hex(dep_groups_tx.hash().as_slice()),
+            index: index as u32,
+            data_hash: to_hex(&data_hash(data)),
+            occupied_capacity: dep_groups_tx.output(index).unwrap().capacity().unpack(),
+        })
+        .collect();
+
+    Ok(Devnet {
+        config: config.clone(),
+        migration: Migration {
+            cell_recipes,
+            dep_group_recipes,
+        },
+        transactions: vec![cells_tx, dep_groups_tx],
+    })
+}
+
+// Transaction with one output per data, each with exactly its occupied capacity.
+// If funded, the change goes in the last output and the witness has room for a secp256k1 signature.
+fn deployment_tx(
+    lock: &Script,
+    data: Vec<Bytes>,
+    funding: Option<(OutPoint, u64, &Funding)>,
+) -> Result<TransactionView, Error> {
+    let mut outputs = Vec::new();
+    let mut total = 0u64;
+    for d in &data {
+        let output = CellOutput::new_builder().lock(lock.clone()).build();
+        let occupied = output
+            .occupied_capacity(Capacity::bytes(d.len()).unwrap())
+            .unwrap()
+            .as_u64();
+        total += occupied;
+        outputs.push(output.as_builder().capacity(occupied.pack()).build());
+    }
+
+    let mut builder = TransactionBuilder::default();
+    let mut outputs_data = data;
+    if let Some((out_point, capacity, funding)) = funding {
+        let change = capacity
+            .checked_sub(total + DEPLOYMENT_FEE)
+            .ok_or_else(|| Error::Unsupported(format!("funding: short of {total} shannons")))?;
+        outputs.push(
+            CellOutput::new_builder()
+                .lock(funding.lock.clone())
+                .capacity(change.pack())
+                .build(),
+        );
+        outputs_data.push(Bytes::new());
+        let witness = WitnessArgs::new_builder()
+            .lock(Some(Bytes::from(vec![0u8; 65])).pack())
+            .build();
+        builder = builder
+            .input(CellInput::new(out_point, 0))
+            .cell_deps(funding.cell_deps.clone())
+            .witness(witness.as_bytes().pack());
+    }
+
+    Ok(builder
+        .outputs(outputs)
+        .outputs_data(
+            outputs_data
+                .into_iter()
+                .map(|d| d.pack())
+                .collect::<Vec<PackedBytes>>(),
+        )
+        .build())
+}
+
+pub fn parse_hash_type(hash_type: &str) -> Result<ScriptHashType, Error> {
+    match hash_type {
+        "data" => Ok(ScriptHashType::Data),
+        "type" => Ok(ScriptHashType::Type),
+        "data1" => Ok(ScriptHashType::Data1),
+        "data2" => Ok(ScriptHashType::Data2),
+        _ => Err(Error::Unsupported(format!("hash type {hash_type}"))),
+    }
+}
+
+pub fn parse_bytes(hex: &str) -> Result<Vec<u8>, Error> {
+    let invalid = || Error::InvalidHex(hex.to_string());
+    let digits = hex.strip_prefix("0x").ok_or_else(invalid)?;
+    if digits.len() % 2 != 0 || !digits.is_ascii() {
+        return Err(invalid());
+    }
+    (0..digits.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).map_err(|_| invalid()))
+        .collect()
+}
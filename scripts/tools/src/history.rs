@@ -0,0 +1,219 @@
+use std::{collections::BTreeMap, path::Path};
+
+use ckb_types::{
+    bytes::Bytes,
+    core::ScriptHashType,
+    packed::{OutPoint, Script},
+    prelude::*,
+};
+use ickb_sdk::Scripts;
+use utils::XUDT_CODE_HASH;
+
+use crate::{
+    deployment::{migration_paths, parse_hash, to_hex, Migration},
+    scripts::{script, ICKB_LOGIC, LIMIT_ORDER, OWNED_OWNER},
+    Error,
+};
+
+// Code cells deployed by every migration, in report order
+pub const HISTORY_SCRIPTS: [&str; 3] = [ICKB_LOGIC, OWNED_OWNER, LIMIT_ORDER];
+
+// Scripts deployed by a migration: their data hashes and the identities derived from them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generation {
+    pub migration: String,
+    pub code_cells: BTreeMap<String, CodeCell>,
+    pub ickb_logic_hash: [u8; 32],
+    // xUDT args contain the ickb_logic script hash, so each ickb_logic is a different iCKB token
+    pub ickb_udt_hash: [u8; 32],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeCell {
+    pub out_point: OutPoint,
+    pub data_hash: [u8; 32],
+    // Hash of the Data1 script with empty args, as used by iCKB cells
+    pub script_hash: [u8; 32],
+}
+
+// A migration compared with the previous one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationStep {
+    pub generation: Generation,
+    // Scripts whose data hash changed, all of them on the first migration
+    pub changed: Vec<String>,
+    // Cells of the previous generation that the new deployment cannot spend
+    pub stranded: Vec<StrandedCells>,
+}
+
+// Cells identified by a script hash of the previous generation: the new deployment provides a
+// different binary, so they depend on the previous code cell staying live
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrandedCells {
+    pub script: String,
+    pub cells: &'static str,
+    pub script_hash: [u8; 32],
+    pub code_cell: OutPoint,
+}
+
+impl Generation {
+    pub fn new(network: &str, name: &str, migration: &Migration) -> Result<Self, Error> {
+        let mut code_cells = BTreeMap::new();
+        for script_name in HISTORY_SCRIPTS {
+            let recipe = migration.cell_recipe(script_name).ok_or_else(|| {
+                Error::MissingRecipe(format!("{network}/{name}"), script_name.to_string())
+            })?;
+            let data_hash = parse_hash(&recipe.data_hash)?;
+            let out_point = OutPoint::new_builder()
+                .tx_hash(parse_hash(&recipe.tx_hash)?.pack())
+                .index(recipe.index.pack())
+                .build();
+            code_cells.insert(
+                script_name.to_string(),
+                CodeCell {
+                    out_point,
+                    data_hash,
+                    script_hash: data1(data_hash).calc_script_hash().unpack(),
+                },
+            );
+        }
+
+        let ickb_logic = data1(code_cells[ICKB_LOGIC].data_hash);
+        Ok(Generation {
+            migration: name.to_string(),
+            code_cells,
+            ickb_logic_hash: ickb_logic.calc_script_hash().unpack(),
+            ickb_udt_hash: Scripts::ickb_udt(&ickb_logic, XUDT_CODE_HASH.pack())
+                .calc_script_hash()
+                .unpack(),
+        })
+    }
+}
+
+fn data1(data_hash: [u8; 32]) -> Script {
+    script(data_hash, ScriptHashType::Data1, Bytes::new())
+}
+
+// Cells of a generation identified by each script
+fn script_cells(generation: &Generation, script: &str) -> Vec<(&'static str, [u8; 32])> {
+    let script_hash = generation.code_cells[script].script_hash;
+    match script {
+        ICKB_LOGIC => vec![
+            ("deposits locked by ickb_logic", script_hash),
+            ("receipts typed by ickb_logic", script_hash),
+            (
+                "iCKB xUDT cells, also inside limit orders: still transferable, but convertible \
+                 to CKB only through the previous ickb_logic",
+                generation.ickb_udt_hash,
+            ),
+        ],
+        OWNED_OWNER => vec![
+            ("withdrawal requests locked by owned_owner", script_hash),
+            ("owner cells typed by owned_owner", script_hash),
+        ],
+        LIMIT_ORDER => vec![
+            ("limit orders locked by limit_order", script_hash),
+            ("master cells typed by limit_order", script_hash),
+        ],
+        _ => Vec::new(),
+    }
+}
+
+// Walk migrations from the oldest to the latest, comparing each one with the previous
+pub fn migration_history(
+    network: &str,
+    migrations: &[(String, Migration)],
+) -> Result<Vec<MigrationStep>, Error> {
+    let mut steps: Vec<MigrationStep> = Vec::new();
+    for (name, migration) in migrations {
+        let generation = Generation::new(network, name, migration)?;
+        let previous = steps.last().map(|s| &s.generation);
+        let changed: Vec<String> = HISTORY_SCRIPTS
+            .iter()
+            .filter(|s| {
+                previous.map_or(true, |p| {
+                    p.code_cells[**s].data_hash != generation.code_cells[**s].data_hash
+                })
+            })
+            .map(ToString::to_string)
+            .collect();
+        let stranded = previous
+            .map(|p| {
+                changed
+                    .iter()
+                    .flat_map(|s| {
+                        script_cells(p, s)
+                            .into_iter()
+                            .map(|(cells, script_hash)| StrandedCells {
+                                script: s.clone(),
+                                cells,
+                                script_hash,
+                                code_cell: p.code_cells[s].out_point.clone(),
+                            })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        steps.push(MigrationStep {
+            generation,
+            changed,
+            stranded,
+        });
+    }
+    Ok(steps)
+}
+
+// Migrations of a network directory, such as deployment/testnet/migrations, by file name
+pub fn load_migrations(dir: &Path) -> Result<Vec<(String, Migration)>, Error> {
+    migration_paths(dir)?
+        .into_iter()
+        .map(|path| {
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            Ok((name, Migration::load(&path)?))
+        })
+        .collect()
+}
+
+#[must_use]
+pub fn history_report(steps: &[MigrationStep]) -> String {
+    let mut report = String::new();
+    for step in steps {
+        let generation = &step.generation;
+        report.push_str(&format!("{}\n", generation.migration));
+        let changed = if step.changed.is_empty() {
+            "none".to_string()
+        } else {
+            step.changed.join(", ")
+        };
+        report.push_str(&format!("  changed      {changed}\n"));
+        for (name, cell) in &generation.code_cells {
+            report.push_str(&format!(
+                "  {name:<13}{} at {}:{}\n",
+                to_hex(&cell.data_hash),
+                to_hex(cell.out_point.tx_hash().as_slice()),
+                Unpack::<u32>::unpack(&cell.out_point.index())
+            ));
+        }
+        report.push_str(&format!(
+            "  ickb_logic   {} script hash\n",
+            to_hex(&generation.ickb_logic_hash)
+        ));
+        report.push_str(&format!(
+            "  iCKB xUDT    {} type hash\n",
+            to_hex(&generation.ickb_udt_hash)
+        ));
+        for stranded in &step.stranded {
+            report.push_str(&format!(
+                "  stranded     {}\n    script hash {}, previous code cell {}:{}\n",
+                stranded.cells,
+                to_hex(&stranded.script_hash),
+                to_hex(stranded.code_cell.tx_hash().as_slice()),
+                Unpack::<u32>::unpack(&stranded.code_cell.index())
+            ));
+        }
+    }
+    report
+}
@@ -0,0 +1,10 @@
+//! Off-chain tooling around the iCKB deployments recorded by `ickb_registry`: checks of the
+//! `build/release` binaries against them and the script identifiers derived from them, the
+//! history of script generations across migrations and the deterministic devnet deployment.
+
+pub mod devnet;
+pub mod history;
+pub mod scripts;
+pub mod verify;
+
+pub use ickb_registry::{deployment, registry, Error};
@@ -0,0 +1,131 @@
+use std::collections::BTreeMap;
+
+use ckb_types::{
+    bytes::Bytes,
+    core::{DepType, ScriptHashType},
+    packed::Script,
+    prelude::*,
+};
+use ickb_rate::DepositLimits;
+use ickb_sdk::Scripts;
+use serde::Serialize;
+use utils::{DAO_CODE_HASH, XUDT_CODE_HASH};
+
+use crate::{deployment::to_hex, registry::Deployment, Error};
+
+// Deployed cells whose code hash is the data hash recorded in the migrations
+pub const ICKB_LOGIC: &str = "ICKB_LOGIC";
+pub const LIMIT_ORDER: &str = "LIMIT_ORDER";
+pub const OWNED_OWNER: &str = "OWNED_OWNER";
+pub const ICKB_DEP_GROUP: &str = "ickb_dep_group";
+
+// iCKB scripts of a network, cell deps contain its iCKB dep group
+pub fn network_scripts(deployment: &Deployment) -> Result<Scripts, Error> {
+    let missing = |name: &str| Error::MissingRecipe(deployment.network.clone(), name.to_string());
+    let data1 = |name: &str| -> Result<Script, Error> {
+        let data_hash = deployment
+            .cell(name)
+            .and_then(|cell| cell.data_hash)
+            .ok_or_else(|| missing(name))?;
+        Ok(script(data_hash, ScriptHashType::Data1, Bytes::new()))
+    };
+
+    let ickb_logic = data1(ICKB_LOGIC)?;
+    let cell_dep = deployment
+        .dep_group(ICKB_DEP_GROUP)
+        .ok_or_else(|| missing(ICKB_DEP_GROUP))?
+        .cell_dep();
+
+    Ok(Scripts {
+        dao: script(DAO_CODE_HASH, ScriptHashType::Type, Bytes::new()),
+        ickb_udt: Scripts::ickb_udt(&ickb_logic, XUDT_CODE_HASH.pack()),
+        ickb_logic,
+        owned_owner: data1(OWNED_OWNER)?,
+        limit_order: data1(LIMIT_ORDER)?,
+        cell_deps: vec![cell_dep],
+        deposit_limits: DepositLimits::V1,
+    })
+}
+
+pub(crate) fn script(code_hash: [u8; 32], hash_type: ScriptHashType, args: Bytes) -> Script {
+    Script::new_builder()
+        .code_hash(code_hash.pack())
+        .hash_type(hash_type.into())
+        .args(args.pack())
+        .build()
+}
+
+// Every identifier derived from a deployment, as hex strings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScriptIds {
+    pub network: String,
+    pub scripts: BTreeMap<String, ScriptId>,
+    pub cell_deps: Vec<CellDepId>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScriptId {
+    pub code_hash: String,
+    pub hash_type: String,
+    pub args: String,
+    pub script_hash: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CellDepId {
+    pub tx_hash: String,
+    pub index: u32,
+    pub dep_type: String,
+}
+
+impl From<&Script> for ScriptId {
+    fn from(script: &Script) -> Self {
+        let hash_type = match ScriptHashType::try_from(script.hash_type()) {
+            Ok(ScriptHashType::Data) => "data",
+            Ok(ScriptHashType::Type) => "type",
+            Ok(ScriptHashType::Data1) => "data1",
+            Ok(ScriptHashType::Data2) => "data2",
+            Err(_) => "unknown",
+        };
+        ScriptId {
+            code_hash: to_hex(script.code_hash().as_slice()),
+            hash_type: hash_type.to_string(),
+            args: to_hex(&script.args().raw_data()),
+            script_hash: to_hex(script.calc_script_hash().as_slice()),
+        }
+    }
+}
+
+pub fn script_ids(deployment: &Deployment) -> Result<ScriptIds, Error> {
+    let s = network_scripts(deployment)?;
+    let scripts = [
+        ("dao", &s.dao),
+        ("ickb_logic", &s.ickb_logic),
+        ("ickb_udt", &s.ickb_udt),
+        ("owned_owner", &s.owned_owner),
+        ("limit_order", &s.limit_order),
+    ]
+    .into_iter()
+    .map(|(name, script)| (name.to_string(), ScriptId::from(script)))
+    .collect();
+    let cell_deps = s
+        .cell_deps
+        .iter()
+        .map(|d| CellDepId {
+            tx_hash: to_hex(d.out_point().tx_hash().as_slice()),
+            index: d.out_point().index().unpack(),
+            dep_type: if d.dep_type() == DepType::DepGroup.into() {
+                "dep_group"
+            } else {
+                "code"
+            }
+            .to_string(),
+        })
+        .collect();
+
+    Ok(ScriptIds {
+        network: deployment.network.clone(),
+        scripts,
+        cell_deps,
+    })
+}
@@ -0,0 +1,205 @@
+use std::{fs, path::Path};
+
+use ckb_types::{bytes::Bytes, packed::OutPointVec, prelude::*};
+
+use crate::{
+    deployment::{
+        data_hash, parse_hash, to_hex, CellLocation, DeploymentConfig, Migration, Network,
+    },
+    registry::Deployment,
+    Error,
+};
+
+// Data hash of a release binary, compared with every record that references it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BinaryCheck {
+    pub name: String,
+    pub local: Option<[u8; 32]>,
+    // Record source, such as testnet or mainnet, with its recorded data hash, if any
+    pub records: Vec<(String, Option<[u8; 32]>)>,
+}
+
+impl BinaryCheck {
+    #[must_use]
+    pub fn agrees(&self) -> bool {
+        self.local.is_some() && self.records.iter().all(|(_, hash)| *hash == self.local)
+    }
+}
+
+// Check the binaries in release_dir against the latest migration of each network.
+// Cells deployed from a file are matched to the binary with the same file name in release_dir.
+// Binaries whose hash is a protocol constant, such as xUDT, are checked against that constant.
+pub fn verify_release(
+    deployment_dir: &Path,
+    release_dir: &Path,
+    networks: &[&str],
+    constants: &[(&str, [u8; 32])],
+) -> Result<Vec<BinaryCheck>, Error> {
+    let networks = networks
+        .iter()
+        .map(|name| Network::load(deployment_dir, name))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Binaries referenced by any network, in order of first appearance
+    let mut binaries: Vec<(String, String)> = Vec::new();
+    for network in &networks {
+        for cell in &network.config.cells {
+            if let CellLocation::File { file } = &cell.location {
+                let file_name = Path::new(file)
+                    .file_name()
+                    .map_or_else(|| file.clone(), |f| f.to_string_lossy().to_string());
+                if !binaries.iter().any(|(name, _)| *name == cell.name) {
+                    binaries.push((cell.name.clone(), file_name));
+                }
+            }
+        }
+    }
+
+    let mut checks = Vec::new();
+    for (name, file_name) in binaries {
+        let mut records = Vec::new();
+        for network in &networks {
+            let recorded = match network.migration.cell_recipe(&name) {
+                Some(recipe) => Some(parse_hash(&recipe.data_hash)?),
+                None => None,
+            };
+            records.push((network.name.clone(), recorded));
+        }
+        checks.push(BinaryCheck {
+            name,
+            local: local_hash(&release_dir.join(file_name)),
+            records,
+        });
+    }
+
+    for (file_name, hash) in constants {
+        checks.push(BinaryCheck {
+            name: file_name.to_uppercase(),
+            local: local_hash(&release_dir.join(file_name)),
+            records: vec![("constant".to_string(), Some(*hash))],
+        });
+    }
+
+    Ok(checks)
+}
+
+fn local_hash(path: &Path) -> Option<[u8; 32]> {
+    fs::read(path).ok().map(|data| data_hash(&data))
+}
+
+#[must_use]
+pub fn report(checks: &[BinaryCheck]) -> String {
+    let hex = |hash: &Option<[u8; 32]>| hash.map_or_else(|| "missing".to_string(), |h| to_hex(&h));
+    // Hashes aligned past the longest label, migration file stems included
+    let width = checks
+        .iter()
+        .flat_map(|check| check.records.iter().map(|(source, _)| source.len()))
+        .fold("local".len(), usize::max);
+    let mut report = String::new();
+    for check in checks {
+        let status = if check.agrees() { "OK" } else { "MISMATCH" };
+        report.push_str(&format!("{} {status}\n", check.name));
+        report.push_str(&format!("  {:<width$} {}\n", "local", hex(&check.local)));
+        for (source, hash) in &check.records {
+            report.push_str(&format!("  {source:<width$} {}\n", hex(hash)));
+        }
+    }
+    report
+}
+
+// Dep group cell data rebuilt from the out points of its cells, in order,
+// compared with the data hash recorded by the migration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepGroupCheck {
+    pub name: String,
+    pub cells: Vec<String>,
+    pub rebuilt: [u8; 32],
+    pub recorded: [u8; 32],
+}
+
+impl DepGroupCheck {
+    #[must_use]
+    pub fn agrees(&self) -> bool {
+        self.rebuilt == self.recorded
+    }
+}
+
+// Molecule OutPointVec of the dep group cells, as stored in the dep group cell
+#[must_use]
+pub fn dep_group_data(deployment: &Deployment, name: &str) -> Option<Bytes> {
+    let out_points = deployment
+        .dep_group(name)?
+        .cells
+        .iter()
+        .map(|cell| deployment.cell(cell).map(|c| c.out_point.clone()))
+        .collect::<Option<Vec<_>>>()?;
+    Some(
+        OutPointVec::new_builder()
+            .set(out_points)
+            .build()
+            .as_bytes(),
+    )
+}
+
+#[must_use]
+pub fn verify_dep_groups(deployment: &Deployment) -> Vec<DepGroupCheck> {
+    deployment
+        .dep_groups
+        .iter()
+        .map(|(name, dep_group)| DepGroupCheck {
+            name: name.clone(),
+            cells: dep_group.cells.clone(),
+            rebuilt: dep_group_data(deployment, name).map_or([0u8; 32], |d| data_hash(&d)),
+            recorded: dep_group.data_hash,
+        })
+        .collect()
+}
+
+// Check a single migration file against its deployment.toml: every dep group is rebuilt from
+// the migration out points and every cell recipe is compared with its binary in release_dir.
+// A migration missing a recipe of the configuration fails to load as a Deployment.
+pub fn verify_migration(
+    config_path: &Path,
+    migration_path: &Path,
+    release_dir: &Path,
+) -> Result<(Vec<DepGroupCheck>, Vec<BinaryCheck>), Error> {
+    let config = DeploymentConfig::load(config_path)?;
+    let migration = Migration::load(migration_path)?;
+    let source = migration_path
+        .file_stem()
+        .map_or_else(String::new, |s| s.to_string_lossy().to_string());
+    let deployment = Deployment::new(&source, config, migration)?;
+
+    let mut binaries = Vec::new();
+    for recipe in &deployment.migration.cell_recipes {
+        let file = deployment
+            .config
+            .cells
+            .iter()
+            .find(|c| c.name == recipe.name)
+            .and_then(|c| match &c.location {
+                CellLocation::File { file } => Path::new(file).file_name(),
+                CellLocation::OutPoint { .. } => None,
+            });
+        binaries.push(BinaryCheck {
+            name: recipe.name.clone(),
+            local: file.and_then(|f| local_hash(&release_dir.join(f))),
+            records: vec![(source.clone(), Some(parse_hash(&recipe.data_hash)?))],
+        });
+    }
+
+    Ok((verify_dep_groups(&deployment), binaries))
+}
+
+#[must_use]
+pub fn dep_groups_report(checks: &[DepGroupCheck]) -> String {
+    let mut report = String::new();
+    for check in checks {
+        let status = if check.agrees() { "OK" } else { "MISMATCH" };
+        report.push_str(&format!("{} {status}\n", check.name));
+        report.push_str(&format!("  cells    {}\n", check.cells.join(", ")));
+        report.push_str(&format!("  rebuilt  {}\n", to_hex(&check.rebuilt)));
+        report.push_str(&format!("  recorded {}\n", to_hex(&check.recorded)));
+    }
+    report
+}